@@ -18,12 +18,12 @@ fn main() {
                 kind: HeadingKind::Atx(1),
                 content: vec![
                     Inline::Text("Welcome to".to_string()),
-                    Inline::Strong(vec![Inline::Text("Generic AST".to_string())]),
+                    Inline::Strong(vec![Inline::Text("Generic AST".to_string())].into()),
                 ],
             }),
             Block::Paragraph(vec![
                 Inline::Text("This example shows basic ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("generic".to_string())]),
+                Inline::Emphasis(vec![Inline::Text("generic".to_string())].into()),
                 Inline::Text(" functionality.".to_string()),
             ]),
         ],
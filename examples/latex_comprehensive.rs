@@ -36,7 +36,7 @@ fn basic_example() {
             }),
             Block::Paragraph(vec![
                 Inline::Text("This is a ".to_string()),
-                Inline::Strong(vec![Inline::Text("simple".to_string())]),
+                Inline::Strong(vec![Inline::Text("simple".to_string())].into()),
                 Inline::Text(" example of LaTeX generation.".to_string()),
             ]),
         ],
@@ -120,7 +120,7 @@ fn complex_document_example() {
             }),
             Block::Paragraph(vec![
                 Inline::Text("The LaTeX printer supports ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("all".to_string())]),
+                Inline::Emphasis(vec![Inline::Text("all".to_string())].into()),
                 Inline::Text(" CommonMark and GFM features.".to_string()),
             ]),
             Block::Heading(Heading {
@@ -134,9 +134,9 @@ fn complex_document_example() {
                         task: Some(TaskState::Complete),
                         blocks: vec![Block::Paragraph(vec![
                             Inline::Text("Text formatting: ".to_string()),
-                            Inline::Strong(vec![Inline::Text("bold".to_string())]),
+                            Inline::Strong(vec![Inline::Text("bold".to_string())].into()),
                             Inline::Text(", ".to_string()),
-                            Inline::Emphasis(vec![Inline::Text("italic".to_string())]),
+                            Inline::Emphasis(vec![Inline::Text("italic".to_string())].into()),
                             Inline::Text(", and ".to_string()),
                             Inline::Strikethrough(vec![Inline::Text("strikethrough".to_string())]),
                         ])],
@@ -160,6 +160,7 @@ fn complex_document_example() {
                         ])],
                     },
                 ],
+                tight: true,
             }),
             Block::BlockQuote(vec![Block::Paragraph(vec![Inline::Text(
                 "LaTeX is a high-quality typesetting system.".to_string(),
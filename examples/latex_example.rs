@@ -11,9 +11,9 @@ fn main() {
             }),
             Block::Paragraph(vec![
                 Inline::Text("This is a ".to_string()),
-                Inline::Strong(vec![Inline::Text("bold".to_string())]),
+                Inline::Strong(vec![Inline::Text("bold".to_string())].into()),
                 Inline::Text(" and ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("italic".to_string())]),
+                Inline::Emphasis(vec![Inline::Text("italic".to_string())].into()),
                 Inline::Text(" text with special characters: $ & % # ^ _ { } ~ \\".to_string()),
             ]),
             Block::CodeBlock(CodeBlock {
@@ -38,6 +38,7 @@ fn main() {
                         )])],
                     },
                 ],
+                tight: true,
             }),
         ],
     };
@@ -38,12 +38,12 @@ fn main() {
                 kind: HeadingKind::Atx(1),
                 content: vec![
                     Inline::Text("Complex".to_string()),
-                    Inline::Strong(vec![Inline::Text("Heading".to_string())]),
+                    Inline::Strong(vec![Inline::Text("Heading".to_string())].into()),
                 ],
             }),
             Block::Paragraph(vec![
                 Inline::Text("With ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("nested".to_string())]),
+                Inline::Emphasis(vec![Inline::Text("nested".to_string())].into()),
                 Inline::Text(" content.".to_string()),
             ]),
         ],
@@ -85,6 +85,7 @@ fn create_document_with_ids() -> with_ids::Document {
                         user_data: id_gen.generate(),
                     },
                     generic::Inline::Strong {
+                        delimiter: EmphasisDelimiter::Asterisk,
                         content: vec![generic::Inline::Text {
                             content: "Document".to_string(),
                             user_data: id_gen.generate(),
@@ -101,6 +102,7 @@ fn create_document_with_ids() -> with_ids::Document {
                         user_data: id_gen.generate(),
                     },
                     generic::Inline::Emphasis {
+                        delimiter: EmphasisDelimiter::Asterisk,
                         content: vec![generic::Inline::Text {
                             content: "complex".to_string(),
                             user_data: id_gen.generate(),
@@ -141,6 +143,7 @@ fn create_document_with_ids() -> with_ids::Document {
                     },
                 ],
                 user_data: id_gen.generate(),
+                tight: true,
             }),
         ],
         user_data: id_gen.generate(),
@@ -221,7 +224,12 @@ impl MapDataVisitor<ElementId, ElementMetadata> for MetadataVisitor {
                     depth: self.depth,
                 },
             },
-            generic::Inline::Strong { content, user_data } => generic::Inline::Strong {
+            generic::Inline::Strong {
+                delimiter,
+                content,
+                user_data,
+            } => generic::Inline::Strong {
+                delimiter,
                 content: content.into_iter().map(|i| self.visit_inline(i)).collect(),
                 user_data: ElementMetadata {
                     original_id: user_data.id(),
@@ -229,7 +237,12 @@ impl MapDataVisitor<ElementId, ElementMetadata> for MetadataVisitor {
                     depth: self.depth,
                 },
             },
-            generic::Inline::Emphasis { content, user_data } => generic::Inline::Emphasis {
+            generic::Inline::Emphasis {
+                delimiter,
+                content,
+                user_data,
+            } => generic::Inline::Emphasis {
+                delimiter,
                 content: content.into_iter().map(|i| self.visit_inline(i)).collect(),
                 user_data: ElementMetadata {
                     original_id: user_data.id(),
@@ -20,12 +20,12 @@ fn main() {
                 kind: HeadingKind::Atx(1),
                 content: vec![
                     Inline::Text("Welcome to".to_string()),
-                    Inline::Strong(vec![Inline::Text("Markdown".to_string())]),
+                    Inline::Strong(vec![Inline::Text("Markdown".to_string())].into()),
                 ],
             }),
             Block::Paragraph(vec![
                 Inline::Text("This is a ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("simple".to_string())]),
+                Inline::Emphasis(vec![Inline::Text("simple".to_string())].into()),
                 Inline::Text(" example.".to_string()),
             ]),
             Block::List(List {
@@ -44,6 +44,7 @@ fn main() {
                         )])],
                     },
                 ],
+                tight: true,
             }),
         ],
     };
@@ -111,6 +112,7 @@ fn add_element_ids(_doc: Document) -> with_ids::Document {
                         user_data: ElementId::new(2),
                     },
                     generic::Inline::Strong {
+                        delimiter: EmphasisDelimiter::Asterisk,
                         content: vec![generic::Inline::Text {
                             content: "Markdown".to_string(),
                             user_data: ElementId::new(3),
@@ -127,6 +129,7 @@ fn add_element_ids(_doc: Document) -> with_ids::Document {
                         user_data: ElementId::new(5),
                     },
                     generic::Inline::Emphasis {
+                        delimiter: EmphasisDelimiter::Asterisk,
                         content: vec![generic::Inline::Text {
                             content: "simple".to_string(),
                             user_data: ElementId::new(6),
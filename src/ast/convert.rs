@@ -89,11 +89,21 @@ impl<T: Default> WithData<T> for Block {
                 user_data: data,
             },
             Block::List(list) => generic::Block::List(list.with_data(data)),
+            Block::DefinitionList(list) => generic::Block::DefinitionList(list.with_data(data)),
             Block::CodeBlock(code_block) => generic::Block::CodeBlock(code_block.with_data(data)),
+            Block::Math(content) => generic::Block::Math {
+                content,
+                user_data: data,
+            },
             Block::HtmlBlock(content) => generic::Block::HtmlBlock {
                 content,
                 user_data: data,
             },
+            Block::FrontMatter { format, raw } => generic::Block::FrontMatter {
+                format,
+                raw,
+                user_data: data,
+            },
             Block::Definition(def) => generic::Block::Definition(def.with_data(data)),
             Block::Table(table) => generic::Block::Table(table.with_data(data)),
             Block::FootnoteDefinition(footnote) => {
@@ -115,10 +125,15 @@ impl<T: Default> WithData<T> for Inline {
                 user_data: data,
             },
             Inline::LineBreak => generic::Inline::LineBreak { user_data: data },
+            Inline::SoftBreak => generic::Inline::SoftBreak { user_data: data },
             Inline::Code(content) => generic::Inline::Code {
                 content,
                 user_data: data,
             },
+            Inline::Math(content) => generic::Inline::Math {
+                content,
+                user_data: data,
+            },
             Inline::Html(content) => generic::Inline::Html {
                 content,
                 user_data: data,
@@ -128,14 +143,16 @@ impl<T: Default> WithData<T> for Inline {
                 generic::Inline::LinkReference(link_ref.with_data(data))
             }
             Inline::Image(image) => generic::Inline::Image(image.with_data(data)),
-            Inline::Emphasis(content) => generic::Inline::Emphasis {
+            Inline::Emphasis(Emphasis { delimiter, content }) => generic::Inline::Emphasis {
+                delimiter,
                 content: content
                     .into_iter()
                     .map(|i| i.with_data(T::default()))
                     .collect(),
                 user_data: data,
             },
-            Inline::Strong(content) => generic::Inline::Strong {
+            Inline::Strong(Emphasis { delimiter, content }) => generic::Inline::Strong {
+                delimiter,
                 content: content
                     .into_iter()
                     .map(|i| i.with_data(T::default()))
@@ -189,6 +206,7 @@ impl<T: Default> WithData<T> for List {
                 .into_iter()
                 .map(|i| i.with_data(T::default()))
                 .collect(),
+            tight: self.tight,
             user_data: data,
         }
     }
@@ -210,6 +228,46 @@ impl<T: Default> WithData<T> for ListItem {
     }
 }
 
+impl<T: Default> WithData<T> for DefinitionList {
+    type WithDataType = generic::DefinitionList<T>;
+
+    fn with_data(self, data: T) -> Self::WithDataType {
+        generic::DefinitionList {
+            items: self
+                .items
+                .into_iter()
+                .map(|i| i.with_data(T::default()))
+                .collect(),
+            user_data: data,
+        }
+    }
+}
+
+impl<T: Default> WithData<T> for DefinitionListItem {
+    type WithDataType = generic::DefinitionListItem<T>;
+
+    fn with_data(self, data: T) -> Self::WithDataType {
+        generic::DefinitionListItem {
+            term: self
+                .term
+                .into_iter()
+                .map(|i| i.with_data(T::default()))
+                .collect(),
+            definitions: self
+                .definitions
+                .into_iter()
+                .map(|blocks| {
+                    blocks
+                        .into_iter()
+                        .map(|b| b.with_data(T::default()))
+                        .collect()
+                })
+                .collect(),
+            user_data: data,
+        }
+    }
+}
+
 impl<T: Default> WithData<T> for CodeBlock {
     type WithDataType = generic::CodeBlock<T>;
 
@@ -373,8 +431,11 @@ impl<T> StripData<T> for generic::Block<T> {
                 Block::BlockQuote(blocks.into_iter().map(|b| b.strip_data()).collect())
             }
             generic::Block::List(list) => Block::List(list.strip_data()),
+            generic::Block::DefinitionList(list) => Block::DefinitionList(list.strip_data()),
             generic::Block::CodeBlock(code_block) => Block::CodeBlock(code_block.strip_data()),
+            generic::Block::Math { content, .. } => Block::Math(content),
             generic::Block::HtmlBlock { content, .. } => Block::HtmlBlock(content),
+            generic::Block::FrontMatter { format, raw, .. } => Block::FrontMatter { format, raw },
             generic::Block::Definition(def) => Block::Definition(def.strip_data()),
             generic::Block::Table(table) => Block::Table(table.strip_data()),
             generic::Block::FootnoteDefinition(footnote) => {
@@ -393,19 +454,27 @@ impl<T> StripData<T> for generic::Inline<T> {
         match self {
             generic::Inline::Text { content, .. } => Inline::Text(content),
             generic::Inline::LineBreak { .. } => Inline::LineBreak,
+            generic::Inline::SoftBreak { .. } => Inline::SoftBreak,
             generic::Inline::Code { content, .. } => Inline::Code(content),
+            generic::Inline::Math { content, .. } => Inline::Math(content),
             generic::Inline::Html { content, .. } => Inline::Html(content),
             generic::Inline::Link(link) => Inline::Link(link.strip_data()),
             generic::Inline::LinkReference(link_ref) => {
                 Inline::LinkReference(link_ref.strip_data())
             }
             generic::Inline::Image(image) => Inline::Image(image.strip_data()),
-            generic::Inline::Emphasis { content, .. } => {
-                Inline::Emphasis(content.into_iter().map(|i| i.strip_data()).collect())
-            }
-            generic::Inline::Strong { content, .. } => {
-                Inline::Strong(content.into_iter().map(|i| i.strip_data()).collect())
-            }
+            generic::Inline::Emphasis {
+                delimiter, content, ..
+            } => Inline::Emphasis(Emphasis {
+                delimiter,
+                content: content.into_iter().map(|i| i.strip_data()).collect(),
+            }),
+            generic::Inline::Strong {
+                delimiter, content, ..
+            } => Inline::Strong(Emphasis {
+                delimiter,
+                content: content.into_iter().map(|i| i.strip_data()).collect(),
+            }),
             generic::Inline::Strikethrough { content, .. } => {
                 Inline::Strikethrough(content.into_iter().map(|i| i.strip_data()).collect())
             }
@@ -434,6 +503,7 @@ impl<T> StripData<T> for generic::List<T> {
         List {
             kind: self.kind.into(),
             items: self.items.into_iter().map(|i| i.strip_data()).collect(),
+            tight: self.tight,
         }
     }
 }
@@ -449,6 +519,31 @@ impl<T> StripData<T> for generic::ListItem<T> {
     }
 }
 
+impl<T> StripData<T> for generic::DefinitionList<T> {
+    type StrippedType = DefinitionList;
+
+    fn strip_data(self) -> Self::StrippedType {
+        DefinitionList {
+            items: self.items.into_iter().map(|i| i.strip_data()).collect(),
+        }
+    }
+}
+
+impl<T> StripData<T> for generic::DefinitionListItem<T> {
+    type StrippedType = DefinitionListItem;
+
+    fn strip_data(self) -> Self::StrippedType {
+        DefinitionListItem {
+            term: self.term.into_iter().map(|i| i.strip_data()).collect(),
+            definitions: self
+                .definitions
+                .into_iter()
+                .map(|blocks| blocks.into_iter().map(|b| b.strip_data()).collect())
+                .collect(),
+        }
+    }
+}
+
 impl<T> StripData<T> for generic::CodeBlock<T> {
     type StrippedType = CodeBlock;
 
@@ -588,11 +683,21 @@ impl<T, U> MapData<T, U> for generic::Block<T> {
                 user_data: f(user_data),
             },
             generic::Block::List(list) => generic::Block::List(list.map_data(f)),
+            generic::Block::DefinitionList(list) => generic::Block::DefinitionList(list.map_data(f)),
             generic::Block::CodeBlock(code_block) => generic::Block::CodeBlock(code_block.map_data(f)),
+            generic::Block::Math { content, user_data } => generic::Block::Math {
+                content,
+                user_data: f(user_data),
+            },
             generic::Block::HtmlBlock { content, user_data } => generic::Block::HtmlBlock {
                 content,
                 user_data: f(user_data),
             },
+            generic::Block::FrontMatter { format, raw, user_data } => generic::Block::FrontMatter {
+                format,
+                raw,
+                user_data: f(user_data),
+            },
             generic::Block::Definition(def) => generic::Block::Definition(def.map_data(f)),
             generic::Block::Table(table) => generic::Block::Table(table.map_data(f)),
             generic::Block::FootnoteDefinition(footnote) => generic::Block::FootnoteDefinition(footnote.map_data(f)),
@@ -615,10 +720,15 @@ impl<T, U> MapData<T, U> for generic::Inline<T> {
                 user_data: f(user_data),
             },
             generic::Inline::LineBreak { user_data } => generic::Inline::LineBreak { user_data: f(user_data) },
+            generic::Inline::SoftBreak { user_data } => generic::Inline::SoftBreak { user_data: f(user_data) },
             generic::Inline::Code { content, user_data } => generic::Inline::Code {
                 content,
                 user_data: f(user_data),
             },
+            generic::Inline::Math { content, user_data } => generic::Inline::Math {
+                content,
+                user_data: f(user_data),
+            },
             generic::Inline::Html { content, user_data } => generic::Inline::Html {
                 content,
                 user_data: f(user_data),
@@ -679,6 +789,7 @@ impl<T, U> MapData<T, U> for generic::List<T> {
         generic::List {
             kind: self.kind,
             items: self.items.into_iter().map(|i| i.map_data(&mut f)).collect(),
+            tight: self.tight,
             user_data: f(self.user_data),
         }
     }
@@ -699,6 +810,39 @@ impl<T, U> MapData<T, U> for generic::ListItem<T> {
     }
 }
 
+impl<T, U> MapData<T, U> for generic::DefinitionList<T> {
+    type MappedType = generic::DefinitionList<U>;
+
+    fn map_data<F>(self, mut f: F) -> Self::MappedType
+    where
+        F: FnMut(T) -> U,
+    {
+        generic::DefinitionList {
+            items: self.items.into_iter().map(|i| i.map_data(&mut f)).collect(),
+            user_data: f(self.user_data),
+        }
+    }
+}
+
+impl<T, U> MapData<T, U> for generic::DefinitionListItem<T> {
+    type MappedType = generic::DefinitionListItem<U>;
+
+    fn map_data<F>(self, mut f: F) -> Self::MappedType
+    where
+        F: FnMut(T) -> U,
+    {
+        generic::DefinitionListItem {
+            term: self.term.into_iter().map(|i| i.map_data(&mut f)).collect(),
+            definitions: self
+                .definitions
+                .into_iter()
+                .map(|blocks| blocks.into_iter().map(|b| b.map_data(&mut f)).collect())
+                .collect(),
+            user_data: f(self.user_data),
+        }
+    }
+}
+
 impl<T, U> MapData<T, U> for generic::Link<T> {
     type MappedType = generic::Link<U>;
 
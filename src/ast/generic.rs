@@ -36,8 +36,8 @@
 
 // Re-export types from parent module that don't need generics
 pub use super::{
-    Alignment, CodeBlockKind, GitHubAlert, GitHubAlertType, HeadingKind, ListBulletKind,
-    ListOrderedKindOptions, SetextHeading, TaskState,
+    Alignment, CodeBlockKind, EmphasisDelimiter, FrontMatterFormat, GitHubAlert, GitHubAlertType,
+    HeadingKind, ListBulletKind, ListOrderedKindOptions, SetextHeading, TaskState,
 };
 
 // ——————————————————————————————————————————————————————————————————————————
@@ -90,9 +90,19 @@ pub enum Block<T = ()> {
     /// List (bullet or ordered)
     List(List<T>),
 
+    /// Definition list (`Term` followed by `: definition` lines)
+    DefinitionList(DefinitionList<T>),
+
     /// Fenced or indented code block
     CodeBlock(CodeBlock<T>),
 
+    /// Display math (`$$ … $$`), stored verbatim
+    Math {
+        content: String,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
     /// Raw HTML block
     HtmlBlock {
         content: String,
@@ -112,6 +122,14 @@ pub enum Block<T = ()> {
     /// GitHub alert block (NOTE, TIP, IMPORTANT, WARNING, CAUTION)
     GitHubAlert(GitHubAlertNode<T>),
 
+    /// Front matter at the very beginning of the document
+    FrontMatter {
+        format: FrontMatterFormat,
+        raw: String,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
     /// Empty block. This is used to represent skipped blocks in the AST.
     Empty {
         #[cfg_attr(feature = "ast-serde", serde(default))]
@@ -149,6 +167,11 @@ pub struct List<T = ()> {
     /// List items in source order.
     pub items: Vec<ListItem<T>>,
 
+    /// `true` for a tight list, whose items are not separated by blank lines.
+    /// Paragraphs of tight list items are rendered without `<p>` in HTML.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub tight: bool,
+
     /// User-defined data associated with this list
     #[cfg_attr(feature = "ast-serde", serde(default))]
     pub user_data: T,
@@ -180,6 +203,37 @@ pub struct ListItem<T = ()> {
     pub user_data: T,
 }
 
+// ——————————————————————————————————————————————————————————————————————————
+// Definition lists
+// ——————————————————————————————————————————————————————————————————————————
+
+/// Definition list (PHP Markdown Extra / Pandoc style).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionList<T = ()> {
+    /// Terms with their definitions **in document order**.
+    pub items: Vec<DefinitionListItem<T>>,
+
+    /// User-defined data associated with this definition list
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub user_data: T,
+}
+
+/// A term together with its definitions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionListItem<T = ()> {
+    /// Inline content of the term.
+    pub term: Vec<Inline<T>>,
+
+    /// Definitions of the term, each a sequence of blocks.
+    pub definitions: Vec<Vec<Block<T>>>,
+
+    /// User-defined data associated with this item
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub user_data: T,
+}
+
 // ——————————————————————————————————————————————————————————————————————————
 // Code blocks
 // ——————————————————————————————————————————————————————————————————————————
@@ -306,6 +360,13 @@ pub enum Inline<T = ()> {
         user_data: T,
     },
 
+    /// Soft line break: a line ending inside a paragraph that is not a hard
+    /// line break
+    SoftBreak {
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
     /// Inline code span
     Code {
         content: String,
@@ -313,6 +374,13 @@ pub enum Inline<T = ()> {
         user_data: T,
     },
 
+    /// Inline math (`$…$`), stored verbatim
+    Math {
+        content: String,
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        user_data: T,
+    },
+
     /// Raw HTML fragment
     Html {
         content: String,
@@ -331,6 +399,8 @@ pub enum Inline<T = ()> {
 
     /// Emphasis (`*` / `_`)
     Emphasis {
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        delimiter: EmphasisDelimiter,
         content: Vec<Inline<T>>,
         #[cfg_attr(feature = "ast-serde", serde(default))]
         user_data: T,
@@ -338,6 +408,8 @@ pub enum Inline<T = ()> {
 
     /// Strong emphasis (`**` / `__`)
     Strong {
+        #[cfg_attr(feature = "ast-serde", serde(default))]
+        delimiter: EmphasisDelimiter,
         content: Vec<Inline<T>>,
         #[cfg_attr(feature = "ast-serde", serde(default))]
         user_data: T,
@@ -422,6 +494,52 @@ pub struct LinkReference<T = ()> {
     pub user_data: T,
 }
 
+impl<T> Block<T> {
+    /// User data attached to the block itself
+    pub fn user_data(&self) -> &T {
+        match self {
+            Block::Paragraph { user_data, .. }
+            | Block::ThematicBreak { user_data }
+            | Block::BlockQuote { user_data, .. }
+            | Block::HtmlBlock { user_data, .. }
+            | Block::Math { user_data, .. }
+            | Block::FrontMatter { user_data, .. }
+            | Block::Empty { user_data } => user_data,
+            Block::Heading(v) => &v.user_data,
+            Block::List(v) => &v.user_data,
+            Block::DefinitionList(v) => &v.user_data,
+            Block::CodeBlock(v) => &v.user_data,
+            Block::Definition(v) => &v.user_data,
+            Block::Table(v) => &v.user_data,
+            Block::FootnoteDefinition(v) => &v.user_data,
+            Block::GitHubAlert(v) => &v.user_data,
+        }
+    }
+}
+
+impl<T> Inline<T> {
+    /// User data attached to the inline itself
+    pub fn user_data(&self) -> &T {
+        match self {
+            Inline::Text { user_data, .. }
+            | Inline::LineBreak { user_data }
+            | Inline::SoftBreak { user_data }
+            | Inline::Code { user_data, .. }
+            | Inline::Math { user_data, .. }
+            | Inline::Html { user_data, .. }
+            | Inline::Emphasis { user_data, .. }
+            | Inline::Strong { user_data, .. }
+            | Inline::Strikethrough { user_data, .. }
+            | Inline::Autolink { user_data, .. }
+            | Inline::FootnoteReference { user_data, .. }
+            | Inline::Empty { user_data } => user_data,
+            Inline::Link(v) => &v.user_data,
+            Inline::LinkReference(v) => &v.user_data,
+            Inline::Image(v) => &v.user_data,
+        }
+    }
+}
+
 // ——————————————————————————————————————————————————————————————————————————
 // Default implementations for common cases
 // ——————————————————————————————————————————————————————————————————————————
@@ -450,6 +568,7 @@ impl<T: Default> Default for List<T> {
         Self {
             kind: ListKind::Bullet(ListBulletKind::Dash),
             items: Vec::new(),
+            tight: true,
             user_data: T::default(),
         }
     }
@@ -8,7 +8,7 @@ use std::collections::HashMap;
 
 /// Assign numeric indices (1, 2, 3, ...) to footnote definitions by document order.
 ///
-/// Recursively traverses all nested blocks (lists, blockquotes, alerts)
+/// Recursively traverses all nested blocks (lists, definition lists, blockquotes, alerts)
 /// to find every `FootnoteDefinition`.
 pub fn get_footnote_indices(ast: &Document) -> HashMap<String, usize> {
     let mut index = HashMap::new();
@@ -26,6 +26,11 @@ pub fn get_footnote_indices(ast: &Document) -> HashMap<String, usize> {
                         process(&item.blocks, index, counter);
                     }
                 }
+                Block::DefinitionList(list) => {
+                    for definition in list.items.iter().flat_map(|i| &i.definitions) {
+                        process(definition, index, counter);
+                    }
+                }
                 Block::BlockQuote(blocks) => process(blocks, index, counter),
                 Block::GitHubAlert(alert) => process(&alert.blocks, index, counter),
                 _ => {}
@@ -54,6 +59,11 @@ pub fn get_link_definitions(ast: &Document) -> HashMap<Vec<Inline>, LinkDefiniti
                         process(&item.blocks, defs);
                     }
                 }
+                Block::DefinitionList(list) => {
+                    for definition in list.items.iter().flat_map(|i| &i.definitions) {
+                        process(definition, defs);
+                    }
+                }
                 Block::BlockQuote(blocks) => process(blocks, defs),
                 Block::GitHubAlert(alert) => process(&alert.blocks, defs),
                 _ => {}
@@ -121,6 +131,7 @@ mod tests {
                     blocks: vec![],
                 })],
             }],
+            tight: true,
         })]);
         let indices = get_footnote_indices(&ast);
         assert_eq!(indices.len(), 1);
@@ -151,6 +162,7 @@ mod tests {
                     blocks: vec![],
                 })],
             }],
+            tight: true,
         })])]);
         let indices = get_footnote_indices(&ast);
         assert_eq!(indices.len(), 1);
@@ -191,6 +203,7 @@ mod tests {
                     title: Some("Nested".into()),
                 })],
             }],
+            tight: true,
         })]);
         let defs = get_link_definitions(&ast);
         assert_eq!(defs.len(), 1);
@@ -226,6 +239,7 @@ mod tests {
                     title: None,
                 })],
             }],
+            tight: true,
         })])]);
         let defs = get_link_definitions(&ast);
         assert_eq!(defs.len(), 1);
@@ -40,13 +40,29 @@ pub trait MapDataVisitor<T, U> {
                 user_data: self.map_data(user_data),
             },
             generic::Block::List(list) => generic::Block::List(self.visit_list(list)),
+            generic::Block::DefinitionList(list) => {
+                generic::Block::DefinitionList(self.visit_definition_list(list))
+            }
             generic::Block::CodeBlock(code_block) => {
                 generic::Block::CodeBlock(self.visit_code_block(code_block))
             }
+            generic::Block::Math { content, user_data } => generic::Block::Math {
+                content,
+                user_data: self.map_data(user_data),
+            },
             generic::Block::HtmlBlock { content, user_data } => generic::Block::HtmlBlock {
                 content,
                 user_data: self.map_data(user_data),
             },
+            generic::Block::FrontMatter {
+                format,
+                raw,
+                user_data,
+            } => generic::Block::FrontMatter {
+                format,
+                raw,
+                user_data: self.map_data(user_data),
+            },
             generic::Block::Definition(def) => {
                 generic::Block::Definition(self.visit_link_definition(def))
             }
@@ -73,10 +89,17 @@ pub trait MapDataVisitor<T, U> {
             generic::Inline::LineBreak { user_data } => generic::Inline::LineBreak {
                 user_data: self.map_data(user_data),
             },
+            generic::Inline::SoftBreak { user_data } => generic::Inline::SoftBreak {
+                user_data: self.map_data(user_data),
+            },
             generic::Inline::Code { content, user_data } => generic::Inline::Code {
                 content,
                 user_data: self.map_data(user_data),
             },
+            generic::Inline::Math { content, user_data } => generic::Inline::Math {
+                content,
+                user_data: self.map_data(user_data),
+            },
             generic::Inline::Html { content, user_data } => generic::Inline::Html {
                 content,
                 user_data: self.map_data(user_data),
@@ -86,11 +109,21 @@ pub trait MapDataVisitor<T, U> {
                 generic::Inline::LinkReference(self.visit_link_reference(link_ref))
             }
             generic::Inline::Image(image) => generic::Inline::Image(self.visit_image(image)),
-            generic::Inline::Emphasis { content, user_data } => generic::Inline::Emphasis {
+            generic::Inline::Emphasis {
+                delimiter,
+                content,
+                user_data,
+            } => generic::Inline::Emphasis {
+                delimiter,
                 content: content.into_iter().map(|i| self.visit_inline(i)).collect(),
                 user_data: self.map_data(user_data),
             },
-            generic::Inline::Strong { content, user_data } => generic::Inline::Strong {
+            generic::Inline::Strong {
+                delimiter,
+                content,
+                user_data,
+            } => generic::Inline::Strong {
+                delimiter,
                 content: content.into_iter().map(|i| self.visit_inline(i)).collect(),
                 user_data: self.map_data(user_data),
             },
@@ -138,6 +171,7 @@ pub trait MapDataVisitor<T, U> {
                 .into_iter()
                 .map(|i| self.visit_list_item(i))
                 .collect(),
+            tight: list.tight,
             user_data: self.map_data(list.user_data),
         }
     }
@@ -155,6 +189,41 @@ pub trait MapDataVisitor<T, U> {
         }
     }
 
+    /// Transform a definition list
+    fn visit_definition_list(
+        &mut self,
+        list: generic::DefinitionList<T>,
+    ) -> generic::DefinitionList<U> {
+        generic::DefinitionList {
+            items: list
+                .items
+                .into_iter()
+                .map(|i| self.visit_definition_list_item(i))
+                .collect(),
+            user_data: self.map_data(list.user_data),
+        }
+    }
+
+    /// Transform a definition list item
+    fn visit_definition_list_item(
+        &mut self,
+        item: generic::DefinitionListItem<T>,
+    ) -> generic::DefinitionListItem<U> {
+        generic::DefinitionListItem {
+            term: item
+                .term
+                .into_iter()
+                .map(|i| self.visit_inline(i))
+                .collect(),
+            definitions: item
+                .definitions
+                .into_iter()
+                .map(|blocks| blocks.into_iter().map(|b| self.visit_block(b)).collect())
+                .collect(),
+            user_data: self.map_data(item.user_data),
+        }
+    }
+
     /// Transform a code block
     fn visit_code_block(&mut self, code_block: generic::CodeBlock<T>) -> generic::CodeBlock<U> {
         generic::CodeBlock {
@@ -361,6 +430,7 @@ mod tests {
                             user_data: 1u32,
                         },
                         generic::Inline::Strong {
+                            delimiter: crate::ast::EmphasisDelimiter::Asterisk,
                             content: vec![generic::Inline::Text {
                                 content: "Bold".to_string(),
                                 user_data: 2u32,
@@ -384,6 +454,7 @@ mod tests {
                         user_data: 7u32,
                     }],
                     user_data: 8u32,
+                    tight: true,
                 }),
             ],
             user_data: 9u32,
@@ -399,7 +470,9 @@ mod tests {
             generic::Block::Heading(heading) => {
                 assert_eq!(heading.user_data, 40);
                 match &heading.content[1] {
-                    generic::Inline::Strong { user_data, content } => {
+                    generic::Inline::Strong {
+                        user_data, content, ..
+                    } => {
                         assert_eq!(*user_data, 30);
                         match &content[0] {
                             generic::Inline::Text { user_data, .. } => {
@@ -34,6 +34,16 @@ pub mod map_data_visitor;
 mod github_alerts;
 pub use github_alerts::{GitHubAlert, GitHubAlertType};
 
+mod span;
+pub use span::{Position, Span};
+
+mod slug;
+#[cfg(feature = "lint")]
+pub(crate) use slug::inline_text;
+#[cfg(feature = "ast-transform")]
+pub(crate) use slug::normalize_label;
+pub use slug::{slugify, Slugger};
+
 // ——————————————————————————————————————————————————————————————————————————
 // Document root
 // ——————————————————————————————————————————————————————————————————————————
@@ -69,9 +79,15 @@ pub enum Block {
     /// List (bullet or ordered)
     List(List),
 
+    /// Definition list (`Term` followed by `: definition` lines)
+    DefinitionList(DefinitionList),
+
     /// Fenced or indented code block
     CodeBlock(CodeBlock),
 
+    /// Display math (`$$ … $$`), stored verbatim
+    Math(String),
+
     /// Raw HTML block
     HtmlBlock(String),
 
@@ -87,6 +103,15 @@ pub enum Block {
     /// GitHub alert block (NOTE, TIP, IMPORTANT, WARNING, CAUTION)
     GitHubAlert(GitHubAlert),
 
+    /// Front matter at the very beginning of the document
+    FrontMatter {
+        /// Syntax of the metadata, determined by the fence
+        format: FrontMatterFormat,
+
+        /// Metadata text between the fences, without a trailing newline
+        raw: String,
+    },
+
     /// Empty block. This is used to represent skipped blocks in the AST.
     Empty,
 }
@@ -124,6 +149,29 @@ pub enum SetextHeading {
     Level2,
 }
 
+/// Syntax of a front matter block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FrontMatterFormat {
+    /// YAML, fenced with `---`. The closing fence may also be `...`, which is
+    /// not kept: printers close YAML front matter with `---`.
+    Yaml,
+
+    /// TOML, fenced with `+++`
+    Toml,
+}
+
+impl FrontMatterFormat {
+    /// Fence line that opens and closes front matter of this format when it
+    /// is printed.
+    pub fn fence(self) -> &'static str {
+        match self {
+            FrontMatterFormat::Yaml => "---",
+            FrontMatterFormat::Toml => "+++",
+        }
+    }
+}
+
 // ——————————————————————————————————————————————————————————————————————————
 // Lists
 // ——————————————————————————————————————————————————————————————————————————
@@ -138,6 +186,11 @@ pub struct List {
 
     /// List items in source order.
     pub items: Vec<ListItem>,
+
+    /// `true` for a tight list, whose items are not separated by blank lines.
+    /// Paragraphs of tight list items are rendered without `<p>` in HTML.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub tight: bool,
 }
 
 /// Specifies *what kind* of list we have.
@@ -195,6 +248,29 @@ pub enum TaskState {
     Complete,
 }
 
+// ——————————————————————————————————————————————————————————————————————————
+// Definition lists
+// ——————————————————————————————————————————————————————————————————————————
+
+/// Definition list (PHP Markdown Extra / Pandoc style).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionList {
+    /// Terms with their definitions **in document order**.
+    pub items: Vec<DefinitionListItem>,
+}
+
+/// A term together with its definitions.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DefinitionListItem {
+    /// Inline content of the term.
+    pub term: Vec<Inline>,
+
+    /// Definitions of the term, each a sequence of blocks.
+    pub definitions: Vec<Vec<Block>>,
+}
+
 // ——————————————————————————————————————————————————————————————————————————
 // Code blocks
 // ——————————————————————————————————————————————————————————————————————————
@@ -311,9 +387,16 @@ pub enum Inline {
     /// Hard line break
     LineBreak,
 
+    /// Soft line break: a line ending inside a paragraph that is not a hard
+    /// line break
+    SoftBreak,
+
     /// Inline code span
     Code(String),
 
+    /// Inline math (`$…$`), stored verbatim
+    Math(String),
+
     /// Raw HTML fragment
     Html(String),
 
@@ -327,9 +410,9 @@ pub enum Inline {
     Image(Image),
 
     /// Emphasis (`*` / `_`)
-    Emphasis(Vec<Inline>),
+    Emphasis(Emphasis),
     /// Strong emphasis (`**` / `__`)
-    Strong(Vec<Inline>),
+    Strong(Emphasis),
     /// Strikethrough (`~~`)
     Strikethrough(Vec<Inline>),
 
@@ -343,6 +426,40 @@ pub enum Inline {
     Empty,
 }
 
+/// Content of an emphasis or strong emphasis together with its delimiter.
+#[derive(Debug, Clone, PartialEq, Hash, Eq)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Emphasis {
+    /// Delimiter character used in the source.
+    #[cfg_attr(feature = "ast-serde", serde(default))]
+    pub delimiter: EmphasisDelimiter,
+
+    /// Emphasized content.
+    pub content: Vec<Inline>,
+}
+
+/// Emphasis with the default `*` delimiter.
+impl From<Vec<Inline>> for Emphasis {
+    fn from(content: Vec<Inline>) -> Self {
+        Self {
+            delimiter: EmphasisDelimiter::default(),
+            content,
+        }
+    }
+}
+
+/// Delimiter character of an emphasis or strong emphasis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EmphasisDelimiter {
+    /// `*` U+002A
+    #[default]
+    Asterisk,
+
+    /// `_` U+005F
+    Underscore,
+}
+
 /// Re‑usable structure for links and images (destination + children).
 #[derive(Debug, Clone, PartialEq, Hash, Eq)]
 #[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
@@ -404,6 +521,12 @@ pub type SimpleList = generic::List<()>;
 /// Simple list item without user data (backward compatible)
 pub type SimpleListItem = generic::ListItem<()>;
 
+/// Simple definition list without user data (backward compatible)
+pub type SimpleDefinitionList = generic::DefinitionList<()>;
+
+/// Simple definition list item without user data (backward compatible)
+pub type SimpleDefinitionListItem = generic::DefinitionListItem<()>;
+
 /// Simple code block without user data (backward compatible)
 pub type SimpleCodeBlock = generic::CodeBlock<()>;
 
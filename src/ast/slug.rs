@@ -0,0 +1,110 @@
+use crate::ast::{Emphasis, Heading, Inline};
+use std::collections::HashMap;
+
+/// Convert `text` to a GitHub-compatible anchor slug.
+///
+/// The text is lowercased, punctuation is removed and every space becomes a
+/// `-`. Use [`Slugger`] to also disambiguate repeated slugs.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::slugify;
+///
+/// assert_eq!(slugify("Hello, World!"), "hello-world");
+/// assert_eq!(slugify("What's new in 2.0?"), "whats-new-in-20");
+/// assert_eq!(slugify("Ünïcödé text"), "ünïcödé-text");
+/// ```
+pub fn slugify(text: &str) -> String {
+    text.chars()
+        .filter(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+/// Generator of unique GitHub-compatible slugs.
+///
+/// Repeated slugs get a numeric suffix: the second `Intro` heading becomes
+/// `intro-1`, the third `intro-2`, and so on. Use one slugger per document.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::Slugger;
+///
+/// let mut slugger = Slugger::new();
+/// assert_eq!(slugger.slug("Intro"), "intro");
+/// assert_eq!(slugger.slug("Intro"), "intro-1");
+/// assert_eq!(slugger.slug("Intro 1"), "intro-1-1");
+/// assert_eq!(slugger.slug("Intro"), "intro-2");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Slugger {
+    occurrences: HashMap<String, usize>,
+}
+
+impl Slugger {
+    /// Create a slugger that has not seen any slugs yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Slug for `text`, unique among the slugs returned so far
+    pub fn slug(&mut self, text: &str) -> String {
+        let base = slugify(text);
+        let mut slug = base.clone();
+        while self.occurrences.contains_key(&slug) {
+            let count = self.occurrences.entry(base.clone()).or_default();
+            *count += 1;
+            slug = format!("{base}-{count}");
+        }
+        self.occurrences.insert(slug.clone(), 0);
+        slug
+    }
+
+    /// Slug for the text content of `heading`
+    pub fn heading_slug(&mut self, heading: &Heading) -> String {
+        self.slug(&inline_text(&heading.content))
+    }
+}
+
+/// Text content of inlines, the way it is shown to the reader.
+pub(crate) fn inline_text(inlines: &[Inline]) -> String {
+    fn collect(inlines: &[Inline], out: &mut String) {
+        for inline in inlines {
+            match inline {
+                Inline::Text(s) | Inline::Code(s) | Inline::Math(s) | Inline::Autolink(s) => {
+                    out.push_str(s)
+                }
+                Inline::LineBreak | Inline::SoftBreak => out.push(' '),
+                Inline::Link(link) => collect(&link.children, out),
+                Inline::LinkReference(reference) => collect(&reference.text, out),
+                Inline::Image(image) => out.push_str(&image.alt),
+                Inline::Emphasis(Emphasis {
+                    content: children, ..
+                })
+                | Inline::Strong(Emphasis {
+                    content: children, ..
+                })
+                | Inline::Strikethrough(children) => collect(children, out),
+                Inline::Html(_) | Inline::FootnoteReference(_) | Inline::Empty => {}
+            }
+        }
+    }
+
+    let mut out = String::new();
+    collect(inlines, &mut out);
+    out
+}
+
+/// Reference label the way definitions are matched: case-insensitive, with
+/// collapsed whitespace
+#[cfg(feature = "ast-transform")]
+pub(crate) fn normalize_label(label: &[Inline]) -> String {
+    inline_text(label)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
@@ -0,0 +1,78 @@
+/// A location in the source text.
+///
+/// `offset` is a byte offset into the original input. `line` and `column`
+/// are 1-based; `column` counts Unicode scalar values (characters) from the
+/// start of the line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    /// Byte offset from the start of the input
+    pub offset: usize,
+    /// Line number, starting at 1
+    pub line: usize,
+    /// Column number in characters, starting at 1
+    pub column: usize,
+}
+
+impl Position {
+    /// Compute the line and column of a byte offset in `input`.
+    ///
+    /// Offsets past the end of `input` are clamped to its length.
+    pub fn from_offset(input: &str, offset: usize) -> Self {
+        let offset = offset.min(input.len());
+        let before = &input.as_bytes()[..offset];
+        let line_start = before
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .map_or(0, |i| i + 1);
+        let column = input
+            .get(line_start..offset)
+            .map_or(offset - line_start, |s| s.chars().count());
+        Self {
+            offset,
+            line: before.iter().filter(|&&b| b == b'\n').count() + 1,
+            column: column + 1,
+        }
+    }
+}
+
+impl Default for Position {
+    fn default() -> Self {
+        Self {
+            offset: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+}
+
+/// Source range covered by an AST node.
+///
+/// `start` is inclusive and `end` is exclusive, so the node text is
+/// `&input[span.start.offset..span.end.offset]`. Trailing line endings of
+/// block-level nodes are not part of the span.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "ast-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Span {
+    /// First position covered by the node
+    pub start: Position,
+    /// Position right after the node
+    pub end: Position,
+}
+
+impl Span {
+    /// Byte range of the span in the original input
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start.offset..self.end.offset
+    }
+
+    /// Length of the span in bytes
+    pub fn len(&self) -> usize {
+        self.end.offset.saturating_sub(self.start.offset)
+    }
+
+    /// Whether the span covers no input
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -0,0 +1,436 @@
+//! Automatic fixes for common document problems
+//!
+//! Each fix is a [`Transformer`], so fixes can be applied one by one with
+//! [`TransformWith::transform_with`](super::TransformWith::transform_with) or
+//! chained with
+//! [`TransformPipeline::transform_with`](super::TransformPipeline::transform_with).
+//! Most of them fix a problem reported by a rule of the `lint` module:
+//!
+//! | Fix                            | Lint rule           |
+//! |--------------------------------|---------------------|
+//! | [`NormalizeHeadingIncrements`] | `heading-increment` |
+//! | [`LinkifyBareUrls`]            |                     |
+//! | [`RemoveUnusedDefinitions`]    | `unused-definition` |
+//! | [`RenumberOrderedLists`]       |                     |
+//! | [`DeduplicateFootnotes`]       |                     |
+//!
+//! # Example
+//!
+//! ```rust
+//! use markdown_ppp::ast::*;
+//! use markdown_ppp::ast_transform::*;
+//!
+//! let doc = Document {
+//!     blocks: vec![
+//!         Block::Heading(Heading {
+//!             kind: HeadingKind::Atx(1),
+//!             content: vec![Inline::Text("Guide".to_string())],
+//!         }),
+//!         Block::Heading(Heading {
+//!             kind: HeadingKind::Atx(3),
+//!             content: vec![Inline::Text("See https://example.com".to_string())],
+//!         }),
+//!     ],
+//! };
+//!
+//! let doc = TransformPipeline::new()
+//!     .transform_with(NormalizeHeadingIncrements::default())
+//!     .transform_with(LinkifyBareUrls)
+//!     .apply(doc);
+//!
+//! assert_eq!(
+//!     doc.blocks[1],
+//!     Block::Heading(Heading {
+//!         kind: HeadingKind::Atx(2),
+//!         content: vec![
+//!             Inline::Text("See ".to_string()),
+//!             Inline::Autolink("https://example.com".to_string()),
+//!         ],
+//!     })
+//! );
+//! ```
+
+use super::toc::heading_level;
+use super::transformer::Transformer;
+use super::visitor::Visitor;
+use crate::ast::*;
+use std::collections::{HashMap, HashSet};
+
+/// Lower heading levels so that they only increase by one at a time.
+///
+/// Headings keep their nesting: in `#`, `###`, `###`, `#####` both `###`
+/// become `##` and the `#####` becomes `###`. Setext headings are never
+/// changed, other headings stay ATX headings.
+#[derive(Debug, Clone, Default)]
+pub struct NormalizeHeadingIncrements {
+    /// Original and new levels of the enclosing headings
+    stack: Vec<(u8, u8)>,
+}
+
+impl Transformer for NormalizeHeadingIncrements {
+    fn transform_document(&mut self, doc: Document) -> Document {
+        self.stack.clear();
+        self.walk_transform_document(doc)
+    }
+
+    fn transform_heading(&mut self, mut heading: Heading) -> Heading {
+        let level = heading_level(&heading);
+        while self.stack.last().is_some_and(|&(l, _)| l >= level) {
+            self.stack.pop();
+        }
+        let new_level = match self.stack.last() {
+            Some(&(_, parent)) => level.min(parent + 1),
+            None => level,
+        };
+        self.stack.push((level, new_level));
+        if new_level != level {
+            heading.kind = HeadingKind::Atx(new_level);
+        }
+        self.walk_transform_heading(heading)
+    }
+}
+
+/// Convert bare `http://` and `https://` URLs in text to autolinks.
+///
+/// Trailing punctuation and unbalanced closing parentheses are not part of the
+/// URL, as with GitHub's extended autolinks. Text in links is left alone.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinkifyBareUrls;
+
+impl LinkifyBareUrls {
+    fn linkify(&mut self, inlines: Vec<Inline>) -> Vec<Inline> {
+        let mut result = Vec::with_capacity(inlines.len());
+        for inline in inlines {
+            match inline {
+                Inline::Text(text) => linkify_text(&text, &mut result),
+                other => result.push(self.transform_inline(other)),
+            }
+        }
+        result
+    }
+}
+
+impl Transformer for LinkifyBareUrls {
+    fn transform_block(&mut self, block: Block) -> Block {
+        match block {
+            Block::Paragraph(inlines) => Block::Paragraph(self.linkify(inlines)),
+            other => self.walk_transform_block(other),
+        }
+    }
+
+    fn transform_inline(&mut self, inline: Inline) -> Inline {
+        match inline {
+            Inline::Emphasis(mut emphasis) => {
+                emphasis.content = self.linkify(emphasis.content);
+                Inline::Emphasis(emphasis)
+            }
+            Inline::Strong(mut strong) => {
+                strong.content = self.linkify(strong.content);
+                Inline::Strong(strong)
+            }
+            Inline::Strikethrough(inlines) => Inline::Strikethrough(self.linkify(inlines)),
+            // Links can not contain links
+            other => other,
+        }
+    }
+
+    fn transform_table_cell(&mut self, cell: TableCell) -> TableCell {
+        self.linkify(cell)
+    }
+
+    fn transform_heading(&mut self, mut heading: Heading) -> Heading {
+        heading.content = self.linkify(heading.content);
+        heading
+    }
+
+    fn transform_definition_list_item(
+        &mut self,
+        mut item: DefinitionListItem,
+    ) -> DefinitionListItem {
+        item.term = self.linkify(std::mem::take(&mut item.term));
+        self.walk_transform_definition_list_item(item)
+    }
+}
+
+/// Push `text` to `out`, with the URLs in it as autolinks
+fn linkify_text(text: &str, out: &mut Vec<Inline>) {
+    let mut rest = text;
+    while let Some((start, end)) = find_url(rest) {
+        if start > 0 {
+            out.push(Inline::Text(rest[..start].to_owned()));
+        }
+        out.push(Inline::Autolink(rest[start..end].to_owned()));
+        rest = &rest[end..];
+    }
+    if !rest.is_empty() || out.is_empty() {
+        out.push(Inline::Text(rest.to_owned()));
+    }
+}
+
+/// Byte range of the first URL in `text`
+fn find_url(text: &str) -> Option<(usize, usize)> {
+    let mut from = 0;
+    while let Some(i) = text[from..].find("http").map(|i| from + i) {
+        from = i + 1;
+        let Some(scheme) = ["https://", "http://"]
+            .into_iter()
+            .find(|s| text[i..].starts_with(s))
+        else {
+            continue;
+        };
+        let at_boundary = text[..i]
+            .chars()
+            .next_back()
+            .is_none_or(|c| c.is_whitespace() || matches!(c, '(' | '*' | '_' | '~'));
+        if !at_boundary {
+            continue;
+        }
+        let len = text[i..]
+            .find(|c: char| c.is_whitespace() || c == '<')
+            .unwrap_or(text.len() - i);
+        let url = trim_url_end(&text[i..i + len]);
+        if url.len() > scheme.len() {
+            return Some((i, i + url.len()));
+        }
+    }
+    None
+}
+
+/// `url` without trailing punctuation and unbalanced closing parentheses
+fn trim_url_end(mut url: &str) -> &str {
+    loop {
+        let trimmed =
+            url.trim_end_matches(['?', '!', '.', ',', ':', ';', '*', '_', '~', '\'', '"']);
+        let trimmed = match trimmed.strip_suffix(')') {
+            Some(s) if trimmed.matches(')').count() > trimmed.matches('(').count() => s,
+            _ => trimmed,
+        };
+        if trimmed.len() == url.len() {
+            return url;
+        }
+        url = trimmed;
+    }
+}
+
+/// Remove link definitions that no link reference uses.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RemoveUnusedDefinitions;
+
+impl Transformer for RemoveUnusedDefinitions {
+    fn transform_document(&mut self, doc: Document) -> Document {
+        struct UsedLabels(HashSet<String>);
+
+        impl Visitor for UsedLabels {
+            fn visit_inline(&mut self, inline: &Inline) {
+                if let Inline::LinkReference(reference) = inline {
+                    self.0.insert(normalize_label(&reference.label));
+                }
+                self.walk_inline(inline);
+            }
+        }
+
+        let mut used = UsedLabels(HashSet::new());
+        used.visit_document(&doc);
+        BlockFilter(|block: &Block| match block {
+            Block::Definition(definition) => used.0.contains(&normalize_label(&definition.label)),
+            _ => true,
+        })
+        .transform_document(doc)
+    }
+}
+
+/// Let ordered lists start at the same number, 1 by default.
+///
+/// The numbers of the other items are chosen by the printer, see
+/// `printer::config::Config::with_ordered_list_numbering`.
+#[derive(Debug, Clone, Copy)]
+pub struct RenumberOrderedLists {
+    start: u64,
+}
+
+impl Default for RenumberOrderedLists {
+    fn default() -> Self {
+        Self { start: 1 }
+    }
+}
+
+impl RenumberOrderedLists {
+    /// Number of the first item of every ordered list
+    pub fn with_start(self, start: u64) -> Self {
+        Self { start }
+    }
+}
+
+impl Transformer for RenumberOrderedLists {
+    fn transform_block(&mut self, block: Block) -> Block {
+        match self.walk_transform_block(block) {
+            Block::List(mut list) => {
+                if let ListKind::Ordered(options) = &mut list.kind {
+                    options.start = self.start;
+                }
+                Block::List(list)
+            }
+            other => other,
+        }
+    }
+}
+
+/// Remove repeated footnote definitions.
+///
+/// Of several definitions with the same label only the first one is kept.
+/// With [`with_merge_equal_content`](Self::with_merge_equal_content),
+/// definitions with the same content as an earlier one are removed as well,
+/// and their references point to the earlier definition instead.
+#[derive(Debug, Clone, Default)]
+pub struct DeduplicateFootnotes {
+    merge_equal_content: bool,
+    /// Label of the kept definition, by label of a removed one
+    replacements: HashMap<String, String>,
+}
+
+impl DeduplicateFootnotes {
+    /// Also merge definitions with different labels and the same content.
+    /// Disabled by default.
+    pub fn with_merge_equal_content(self, merge_equal_content: bool) -> Self {
+        Self {
+            merge_equal_content,
+            ..self
+        }
+    }
+}
+
+impl Transformer for DeduplicateFootnotes {
+    fn transform_document(&mut self, doc: Document) -> Document {
+        struct Definitions<'a>(Vec<&'a FootnoteDefinition>);
+
+        impl<'a> Definitions<'a> {
+            fn blocks(&mut self, blocks: &'a [Block]) {
+                for block in blocks {
+                    match block {
+                        Block::FootnoteDefinition(footnote) => {
+                            self.0.push(footnote);
+                            self.blocks(&footnote.blocks);
+                        }
+                        Block::BlockQuote(blocks) => self.blocks(blocks),
+                        Block::List(list) => {
+                            for item in &list.items {
+                                self.blocks(&item.blocks);
+                            }
+                        }
+                        Block::DefinitionList(list) => {
+                            for item in &list.items {
+                                for definition in &item.definitions {
+                                    self.blocks(definition);
+                                }
+                            }
+                        }
+                        Block::GitHubAlert(alert) => self.blocks(&alert.blocks),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        let mut definitions = Definitions(Vec::new());
+        definitions.blocks(&doc.blocks);
+        let mut kept: Vec<&FootnoteDefinition> = Vec::new();
+        self.replacements.clear();
+        for footnote in definitions.0 {
+            let label = &footnote.label;
+            if kept.iter().any(|k| &k.label == label) || self.replacements.contains_key(label) {
+                continue;
+            }
+            match kept.iter().find(|k| k.blocks == footnote.blocks) {
+                Some(k) if self.merge_equal_content => {
+                    self.replacements.insert(label.clone(), k.label.clone());
+                }
+                _ => kept.push(footnote),
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let replacements = &self.replacements;
+        let doc = BlockFilter(|block: &Block| match block {
+            Block::FootnoteDefinition(footnote) => {
+                !replacements.contains_key(&footnote.label) && seen.insert(footnote.label.clone())
+            }
+            _ => true,
+        })
+        .transform_document(doc);
+        self.walk_transform_document(doc)
+    }
+
+    fn transform_inline(&mut self, inline: Inline) -> Inline {
+        match inline {
+            Inline::FootnoteReference(label) => {
+                let label = self.replacements.get(&label).cloned().unwrap_or(label);
+                Inline::FootnoteReference(label)
+            }
+            other => self.walk_transform_inline(other),
+        }
+    }
+}
+
+/// Removes the blocks for which the predicate is false, at any depth. The
+/// predicate is called in document order, for the outer blocks first.
+pub(crate) struct BlockFilter<F>(pub(crate) F);
+
+impl<F: FnMut(&Block) -> bool> BlockFilter<F> {
+    fn blocks(&mut self, blocks: Vec<Block>) -> Vec<Block> {
+        let mut kept = Vec::with_capacity(blocks.len());
+        for block in blocks {
+            if (self.0)(&block) {
+                kept.push(self.transform_block(block));
+            }
+        }
+        kept
+    }
+}
+
+impl<F: FnMut(&Block) -> bool> Transformer for BlockFilter<F> {
+    fn transform_document(&mut self, mut doc: Document) -> Document {
+        doc.blocks = self.blocks(doc.blocks);
+        doc
+    }
+
+    fn transform_block(&mut self, block: Block) -> Block {
+        match block {
+            Block::BlockQuote(blocks) => Block::BlockQuote(self.blocks(blocks)),
+            other => self.walk_transform_block(other),
+        }
+    }
+
+    fn transform_list_item(&mut self, mut item: ListItem) -> ListItem {
+        item.blocks = self.blocks(item.blocks);
+        item
+    }
+
+    fn transform_definition_list_item(
+        &mut self,
+        mut item: DefinitionListItem,
+    ) -> DefinitionListItem {
+        item.definitions = std::mem::take(&mut item.definitions)
+            .into_iter()
+            .map(|blocks| self.blocks(blocks))
+            .collect();
+        item
+    }
+
+    fn transform_footnote_definition(
+        &mut self,
+        mut footnote: FootnoteDefinition,
+    ) -> FootnoteDefinition {
+        footnote.blocks = self.blocks(footnote.blocks);
+        footnote
+    }
+
+    fn transform_github_alert(&mut self, mut alert: GitHubAlert) -> GitHubAlert {
+        alert.blocks = self.blocks(alert.blocks);
+        alert
+    }
+
+    // Only blocks are filtered
+    fn transform_inline(&mut self, inline: Inline) -> Inline {
+        inline
+    }
+}
@@ -212,6 +212,9 @@ pub trait GenericTransformer<T> {
                 user_data,
             },
             Block::List(list) => Block::List(self.transform_list_item_container(list)),
+            Block::DefinitionList(list) => {
+                Block::DefinitionList(self.transform_definition_list(list))
+            }
             Block::Table(table) => Block::Table(self.transform_table(table)),
             Block::FootnoteDefinition(footnote) => {
                 Block::FootnoteDefinition(self.transform_footnote_definition(footnote))
@@ -234,14 +237,24 @@ pub trait GenericTransformer<T> {
     /// Default transformation for inline nodes with user data
     fn walk_transform_inline(&mut self, inline: Inline<T>) -> Inline<T> {
         match inline {
-            Inline::Emphasis { content, user_data } => Inline::Emphasis {
+            Inline::Emphasis {
+                delimiter,
+                content,
+                user_data,
+            } => Inline::Emphasis {
+                delimiter,
                 content: content
                     .into_iter()
                     .map(|inline| self.transform_inline(inline))
                     .collect(),
                 user_data,
             },
-            Inline::Strong { content, user_data } => Inline::Strong {
+            Inline::Strong {
+                delimiter,
+                content,
+                user_data,
+            } => Inline::Strong {
+                delimiter,
                 content: content
                     .into_iter()
                     .map(|inline| self.transform_inline(inline))
@@ -366,6 +379,23 @@ pub trait GenericTransformer<T> {
         list
     }
 
+    /// Helper to transform definition list
+    fn transform_definition_list(&mut self, mut list: DefinitionList<T>) -> DefinitionList<T> {
+        for item in &mut list.items {
+            item.term = std::mem::take(&mut item.term)
+                .into_iter()
+                .map(|inline| self.transform_inline(inline))
+                .collect();
+            for blocks in &mut item.definitions {
+                *blocks = std::mem::take(blocks)
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect();
+            }
+        }
+        list
+    }
+
     /// Helper to transform table
     fn transform_table(&mut self, mut table: Table<T>) -> Table<T> {
         table.rows = table
@@ -415,6 +445,21 @@ pub trait GenericTransformer<T> {
                 let expanded_list = self.expand_list_container(list);
                 return expanded_list.into_iter().map(Block::List).collect();
             }
+            Block::DefinitionList(mut list) => {
+                for item in &mut list.items {
+                    item.term = std::mem::take(&mut item.term)
+                        .into_iter()
+                        .flat_map(|inline| self.walk_expand_inline(inline))
+                        .collect();
+                    for blocks in &mut item.definitions {
+                        *blocks = std::mem::take(blocks)
+                            .into_iter()
+                            .flat_map(|block| self.walk_expand_block(block))
+                            .collect();
+                    }
+                }
+                Block::DefinitionList(list)
+            }
             Block::Table(table) => {
                 let expanded_table = self.expand_table_container(table);
                 return expanded_table.into_iter().map(Block::Table).collect();
@@ -457,14 +502,24 @@ pub trait GenericTransformer<T> {
     /// Default expandable transformation for inline nodes using flat_map
     fn walk_expand_inline(&mut self, inline: Inline<T>) -> Vec<Inline<T>> {
         let transformed_inline = match inline {
-            Inline::Emphasis { content, user_data } => Inline::Emphasis {
+            Inline::Emphasis {
+                delimiter,
+                content,
+                user_data,
+            } => Inline::Emphasis {
+                delimiter,
                 content: content
                     .into_iter()
                     .flat_map(|inline| self.walk_expand_inline(inline))
                     .collect(),
                 user_data,
             },
-            Inline::Strong { content, user_data } => Inline::Strong {
+            Inline::Strong {
+                delimiter,
+                content,
+                user_data,
+            } => Inline::Strong {
+                delimiter,
                 content: content
                     .into_iter()
                     .flat_map(|inline| self.walk_expand_inline(inline))
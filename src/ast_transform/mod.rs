@@ -6,6 +6,8 @@
 //! - Query API for finding elements by conditions
 //! - Convenience methods for common transformations
 //! - Pipeline builder for composing complex transformations
+//! - Table of contents generation
+//! - Automatic fixes for common document problems
 //!
 //! # Examples
 //!
@@ -38,9 +40,11 @@
 //! ```
 
 pub mod convenience;
+pub mod fixes;
 pub mod generic_transformer;
 pub mod pipeline;
 pub mod query;
+pub mod toc;
 pub mod transformer;
 pub mod visitor;
 
@@ -48,8 +52,10 @@ pub mod visitor;
 mod tests;
 
 pub use convenience::*;
+pub use fixes::*;
 pub use generic_transformer::*;
 pub use pipeline::*;
 pub use query::*;
+pub use toc::*;
 pub use transformer::*;
 pub use visitor::*;
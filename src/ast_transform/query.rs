@@ -336,6 +336,16 @@ where
                 }
             }
         }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                for inline in &item.term {
+                    collect_inlines_from_inline(inline, predicate, results);
+                }
+                for block in item.definitions.iter().flatten() {
+                    collect_inlines_from_block(block, predicate, results);
+                }
+            }
+        }
         Block::Table(table) => {
             for row in &table.rows {
                 for cell in row {
@@ -376,7 +386,13 @@ fn collect_inlines_from_inline<'a, F>(
     }
 
     match inline {
-        Inline::Emphasis(inlines) | Inline::Strong(inlines) | Inline::Strikethrough(inlines) => {
+        Inline::Emphasis(Emphasis {
+            content: inlines, ..
+        })
+        | Inline::Strong(Emphasis {
+            content: inlines, ..
+        })
+        | Inline::Strikethrough(inlines) => {
             for inline in inlines {
                 collect_inlines_from_inline(inline, predicate, results);
             }
@@ -419,6 +435,13 @@ where
                 }
             }
         }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                for block in item.definitions.iter().flatten() {
+                    collect_blocks_from_block(block, predicate, results);
+                }
+            }
+        }
         Block::FootnoteDefinition(footnote) => {
             for block in &footnote.blocks {
                 collect_blocks_from_block(block, predicate, results);
@@ -468,6 +491,20 @@ where
                 }
             }
         }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                for inline in &item.term {
+                    if let Some(found) = find_first_inline_in_inline(inline, predicate) {
+                        return Some(found);
+                    }
+                }
+                for block in item.definitions.iter().flatten() {
+                    if let Some(found) = find_first_inline_in_block(block, predicate) {
+                        return Some(found);
+                    }
+                }
+            }
+        }
         Block::Table(table) => {
             for row in &table.rows {
                 for cell in row {
@@ -514,7 +551,13 @@ where
     }
 
     match inline {
-        Inline::Emphasis(inlines) | Inline::Strong(inlines) | Inline::Strikethrough(inlines) => {
+        Inline::Emphasis(Emphasis {
+            content: inlines, ..
+        })
+        | Inline::Strong(Emphasis {
+            content: inlines, ..
+        })
+        | Inline::Strikethrough(inlines) => {
             for inline in inlines {
                 if let Some(found) = find_first_inline_in_inline(inline, predicate) {
                     return Some(found);
@@ -570,6 +613,15 @@ where
                 }
             }
         }
+        Block::DefinitionList(list) => {
+            for item in &list.items {
+                for block in item.definitions.iter().flatten() {
+                    if let Some(found) = find_first_block_in_block(block, predicate) {
+                        return Some(found);
+                    }
+                }
+            }
+        }
         Block::FootnoteDefinition(footnote) => {
             for block in &footnote.blocks {
                 if let Some(found) = find_first_block_in_block(block, predicate) {
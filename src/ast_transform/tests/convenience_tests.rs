@@ -198,7 +198,7 @@ fn test_transform_with_custom_transformer() {
         blocks: vec![
             Block::Paragraph(vec![
                 Inline::Text("Hi".to_string()),
-                Inline::Emphasis(vec![Inline::Text(" there".to_string())]),
+                Inline::Emphasis(vec![Inline::Text(" there".to_string())].into()),
             ]),
             Block::CodeBlock(CodeBlock {
                 kind: CodeBlockKind::Fenced {
@@ -217,7 +217,7 @@ fn test_transform_with_custom_transformer() {
         assert_eq!(inlines[0], Inline::Text("HiHi".to_string()));
         if let Inline::Emphasis(emphasis_content) = &inlines[1] {
             assert_eq!(
-                emphasis_content[0],
+                emphasis_content.content[0],
                 Inline::Text(" there there".to_string())
             );
         }
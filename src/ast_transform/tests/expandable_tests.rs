@@ -113,7 +113,9 @@ impl Transformer for TextExpander {
                     }
                     // Add emphasis between parts (except after the last part)
                     if i < parts.len() - 1 {
-                        result.push(Inline::Emphasis(vec![Inline::Text("EXPANDED".to_string())]));
+                        result.push(Inline::Emphasis(
+                            vec![Inline::Text("EXPANDED".to_string())].into(),
+                        ));
                     }
                 }
 
@@ -147,12 +149,12 @@ fn test_text_expander() {
         assert_eq!(inlines[0], Inline::Text("Hello ".to_string()));
         assert_eq!(
             inlines[1],
-            Inline::Emphasis(vec![Inline::Text("EXPANDED".to_string())])
+            Inline::Emphasis(vec![Inline::Text("EXPANDED".to_string())].into())
         );
         assert_eq!(inlines[2], Inline::Text(" World ".to_string()));
         assert_eq!(
             inlines[3],
-            Inline::Emphasis(vec![Inline::Text("EXPANDED".to_string())])
+            Inline::Emphasis(vec![Inline::Text("EXPANDED".to_string())].into())
         );
         assert_eq!(inlines[4], Inline::Text(" !".to_string()));
     } else {
@@ -176,8 +178,8 @@ impl Transformer for HeadingExpander {
                     .collect();
 
                 // Create an additional paragraph with metadata
-                let meta_paragraph =
-                    Block::Paragraph(vec![Inline::Emphasis(vec![Inline::Text(format!(
+                let meta_paragraph = Block::Paragraph(vec![Inline::Emphasis(
+                    vec![Inline::Text(format!(
                         "This is a {} heading",
                         match &heading.kind {
                             HeadingKind::Atx(level) => format!("level {level}"),
@@ -186,7 +188,9 @@ impl Transformer for HeadingExpander {
                                 SetextHeading::Level2 => "level 2".to_string(),
                             },
                         }
-                    ))])]);
+                    ))]
+                    .into(),
+                )]);
 
                 vec![Block::Heading(transformed_heading), meta_paragraph]
             }
@@ -226,7 +230,7 @@ fn test_heading_expander() {
         assert_eq!(inlines.len(), 1);
         if let Inline::Emphasis(content) = &inlines[0] {
             assert_eq!(
-                content[0],
+                content.content[0],
                 Inline::Text("This is a level 2 heading".to_string())
             );
         } else {
@@ -373,7 +377,7 @@ impl Transformer for ComplexExpander {
             Inline::Text(text) if text.contains("EXPAND") => {
                 vec![
                     Inline::Text(text.replace("EXPAND", "")),
-                    Inline::Strong(vec![Inline::Text("EXPANDED".to_string())]),
+                    Inline::Strong(vec![Inline::Text("EXPANDED".to_string())].into()),
                 ]
             }
             other => self.walk_expand_inline(other),
@@ -411,7 +415,7 @@ fn test_complex_expansion() {
             assert_eq!(text, "Main  Title"); // "EXPAND" removed
         }
         if let Inline::Strong(content) = &heading.content[1] {
-            if let Inline::Text(text) = &content[0] {
+            if let Inline::Text(text) = &content.content[0] {
                 assert_eq!(text, "EXPANDED");
             }
         }
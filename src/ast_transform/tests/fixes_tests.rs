@@ -0,0 +1,113 @@
+#![cfg(all(feature = "parser", feature = "printer"))]
+use crate::ast::*;
+use crate::ast_transform::*;
+use crate::parser::{parse_markdown, MarkdownParserState};
+use rstest::rstest;
+
+fn fix(input: &str, transformer: impl Transformer + 'static) -> String {
+    let doc = parse_markdown(MarkdownParserState::default(), input).unwrap();
+    let doc = TransformPipeline::new()
+        .transform_with(transformer)
+        .apply(doc);
+    crate::printer::render_markdown(&doc, crate::printer::config::Config::default())
+}
+
+#[rstest(input, expected,
+    case("# a\n\n## b", "# a\n\n## b"),
+    case("# a\n\n### b\n\n#### c", "# a\n\n## b\n\n### c"),
+    case("# a\n\n### b\n\n### c\n\n##### d\n\n## e", "# a\n\n## b\n\n## c\n\n### d\n\n## e"),
+    case("## a\n\n#### b\n\n# c\n\n### d", "## a\n\n### b\n\n# c\n\n## d"),
+    case("a\n=\n\n> ### b", "a\n==========\n\n> ## b"),
+    case("# a\n\nb\n-", "# a\n\nb\n----------"),
+)]
+fn heading_increments_are_normalized(input: &str, expected: &str) {
+    assert_eq!(expected, fix(input, NormalizeHeadingIncrements::default()));
+}
+
+#[rstest(input, expected,
+    case("see https://a.com/x, and http://b.org.", "see <https://a.com/x>, and <http://b.org>."),
+    case("(https://a.com/(b)) https://c.com/d)", "(<https://a.com/(b)>) <https://c.com/d>)"),
+    case("*https://a.com* and ~~http://b.com~~", "*<https://a.com>* and ~~<http://b.com>~~"),
+    case("xhttps://a.com https:// http", "xhttps://a.com https:// http"),
+    case("[https://a.com](https://a.com) <https://b.com>", "[https://a.com](https://a.com) <https://b.com>"),
+    case("# https://a.com", "# <https://a.com>"),
+    case("|a|\n|-|\n|https://a.com|", "| a               |\n| --------------- |\n| <https://a.com> |"),
+)]
+fn bare_urls_are_linkified(input: &str, expected: &str) {
+    assert_eq!(expected, fix(input, LinkifyBareUrls));
+}
+
+#[test]
+fn linkified_text_is_split() {
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![Inline::Text(
+            "a https://a.com b".to_string(),
+        )])],
+    };
+    let doc = TransformWith::transform_with(doc, &mut LinkifyBareUrls);
+    assert_eq!(
+        vec![Block::Paragraph(vec![
+            Inline::Text("a ".to_string()),
+            Inline::Autolink("https://a.com".to_string()),
+            Inline::Text(" b".to_string()),
+        ])],
+        doc.blocks
+    );
+}
+
+#[rstest(input, expected,
+    case("[a]\n\n[a]: /a", "[a]\n\n[a]: /a"),
+    case("[x][A  B]\n\n[b]: /b\n\n[a b]: /a", "[x][A B]\n\n[a b]: /a"),
+    case("a\n\n> [a]: /a\n>\n> b", "a\n\n> b"),
+)]
+fn unused_definitions_are_removed(input: &str, expected: &str) {
+    assert_eq!(expected, fix(input, RemoveUnusedDefinitions));
+}
+
+#[rstest(input, expected,
+    case("3. a\n4. b\n\n---\n\n0. c", " 1. a\n 2. b\n\n---\n\n 1. c"),
+    case("- a\n  7. b", " - a\n    1. b"),
+)]
+fn ordered_lists_are_renumbered(input: &str, expected: &str) {
+    assert_eq!(expected, fix(input, RenumberOrderedLists::default()));
+}
+
+#[test]
+fn ordered_lists_are_renumbered_from_start() {
+    let fixer = RenumberOrderedLists::default().with_start(0);
+    assert_eq!(" 0. a\n 1. b", fix("1. a\n2. b", fixer));
+}
+
+#[rstest(input, expected,
+    case("a[^1] b[^2]\n\n[^1]: x\n\n[^2]: y", "a[^1] b[^2]\n\n[^1]: x\n\n[^2]: y"),
+    case("a[^1]\n\n[^1]: x\n\n[^1]: y", "a[^1]\n\n[^1]: x"),
+    case("a[^1] b[^2]\n\n[^1]: x\n\n[^2]: x", "a[^1] b[^2]\n\n[^1]: x\n\n[^2]: x"),
+    case("a[^1]\n\n> [^1]: x\n\n[^1]: x", "a[^1]\n\n> [^1]: x"),
+)]
+fn footnotes_are_deduplicated(input: &str, expected: &str) {
+    assert_eq!(expected, fix(input, DeduplicateFootnotes::default()));
+}
+
+#[rstest(input, expected,
+    case("a[^1] b[^2]\n\n[^1]: x\n\n[^2]: x", "a[^1] b[^1]\n\n[^1]: x"),
+    case("a[^2] b[^1]\n\n> [^2]: x\n\n[^1]: x\n\n[^2]: y", "a[^2] b[^2]\n\n> [^2]: x"),
+)]
+fn footnotes_with_equal_content_are_merged(input: &str, expected: &str) {
+    let fixer = DeduplicateFootnotes::default().with_merge_equal_content(true);
+    assert_eq!(expected, fix(input, fixer));
+}
+
+#[test]
+fn fixes_are_chained() {
+    let input = "# Links\n\n### See https://a.com\n\n[x][a]\n\n[a]: /a\n\n[b]: /b\n";
+    let doc = parse_markdown(MarkdownParserState::default(), input).unwrap();
+    let doc = TransformPipeline::new()
+        .transform_with(NormalizeHeadingIncrements::default())
+        .transform_with(LinkifyBareUrls)
+        .transform_with(RemoveUnusedDefinitions)
+        .apply(doc);
+    assert_eq!(
+        "# Links\n\n## See <https://a.com>\n\n[x][a]\n\n[a]: /a",
+        crate::printer::render_markdown(&doc, crate::printer::config::Config::default())
+    );
+}
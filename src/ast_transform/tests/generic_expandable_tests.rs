@@ -90,7 +90,10 @@ impl GenericTransformer<NodeId> for IdAssignerExpander {
                 content,
                 user_data: self.next_id(),
             },
-            Inline::Emphasis { content, .. } => Inline::Emphasis {
+            Inline::Emphasis {
+                delimiter, content, ..
+            } => Inline::Emphasis {
+                delimiter,
                 content: content
                     .into_iter()
                     .flat_map(|inline| self.walk_expand_inline(inline))
@@ -219,6 +222,7 @@ impl GenericTransformer<SourceLocation> for SourceLocationExpander {
                     // Add separator between parts (except after last)
                     if i < parts.len() - 1 && !parts[i + 1].is_empty() {
                         result.push(Inline::Emphasis {
+                            delimiter: EmphasisDelimiter::Asterisk,
                             content: vec![Inline::Text {
                                 content: " | ".to_string(),
                                 user_data: SourceLocation {
@@ -282,6 +286,7 @@ fn test_source_location_expander() {
         if let Inline::Emphasis {
             content: emphasis_content,
             user_data,
+            ..
         } = &content[1]
         {
             assert_eq!(user_data.line, 1);
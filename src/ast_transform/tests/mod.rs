@@ -17,3 +17,9 @@ mod expandable_tests;
 
 #[cfg(test)]
 mod generic_expandable_tests;
+
+#[cfg(test)]
+mod toc_tests;
+
+#[cfg(test)]
+mod fixes_tests;
@@ -0,0 +1,184 @@
+use crate::ast::*;
+use crate::ast_transform::{TableOfContents, TocEntry, TocOptions};
+
+fn heading(level: u8, text: &str) -> Block {
+    Block::Heading(Heading {
+        kind: HeadingKind::Atx(level),
+        content: vec![Inline::Text(text.to_string())],
+    })
+}
+
+fn entry(level: u8, text: &str, slug: &str, children: Vec<TocEntry>) -> TocEntry {
+    TocEntry {
+        level,
+        content: vec![Inline::Text(text.to_string())],
+        slug: slug.to_string(),
+        children,
+    }
+}
+
+fn link_item(text: &str, slug: &str, nested: Option<Block>) -> ListItem {
+    let mut blocks = vec![Block::Paragraph(vec![Inline::Link(Link {
+        destination: format!("#{slug}"),
+        title: None,
+        children: vec![Inline::Text(text.to_string())],
+    })])];
+    blocks.extend(nested);
+    ListItem { task: None, blocks }
+}
+
+fn bullet_list(items: Vec<ListItem>) -> Block {
+    Block::List(List {
+        kind: ListKind::Bullet(ListBulletKind::Dash),
+        items,
+        tight: true,
+    })
+}
+
+fn create_test_doc() -> Document {
+    Document {
+        blocks: vec![
+            heading(1, "Guide"),
+            heading(3, "Deep"),
+            heading(2, "Usage"),
+            Block::BlockQuote(vec![heading(2, "Usage")]),
+            heading(1, "FAQ"),
+        ],
+    }
+}
+
+#[test]
+fn test_toc_nesting() {
+    let toc = create_test_doc().table_of_contents(&TocOptions::default());
+    assert_eq!(
+        toc,
+        vec![
+            entry(
+                1,
+                "Guide",
+                "guide",
+                vec![
+                    entry(3, "Deep", "deep", vec![]),
+                    entry(2, "Usage", "usage", vec![]),
+                    entry(2, "Usage", "usage-1", vec![]),
+                ]
+            ),
+            entry(1, "FAQ", "faq", vec![]),
+        ]
+    );
+}
+
+#[test]
+fn test_toc_level_limits() {
+    let options = TocOptions::default().with_min_level(2).with_max_level(2);
+    let toc = create_test_doc().table_of_contents(&options);
+    assert_eq!(
+        toc,
+        vec![
+            entry(2, "Usage", "usage", vec![]),
+            entry(2, "Usage", "usage-1", vec![]),
+        ]
+    );
+}
+
+#[test]
+fn test_insert_toc() {
+    let doc = Document {
+        blocks: vec![
+            Block::HtmlBlock("<!-- TOC -->".to_string()),
+            heading(1, "Guide"),
+            heading(2, "Usage"),
+            Block::Paragraph(vec![Inline::Text("[[_TOC_]]".to_string())]),
+        ],
+    };
+
+    let options = TocOptions::default().with_anchor_prefix("doc-".to_string());
+    let result = doc.insert_toc(&options);
+
+    let toc = bullet_list(vec![link_item(
+        "Guide",
+        "doc-guide",
+        Some(bullet_list(vec![link_item("Usage", "doc-usage", None)])),
+    )]);
+    assert_eq!(
+        result.blocks,
+        vec![toc.clone(), heading(1, "Guide"), heading(2, "Usage"), toc]
+    );
+}
+
+#[test]
+fn test_insert_empty_toc_removes_markers() {
+    let text = Block::Paragraph(vec![Inline::Text("Text".to_string())]);
+    let doc = Document {
+        blocks: vec![
+            Block::HtmlBlock("<!-- toc -->".to_string()),
+            text.clone(),
+            Block::BlockQuote(vec![Block::HtmlBlock("<!-- toc -->".to_string())]),
+        ],
+    };
+    assert_eq!(
+        doc.insert_toc(&TocOptions::default()).blocks,
+        vec![text, Block::BlockQuote(vec![])]
+    );
+}
+
+#[test]
+fn test_links_are_removed_from_entries() {
+    let doc = Document {
+        blocks: vec![Block::Heading(Heading {
+            kind: HeadingKind::Atx(1),
+            content: vec![
+                Inline::Text("See ".to_string()),
+                Inline::Link(Link {
+                    destination: "/docs".to_string(),
+                    title: None,
+                    children: vec![Inline::Strong(
+                        vec![Inline::Text("docs".to_string())].into(),
+                    )],
+                }),
+                Inline::FootnoteReference("1".to_string()),
+            ],
+        })],
+    };
+
+    let toc =
+        crate::ast_transform::toc_to_block(&doc.table_of_contents(&TocOptions::default()), "");
+    let Block::List(list) = toc else {
+        panic!("expected a list");
+    };
+    assert_eq!(
+        list.items[0].blocks,
+        vec![Block::Paragraph(vec![Inline::Link(Link {
+            destination: "#see-docs".to_string(),
+            title: None,
+            children: vec![
+                Inline::Text("See ".to_string()),
+                Inline::Strong(vec![Inline::Text("docs".to_string())].into()),
+            ],
+        })])]
+    );
+}
+
+#[cfg(all(feature = "parser", feature = "printer", feature = "html-printer"))]
+#[test]
+fn test_parsed_toc_round_trip() {
+    use crate::parser::{parse_markdown, MarkdownParserState};
+
+    let input = "[[_TOC_]]\n\n# Guide\n\n## Install\n\n## Usage";
+    let doc = parse_markdown(MarkdownParserState::default(), input)
+        .unwrap()
+        .insert_toc(&TocOptions::default());
+
+    let markdown = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(
+        markdown,
+        " - [Guide](#guide)\n    - [Install](#install)\n    - [Usage](#usage)\n\n# Guide\n\n## Install\n\n## Usage"
+    );
+    let reparsed = parse_markdown(MarkdownParserState::default(), &markdown).unwrap();
+    assert_eq!(reparsed, doc);
+
+    let config = crate::html_printer::config::Config::default().with_heading_ids(true);
+    let html = crate::html_printer::render_html(&doc, config);
+    assert!(html.contains("<a href=\"#usage\">Usage</a>"));
+    assert!(html.contains("<h2 id=\"usage\">Usage</h2>"));
+}
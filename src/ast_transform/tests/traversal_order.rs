@@ -116,15 +116,18 @@ mod traversal_order_tests {
                     kind: HeadingKind::Atx(1),
                     content: vec![
                         Inline::Text("Title".to_string()),
-                        Inline::Strong(vec![Inline::Text("Bold".to_string())]),
+                        Inline::Strong(vec![Inline::Text("Bold".to_string())].into()),
                     ],
                 }),
                 Block::Paragraph(vec![
                     Inline::Text("Text1".to_string()),
-                    Inline::Emphasis(vec![
-                        Inline::Text("Italic".to_string()),
-                        Inline::Strong(vec![Inline::Text("BoldItalic".to_string())]),
-                    ]),
+                    Inline::Emphasis(
+                        vec![
+                            Inline::Text("Italic".to_string()),
+                            Inline::Strong(vec![Inline::Text("BoldItalic".to_string())].into()),
+                        ]
+                        .into(),
+                    ),
                     Inline::Text("Text2".to_string()),
                     Inline::Link(Link {
                         destination: "https://example.com".to_string(),
@@ -140,17 +143,18 @@ mod traversal_order_tests {
                             task: None,
                             blocks: vec![Block::Paragraph(vec![
                                 Inline::Text("Item1".to_string()),
-                                Inline::Strong(vec![Inline::Text("Strong1".to_string())]),
+                                Inline::Strong(vec![Inline::Text("Strong1".to_string())].into()),
                             ])],
                         },
                         ListItem {
                             task: None,
                             blocks: vec![Block::Paragraph(vec![
                                 Inline::Text("Item2".to_string()),
-                                Inline::Emphasis(vec![Inline::Text("Emph1".to_string())]),
+                                Inline::Emphasis(vec![Inline::Text("Emph1".to_string())].into()),
                             ])],
                         },
                     ],
+                    tight: true,
                 }),
             ],
         };
@@ -218,14 +222,14 @@ mod traversal_order_tests {
                         label: vec![Inline::Text("ref1".to_string())],
                         text: vec![
                             Inline::Text("link ".to_string()),
-                            Inline::Strong(vec![Inline::Text("text".to_string())]),
+                            Inline::Strong(vec![Inline::Text("text".to_string())].into()),
                         ],
                     }),
                     Inline::Text(" and ".to_string()),
                     Inline::LinkReference(LinkReference {
                         label: vec![
                             Inline::Text("ref2".to_string()),
-                            Inline::Emphasis(vec![Inline::Text("label".to_string())]),
+                            Inline::Emphasis(vec![Inline::Text("label".to_string())].into()),
                         ],
                         text: vec![Inline::Text("more text".to_string())],
                     }),
@@ -9,12 +9,12 @@ fn create_test_doc() -> Document {
             // Simple paragraph
             Block::Paragraph(vec![
                 Inline::Text("Hello ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("world".to_string())]),
+                Inline::Emphasis(vec![Inline::Text("world".to_string())].into()),
                 Inline::Text(" with ".to_string()),
                 Inline::Strong(vec![
                     Inline::Text("strong ".to_string()),
                     Inline::Code("code".to_string()),
-                ]),
+                ].into()),
                 Inline::Text(" and ".to_string()),
                 Inline::Link(Link {
                     destination: "http://example.com".to_string(),
@@ -65,6 +65,7 @@ fn create_test_doc() -> Document {
                         ])],
                     },
                 ],
+                tight: true,
             }),
             // Table with correct structure (first row is header)
             Block::Table(Table {
@@ -103,7 +104,7 @@ fn create_test_doc() -> Document {
             Block::Definition(LinkDefinition {
                 label: vec![
                     Inline::Text("definition ".to_string()),
-                    Inline::Emphasis(vec![Inline::Text("label".to_string())]),
+                    Inline::Emphasis(vec![Inline::Text("label".to_string())].into()),
                 ],
                 destination: "http://definition.com".to_string(),
                 title: Some("Definition title".to_string()),
@@ -196,7 +197,7 @@ impl Visitor for NodeCounter {
             Inline::Strikethrough(_) => self.strikethrough_count += 1,
             Inline::LinkReference(_) => self.link_ref_count += 1,
             Inline::FootnoteReference(_) => self.footnote_ref_count += 1,
-            Inline::LineBreak => {}
+            Inline::Math(_) | Inline::LineBreak | Inline::SoftBreak => {}
             Inline::Empty => {}
         }
         self.walk_inline(inline);
@@ -215,7 +216,7 @@ impl Visitor for NodeCounter {
             Block::FootnoteDefinition(_) => self.footnote_def_count += 1,
             Block::GitHubAlert(_) => self.github_alert_count += 1,
             Block::Definition(_) => self.definition_count += 1,
-            Block::Empty => {}
+            Block::DefinitionList(_) | Block::Math(_) | Block::FrontMatter { .. } | Block::Empty => {}
         }
         self.walk_block(block);
     }
@@ -329,10 +330,11 @@ fn test_visitor_deep_nesting() {
                         title: None,
                         children: vec![Inline::Strong(vec![Inline::Emphasis(vec![Inline::Text(
                             "Deeply nested text".to_string(),
-                        )])])],
+                        )].into())].into())],
                     }),
                 ])])],
             }],
+            tight: true,
         })])],
     };
 
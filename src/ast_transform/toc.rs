@@ -0,0 +1,255 @@
+//! Table of contents generation
+//!
+//! This module builds a nested table of contents from the headings of a
+//! document, and replaces `[[_TOC_]]` or `<!-- toc -->` markers with it.
+//! Entries link to GitHub-compatible heading slugs, the same IDs the HTML
+//! printer emits with `Config::with_heading_ids`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use markdown_ppp::ast::*;
+//! use markdown_ppp::ast_transform::{TableOfContents, TocOptions};
+//!
+//! let heading = |level, text: &str| {
+//!     Block::Heading(Heading {
+//!         kind: HeadingKind::Atx(level),
+//!         content: vec![Inline::Text(text.to_string())],
+//!     })
+//! };
+//! let doc = Document {
+//!     blocks: vec![
+//!         Block::HtmlBlock("<!-- toc -->".to_string()),
+//!         heading(1, "Guide"),
+//!         heading(2, "Install"),
+//!         heading(2, "Usage"),
+//!     ],
+//! };
+//!
+//! let toc = doc.table_of_contents(&TocOptions::default());
+//! assert_eq!(toc.len(), 1);
+//! assert_eq!(toc[0].slug, "guide");
+//! assert_eq!(toc[0].children[1].slug, "usage");
+//!
+//! // The marker is replaced with a bullet list of links
+//! let doc = doc.insert_toc(&TocOptions::default().with_min_level(2));
+//! assert!(matches!(doc.blocks[0], Block::List(_)));
+//! ```
+
+use super::fixes::BlockFilter;
+use super::query::Query;
+use super::transformer::Transformer;
+use crate::ast::*;
+
+/// Options controlling which headings make it into a table of contents
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TocOptions {
+    min_level: u8,
+    max_level: u8,
+    anchor_prefix: String,
+}
+
+impl Default for TocOptions {
+    /// All heading levels, no anchor prefix
+    fn default() -> Self {
+        Self {
+            min_level: 1,
+            max_level: 6,
+            anchor_prefix: String::new(),
+        }
+    }
+}
+
+impl TocOptions {
+    /// Skip headings with a level lower than `min_level`
+    pub fn with_min_level(self, min_level: u8) -> Self {
+        Self { min_level, ..self }
+    }
+
+    /// Skip headings with a level higher than `max_level`
+    pub fn with_max_level(self, max_level: u8) -> Self {
+        Self { max_level, ..self }
+    }
+
+    /// Prefix of the link anchors, matching the HTML printer's anchor prefix
+    pub fn with_anchor_prefix(self, anchor_prefix: String) -> Self {
+        Self {
+            anchor_prefix,
+            ..self
+        }
+    }
+}
+
+/// Entry of a table of contents
+#[derive(Debug, Clone, PartialEq)]
+pub struct TocEntry {
+    /// Heading level (1-6)
+    pub level: u8,
+    /// Heading content
+    pub content: Vec<Inline>,
+    /// Slug of the heading, unique within the document
+    pub slug: String,
+    /// Entries of the headings nested under this one
+    pub children: Vec<TocEntry>,
+}
+
+/// Table of contents support for documents
+pub trait TableOfContents {
+    /// Build a nested table of contents from the headings
+    ///
+    /// Headings become children of the closest preceding heading with a lower
+    /// level. Slugs are computed for all headings, including the skipped
+    /// ones, so they match the heading IDs of the rendered document.
+    fn table_of_contents(&self, options: &TocOptions) -> Vec<TocEntry>;
+
+    /// Replace `[[_TOC_]]` paragraphs and `<!-- toc -->` HTML blocks with the
+    /// table of contents rendered as a bullet list of links. The markers are
+    /// removed if there are no headings to list.
+    fn insert_toc(self, options: &TocOptions) -> Self;
+}
+
+impl TableOfContents for Document {
+    fn table_of_contents(&self, options: &TocOptions) -> Vec<TocEntry> {
+        let mut slugger = Slugger::new();
+        let mut entries = Vec::new();
+        for heading in self.find_all_headings() {
+            let slug = slugger.heading_slug(heading);
+            let level = heading_level(heading);
+            if level < options.min_level || level > options.max_level {
+                continue;
+            }
+            let entry = TocEntry {
+                level,
+                content: heading.content.clone(),
+                slug,
+                children: Vec::new(),
+            };
+            insert_entry(&mut entries, entry);
+        }
+        entries
+    }
+
+    fn insert_toc(self, options: &TocOptions) -> Self {
+        let entries = self.table_of_contents(options);
+        if entries.is_empty() {
+            return BlockFilter(|block: &Block| !is_toc_marker(block)).transform_document(self);
+        }
+        let mut inserter = TocInserter {
+            toc: toc_to_block(&entries, &options.anchor_prefix),
+        };
+        inserter.transform_document(self)
+    }
+}
+
+pub(crate) fn heading_level(heading: &Heading) -> u8 {
+    match heading.kind {
+        HeadingKind::Atx(level) => level,
+        HeadingKind::Setext(SetextHeading::Level1) => 1,
+        HeadingKind::Setext(SetextHeading::Level2) => 2,
+    }
+}
+
+fn insert_entry(entries: &mut Vec<TocEntry>, entry: TocEntry) {
+    match entries.last_mut() {
+        Some(last) if last.level < entry.level => insert_entry(&mut last.children, entry),
+        _ => entries.push(entry),
+    }
+}
+
+/// Render entries as a bullet list of links, or an empty block if there are
+/// no entries.
+pub fn toc_to_block(entries: &[TocEntry], anchor_prefix: &str) -> Block {
+    if entries.is_empty() {
+        return Block::Empty;
+    }
+
+    let items = entries
+        .iter()
+        .map(|entry| {
+            let link = Inline::Link(Link {
+                destination: format!("#{anchor_prefix}{}", entry.slug),
+                title: None,
+                children: link_text(&entry.content),
+            });
+            let mut blocks = vec![Block::Paragraph(vec![link])];
+            if !entry.children.is_empty() {
+                blocks.push(toc_to_block(&entry.children, anchor_prefix));
+            }
+            ListItem { task: None, blocks }
+        })
+        .collect();
+
+    Block::List(List {
+        kind: ListKind::Bullet(ListBulletKind::Dash),
+        items,
+        tight: true,
+    })
+}
+
+/// Heading content without links and footnote references, which cannot be
+/// nested in a link.
+fn link_text(inlines: &[Inline]) -> Vec<Inline> {
+    inlines
+        .iter()
+        .flat_map(|inline| match inline {
+            Inline::Link(link) => link_text(&link.children),
+            Inline::LinkReference(reference) => link_text(&reference.text),
+            Inline::FootnoteReference(_) => Vec::new(),
+            Inline::Emphasis(emphasis) => vec![Inline::Emphasis(Emphasis {
+                delimiter: emphasis.delimiter,
+                content: link_text(&emphasis.content),
+            })],
+            Inline::Strong(strong) => vec![Inline::Strong(Emphasis {
+                delimiter: strong.delimiter,
+                content: link_text(&strong.content),
+            })],
+            Inline::Strikethrough(children) => vec![Inline::Strikethrough(link_text(children))],
+            other => vec![other.clone()],
+        })
+        .collect()
+}
+
+struct TocInserter {
+    toc: Block,
+}
+
+impl Transformer for TocInserter {
+    fn transform_block(&mut self, block: Block) -> Block {
+        if is_toc_marker(&block) {
+            self.toc.clone()
+        } else {
+            self.walk_transform_block(block)
+        }
+    }
+}
+
+fn is_toc_marker(block: &Block) -> bool {
+    match block {
+        Block::Paragraph(inlines) => marker_source(inlines) == "[[_TOC_]]",
+        Block::HtmlBlock(html) => html
+            .trim()
+            .strip_prefix("<!--")
+            .and_then(|s| s.strip_suffix("-->"))
+            .is_some_and(|s| s.trim().eq_ignore_ascii_case("toc")),
+        _ => false,
+    }
+}
+
+/// Approximate Markdown source of a paragraph: `[[_TOC_]]` is parsed as
+/// nested shortcut references around emphasis.
+fn marker_source(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.clone(),
+            Inline::Emphasis(Emphasis {
+                delimiter: EmphasisDelimiter::Underscore,
+                content,
+            }) => format!("_{}_", marker_source(content)),
+            Inline::LinkReference(reference) if reference.label == reference.text => {
+                format!("[{}]", marker_source(&reference.text))
+            }
+            _ => "\u{fffd}".to_owned(),
+        })
+        .collect()
+}
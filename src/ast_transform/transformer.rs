@@ -108,6 +108,11 @@ pub trait Transformer {
         self.walk_transform_text(text)
     }
 
+    /// Transform a definition list item
+    fn transform_definition_list_item(&mut self, item: DefinitionListItem) -> DefinitionListItem {
+        self.walk_transform_definition_list_item(item)
+    }
+
     /// Transform a footnote definition
     fn transform_footnote_definition(
         &mut self,
@@ -155,6 +160,14 @@ pub trait Transformer {
                     .collect();
                 Block::List(list)
             }
+            Block::DefinitionList(mut list) => {
+                list.items = list
+                    .items
+                    .into_iter()
+                    .map(|item| self.transform_definition_list_item(item))
+                    .collect();
+                Block::DefinitionList(list)
+            }
             Block::Table(mut table) => {
                 table.rows = table
                     .rows
@@ -184,18 +197,22 @@ pub trait Transformer {
     /// Default transformation for inline nodes
     fn walk_transform_inline(&mut self, inline: Inline) -> Inline {
         match inline {
-            Inline::Emphasis(inlines) => Inline::Emphasis(
-                inlines
+            Inline::Emphasis(emphasis) => Inline::Emphasis(Emphasis {
+                delimiter: emphasis.delimiter,
+                content: emphasis
+                    .content
                     .into_iter()
                     .map(|inline| self.transform_inline(inline))
                     .collect(),
-            ),
-            Inline::Strong(inlines) => Inline::Strong(
-                inlines
+            }),
+            Inline::Strong(strong) => Inline::Strong(Emphasis {
+                delimiter: strong.delimiter,
+                content: strong
+                    .content
                     .into_iter()
                     .map(|inline| self.transform_inline(inline))
                     .collect(),
-            ),
+            }),
             Inline::Strikethrough(inlines) => Inline::Strikethrough(
                 inlines
                     .into_iter()
@@ -240,6 +257,29 @@ pub trait Transformer {
         item
     }
 
+    /// Default transformation for definition list items
+    fn walk_transform_definition_list_item(
+        &mut self,
+        mut item: DefinitionListItem,
+    ) -> DefinitionListItem {
+        item.term = item
+            .term
+            .into_iter()
+            .map(|inline| self.transform_inline(inline))
+            .collect();
+        item.definitions = item
+            .definitions
+            .into_iter()
+            .map(|blocks| {
+                blocks
+                    .into_iter()
+                    .map(|block| self.transform_block(block))
+                    .collect()
+            })
+            .collect();
+        item
+    }
+
     /// Default transformation for table rows
     fn walk_transform_table_row(&mut self, row: TableRow) -> TableRow {
         row.into_iter()
@@ -427,6 +467,21 @@ pub trait Transformer {
                     .collect();
                 vec![Block::List(list)]
             }
+            Block::DefinitionList(mut list) => {
+                for item in &mut list.items {
+                    item.term = std::mem::take(&mut item.term)
+                        .into_iter()
+                        .flat_map(|inline| self.walk_expand_inline(inline))
+                        .collect();
+                    for blocks in &mut item.definitions {
+                        *blocks = std::mem::take(blocks)
+                            .into_iter()
+                            .flat_map(|block| self.walk_expand_block(block))
+                            .collect();
+                    }
+                }
+                vec![Block::DefinitionList(list)]
+            }
             Block::Table(mut table) => {
                 table.rows = table
                     .rows
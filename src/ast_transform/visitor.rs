@@ -115,6 +115,11 @@ pub trait Visitor {
         self.walk_text(text);
     }
 
+    /// Visit a definition list item
+    fn visit_definition_list_item(&mut self, item: &DefinitionListItem) {
+        self.walk_definition_list_item(item);
+    }
+
     /// Visit a footnote definition
     fn visit_footnote_definition(&mut self, footnote: &FootnoteDefinition) {
         self.walk_footnote_definition(footnote);
@@ -153,6 +158,11 @@ pub trait Visitor {
                     self.visit_list_item(item);
                 }
             }
+            Block::DefinitionList(list) => {
+                for item in &list.items {
+                    self.visit_definition_list_item(item);
+                }
+            }
             Block::Table(table) => {
                 for row in &table.rows {
                     self.visit_table_row(row);
@@ -173,15 +183,23 @@ pub trait Visitor {
                 self.visit_code_block(code_block);
             }
             // Terminal nodes - no traversal needed
-            Block::ThematicBreak | Block::HtmlBlock(_) | Block::Empty => {}
+            Block::ThematicBreak
+            | Block::Math(_)
+            | Block::HtmlBlock(_)
+            | Block::FrontMatter { .. }
+            | Block::Empty => {}
         }
     }
 
     /// Default traversal for inline nodes
     fn walk_inline(&mut self, inline: &Inline) {
         match inline {
-            Inline::Emphasis(inlines)
-            | Inline::Strong(inlines)
+            Inline::Emphasis(Emphasis {
+                content: inlines, ..
+            })
+            | Inline::Strong(Emphasis {
+                content: inlines, ..
+            })
             | Inline::Strikethrough(inlines) => {
                 for inline in inlines {
                     self.visit_inline(inline);
@@ -206,7 +224,9 @@ pub trait Visitor {
             }
             // Terminal nodes - no traversal needed
             Inline::LineBreak
+            | Inline::SoftBreak
             | Inline::Code(_)
+            | Inline::Math(_)
             | Inline::Html(_)
             | Inline::Autolink(_)
             | Inline::FootnoteReference(_)
@@ -228,6 +248,16 @@ pub trait Visitor {
         }
     }
 
+    /// Default traversal for definition list items
+    fn walk_definition_list_item(&mut self, item: &DefinitionListItem) {
+        for inline in &item.term {
+            self.visit_inline(inline);
+        }
+        for block in item.definitions.iter().flatten() {
+            self.visit_block(block);
+        }
+    }
+
     /// Default traversal for table rows
     fn walk_table_row(&mut self, row: &TableRow) {
         for cell in row {
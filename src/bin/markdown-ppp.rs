@@ -0,0 +1,590 @@
+//! Command-line interface to the parser and printers.
+//!
+//! ```text
+//! markdown-ppp fmt --check README.md
+//! markdown-ppp fmt --prose-wrap sentence --bullet-style dash doc.md
+//! markdown-ppp html --heading-ids < README.md > README.html
+//! markdown-ppp latex --table-style booktabs doc.md
+//! markdown-ppp text doc.md
+//! markdown-ppp ast --json doc.md
+//! ```
+//!
+//! Every command reads the given files, or stdin if no files (or `-`) are
+//! given, and writes the result to stdout. `--dialect` selects one of the
+//! parser presets; without it the default parser configuration is used, and
+//! `fmt` additionally keeps front matter.
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use markdown_ppp::ast::Document;
+use markdown_ppp::parser::config::{ElementBehavior, MarkdownParserConfig};
+use markdown_ppp::parser::{parse_markdown, MarkdownParserState};
+use markdown_ppp::{html_printer, latex_printer, plaintext_printer, printer};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Arc;
+
+#[derive(Parser)]
+#[command(
+    name = "markdown-ppp",
+    version,
+    about = "Format Markdown, or render it to HTML, LaTeX or plain text"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Pretty-print Markdown
+    Fmt {
+        /// Do not print anything, exit with status 1 if any input is not formatted
+        #[arg(long)]
+        check: bool,
+
+        #[command(flatten)]
+        options: FmtOptions,
+
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Render Markdown to HTML
+    Html {
+        #[command(flatten)]
+        options: HtmlOptions,
+
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Render Markdown to LaTeX
+    Latex {
+        #[command(flatten)]
+        options: LatexOptions,
+
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Render Markdown to plain text
+    Text {
+        /// Line width
+        #[arg(long, default_value_t = 80)]
+        width: usize,
+
+        #[command(flatten)]
+        input: Input,
+    },
+
+    /// Print the parsed AST
+    Ast {
+        /// Print the AST as JSON instead of Rust debug output
+        #[arg(long)]
+        json: bool,
+
+        #[command(flatten)]
+        input: Input,
+    },
+}
+
+#[derive(Args)]
+struct Input {
+    /// Markdown dialect of the input
+    #[arg(long, value_enum)]
+    dialect: Option<Dialect>,
+
+    /// Input files; stdin is read if none are given or for `-`
+    files: Vec<PathBuf>,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Dialect {
+    /// Plain CommonMark
+    Commonmark,
+    /// GitHub Flavored Markdown
+    Gfm,
+    /// GFM plus front matter, math and definition lists
+    Extended,
+}
+
+#[derive(Args)]
+struct FmtOptions {
+    /// Line width
+    #[arg(long, default_value_t = 80)]
+    width: usize,
+
+    /// Number of spaces before list item markers (0-3)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(0..=3))]
+    spaces_before_list_item: u8,
+
+    /// Do not add an empty line before lists
+    #[arg(long)]
+    no_empty_line_before_list: bool,
+
+    /// How paragraph text is broken into lines
+    #[arg(long, value_enum, default_value_t = ProseWrap::Always)]
+    prose_wrap: ProseWrap,
+
+    /// Delimiter of emphasis
+    #[arg(long, value_enum, default_value_t = DelimiterStyle::Preserve)]
+    emphasis_style: DelimiterStyle,
+
+    /// Delimiter of strong emphasis
+    #[arg(long, value_enum, default_value_t = DelimiterStyle::Preserve)]
+    strong_style: DelimiterStyle,
+
+    /// Marker of bullet list items
+    #[arg(long, value_enum, default_value_t = BulletStyle::Preserve)]
+    bullet_style: BulletStyle,
+
+    /// Numbers of ordered list items
+    #[arg(long, value_enum, default_value_t = OrderedListNumbering::Sequential)]
+    ordered_list_numbering: OrderedListNumbering,
+
+    /// Syntax of level 1 and 2 headings
+    #[arg(long, value_enum, default_value_t = HeadingStyle::Preserve)]
+    heading_style: HeadingStyle,
+
+    /// Character of code fences
+    #[arg(long, value_enum, default_value_t = FenceChar::Backtick)]
+    code_fence_char: FenceChar,
+
+    /// Length of code fences (at least 3)
+    #[arg(long, default_value_t = 3, value_parser = clap::value_parser!(u8).range(3..))]
+    code_fence_length: u8,
+
+    /// Syntax of thematic breaks
+    #[arg(long, value_enum, default_value_t = ThematicBreakStyle::Dash)]
+    thematic_break_style: ThematicBreakStyle,
+
+    /// Move link reference definitions to the end of the document
+    #[arg(long)]
+    link_definitions_at_end: bool,
+}
+
+impl FmtOptions {
+    fn config(&self) -> printer::config::Config {
+        use printer::config as c;
+
+        let prose_wrap = match self.prose_wrap {
+            ProseWrap::Always => c::ProseWrap::Always,
+            ProseWrap::Never => c::ProseWrap::Never,
+            ProseWrap::Preserve => c::ProseWrap::Preserve,
+            ProseWrap::Sentence => c::ProseWrap::Sentence,
+        };
+        let delimiter_style = |style| match style {
+            DelimiterStyle::Preserve => c::DelimiterStyle::Preserve,
+            DelimiterStyle::Asterisk => c::DelimiterStyle::Asterisk,
+            DelimiterStyle::Underscore => c::DelimiterStyle::Underscore,
+        };
+        let bullet_style = match self.bullet_style {
+            BulletStyle::Preserve => c::BulletStyle::Preserve,
+            BulletStyle::Dash => c::BulletStyle::Dash,
+            BulletStyle::Star => c::BulletStyle::Star,
+            BulletStyle::Plus => c::BulletStyle::Plus,
+        };
+        let ordered_list_numbering = match self.ordered_list_numbering {
+            OrderedListNumbering::Sequential => c::OrderedListNumbering::Sequential,
+            OrderedListNumbering::Repeated => c::OrderedListNumbering::Repeated,
+        };
+        let heading_style = match self.heading_style {
+            HeadingStyle::Preserve => c::HeadingStyle::Preserve,
+            HeadingStyle::Atx => c::HeadingStyle::Atx,
+            HeadingStyle::Setext => c::HeadingStyle::Setext,
+        };
+        let code_fence_char = match self.code_fence_char {
+            FenceChar::Backtick => c::FenceChar::Backtick,
+            FenceChar::Tilde => c::FenceChar::Tilde,
+        };
+        let thematic_break_style = match self.thematic_break_style {
+            ThematicBreakStyle::Dash => c::ThematicBreakStyle::Dash,
+            ThematicBreakStyle::Star => c::ThematicBreakStyle::Star,
+            ThematicBreakStyle::Underscore => c::ThematicBreakStyle::Underscore,
+        };
+        c::Config::default()
+            .with_width(self.width)
+            .with_spaces_before_list_item(self.spaces_before_list_item.into())
+            .with_empty_line_before_list(!self.no_empty_line_before_list)
+            .with_prose_wrap(prose_wrap)
+            .with_emphasis_style(delimiter_style(self.emphasis_style))
+            .with_strong_style(delimiter_style(self.strong_style))
+            .with_bullet_style(bullet_style)
+            .with_ordered_list_numbering(ordered_list_numbering)
+            .with_heading_style(heading_style)
+            .with_code_fence_char(code_fence_char)
+            .with_code_fence_length(self.code_fence_length.into())
+            .with_thematic_break_style(thematic_break_style)
+            .with_link_definitions_at_end(self.link_definitions_at_end)
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ProseWrap {
+    /// Wrap at the line width
+    Always,
+    /// Put every paragraph on a single line
+    Never,
+    /// Keep the line breaks of the input
+    Preserve,
+    /// Start every sentence on a new line
+    Sentence,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DelimiterStyle {
+    Preserve,
+    Asterisk,
+    Underscore,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BulletStyle {
+    Preserve,
+    Dash,
+    Star,
+    Plus,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum OrderedListNumbering {
+    /// Count up from the first number
+    Sequential,
+    /// Repeat the first number
+    Repeated,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum HeadingStyle {
+    Preserve,
+    Atx,
+    Setext,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum FenceChar {
+    Backtick,
+    Tilde,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ThematicBreakStyle {
+    Dash,
+    Star,
+    Underscore,
+}
+
+#[derive(Args)]
+struct HtmlOptions {
+    /// Line width
+    #[arg(long, default_value_t = 80)]
+    width: usize,
+
+    /// Prefix of heading and footnote anchors
+    #[arg(long, default_value = "")]
+    anchor_prefix: String,
+
+    /// Add `id` attributes to headings
+    #[arg(long)]
+    heading_ids: bool,
+}
+
+impl HtmlOptions {
+    fn config(&self) -> html_printer::config::Config {
+        html_printer::config::Config::default()
+            .with_width(self.width)
+            .with_anchor_prefix(self.anchor_prefix.clone())
+            .with_heading_ids(self.heading_ids)
+    }
+}
+
+#[derive(Args)]
+struct LatexOptions {
+    /// Line width
+    #[arg(long, default_value_t = 80)]
+    width: usize,
+
+    /// Environment used for tables
+    #[arg(long, value_enum, default_value_t = TableStyle::Tabular)]
+    table_style: TableStyle,
+
+    /// Environment used for code blocks
+    #[arg(long, value_enum, default_value_t = CodeBlockStyle::Verbatim)]
+    code_block_style: CodeBlockStyle,
+
+    /// Add a `\label{}` to every heading
+    #[arg(long)]
+    heading_labels: bool,
+}
+
+impl LatexOptions {
+    fn config(&self) -> latex_printer::config::Config {
+        let table_style = match self.table_style {
+            TableStyle::Tabular => latex_printer::config::TableStyle::Tabular,
+            TableStyle::Longtabu => latex_printer::config::TableStyle::Longtabu,
+            TableStyle::Booktabs => latex_printer::config::TableStyle::Booktabs,
+        };
+        let code_block_style = match self.code_block_style {
+            CodeBlockStyle::Verbatim => latex_printer::config::CodeBlockStyle::Verbatim,
+            CodeBlockStyle::Listings => latex_printer::config::CodeBlockStyle::Listings,
+            CodeBlockStyle::Minted => latex_printer::config::CodeBlockStyle::Minted,
+        };
+        latex_printer::config::Config::default()
+            .with_width(self.width)
+            .with_table_style(table_style)
+            .with_code_block_style(code_block_style)
+            .with_heading_labels(self.heading_labels)
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum TableStyle {
+    Tabular,
+    Longtabu,
+    Booktabs,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum CodeBlockStyle {
+    Verbatim,
+    Listings,
+    Minted,
+}
+
+/// Input file name (or `<stdin>`) and its content
+struct Source {
+    name: String,
+    text: String,
+}
+
+impl Input {
+    /// Parser configuration for the selected dialect. Without a dialect the
+    /// default configuration is used, with front matter if `front_matter`.
+    fn parser_config(&self, front_matter: bool) -> Arc<MarkdownParserConfig> {
+        let config = match self.dialect {
+            Some(Dialect::Commonmark) => MarkdownParserConfig::commonmark(),
+            Some(Dialect::Gfm) => MarkdownParserConfig::gfm(),
+            Some(Dialect::Extended) => MarkdownParserConfig::extended(),
+            None if front_matter => MarkdownParserConfig::default()
+                .with_block_front_matter_behavior(ElementBehavior::Parse),
+            None => MarkdownParserConfig::default(),
+        };
+        Arc::new(config)
+    }
+
+    fn read(&self) -> Result<Vec<Source>, String> {
+        if self.files.is_empty() {
+            return Ok(vec![read_stdin()?]);
+        }
+        self.files
+            .iter()
+            .map(|path| {
+                if path.as_os_str() == "-" {
+                    return read_stdin();
+                }
+                let name = path.display().to_string();
+                std::fs::read_to_string(path)
+                    .map(|text| Source {
+                        name: name.clone(),
+                        text,
+                    })
+                    .map_err(|err| format!("{name}: {err}"))
+            })
+            .collect()
+    }
+}
+
+fn read_stdin() -> Result<Source, String> {
+    let mut text = String::new();
+    io::stdin()
+        .read_to_string(&mut text)
+        .map_err(|err| format!("<stdin>: {err}"))?;
+    Ok(Source {
+        name: "<stdin>".to_owned(),
+        text,
+    })
+}
+
+fn parse(source: &Source, config: &Arc<MarkdownParserConfig>) -> Result<Document, String> {
+    parse_markdown(
+        MarkdownParserState::with_config(config.clone()),
+        &source.text,
+    )
+    .map_err(|err| format!("{}:{err}", source.name))
+}
+
+/// Formatted Markdown, without the final newline
+fn format(doc: &Document, options: &FmtOptions) -> String {
+    printer::render_markdown(doc, options.config())
+}
+
+/// Write a rendered document to stdout. A closed pipe (e.g. `| head`) is
+/// not an error.
+fn output(text: &str) -> Result<(), String> {
+    let mut stdout = io::stdout().lock();
+    match writeln!(stdout, "{text}").and_then(|()| stdout.flush()) {
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => Err(format!("<stdout>: {err}")),
+        _ => Ok(()),
+    }
+}
+
+fn run(command: Command) -> Result<ExitCode, String> {
+    let mut status = ExitCode::SUCCESS;
+    match command {
+        Command::Fmt {
+            check,
+            options,
+            input,
+        } => {
+            let config = input.parser_config(true);
+            for source in input.read()? {
+                let formatted = format(&parse(&source, &config)?, &options);
+                if !check {
+                    output(&formatted)?;
+                } else if source.text.strip_suffix('\n') != Some(formatted.as_str()) {
+                    eprintln!("{} is not formatted", source.name);
+                    status = ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Html { options, input } => {
+            let config = input.parser_config(false);
+            for source in input.read()? {
+                output(&html_printer::render_html(
+                    &parse(&source, &config)?,
+                    options.config(),
+                ))?;
+            }
+        }
+        Command::Latex { options, input } => {
+            let config = input.parser_config(false);
+            for source in input.read()? {
+                output(&latex_printer::render_latex(
+                    &parse(&source, &config)?,
+                    options.config(),
+                ))?;
+            }
+        }
+        Command::Text { width, input } => {
+            let config = input.parser_config(false);
+            for source in input.read()? {
+                output(&plaintext_printer::render_plaintext(
+                    &parse(&source, &config)?,
+                    plaintext_printer::config::Config::default().with_width(width),
+                ))?;
+            }
+        }
+        Command::Ast { json, input } => {
+            let config = input.parser_config(false);
+            for source in input.read()? {
+                let doc = parse(&source, &config)?;
+                if json {
+                    let json = serde_json::to_string_pretty(&doc)
+                        .map_err(|err| format!("{}: {err}", source.name))?;
+                    output(&json)?;
+                } else {
+                    output(&format!("{doc:#?}"))?;
+                }
+            }
+        }
+    }
+    Ok(status)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(cli.command) {
+        Ok(status) => status,
+        Err(err) => {
+            eprintln!("markdown-ppp: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn fmt(args: &[&str], text: &str) -> String {
+        let cli = Cli::parse_from(["markdown-ppp", "fmt"].iter().chain(args));
+        let Command::Fmt { options, input, .. } = cli.command else {
+            panic!("expected fmt");
+        };
+        let source = Source {
+            name: "test.md".to_owned(),
+            text: text.to_owned(),
+        };
+        format(
+            &parse(&source, &input.parser_config(true)).unwrap(),
+            &options,
+        )
+    }
+
+    #[test]
+    fn cli_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn fmt_options() {
+        let args = [
+            "--width",
+            "40",
+            "--spaces-before-list-item",
+            "0",
+            "--no-empty-line-before-list",
+        ];
+        assert_eq!(fmt(&args, "Text\n* item"), "Text\n* item");
+
+        assert!(
+            Cli::try_parse_from(["markdown-ppp", "fmt", "--spaces-before-list-item", "4"]).is_err()
+        );
+    }
+
+    #[test]
+    fn fmt_keeps_front_matter() {
+        let text = "---\ntitle: Hello\ntags: [a]\n---\n\n# Doc";
+        assert_eq!(fmt(&[], text), text);
+        assert_eq!(fmt(&["--dialect", "extended"], text), text);
+        assert_eq!(
+            fmt(&["--dialect", "commonmark"], text),
+            "---\n\ntitle: Hello tags: [a]\n----------\n\n# Doc"
+        );
+    }
+
+    #[test]
+    fn fmt_style_options() {
+        let text = "Title\n=====\n\n* a\n* b\n\n_x_ and __y__. Next sentence.";
+        let args = [
+            "--heading-style",
+            "atx",
+            "--bullet-style",
+            "dash",
+            "--emphasis-style",
+            "asterisk",
+            "--strong-style",
+            "asterisk",
+            "--prose-wrap",
+            "sentence",
+        ];
+        assert_eq!(
+            fmt(&args, text),
+            "# Title\n\n - a\n - b\n\n*x* and **y**.\nNext sentence."
+        );
+
+        let text = "```\ncode\n```\n\n***";
+        let args = [
+            "--code-fence-char",
+            "tilde",
+            "--code-fence-length",
+            "4",
+            "--thematic-break-style",
+            "underscore",
+        ];
+        assert_eq!(fmt(&args, text), "~~~~\ncode\n~~~~\n\n___");
+        assert!(Cli::try_parse_from(["markdown-ppp", "fmt", "--code-fence-length", "2"]).is_err());
+    }
+}
@@ -0,0 +1,55 @@
+//! Syntax highlighting hook for code blocks
+//!
+//! The HTML and LaTeX printers render code blocks as plain, escaped text. A
+//! [`CodeHighlighter`] set with `with_code_highlighter` on
+//! [`html_printer::config::Config`](crate::html_printer::config::Config) or
+//! [`latex_printer::config::Config`](crate::latex_printer::config::Config)
+//! renders them instead, e.g. with a tree-sitter or syntect based highlighter.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "html-printer")]
+//! # {
+//! use markdown_ppp::html_printer::{config::Config, escape, render_html};
+//! use markdown_ppp::parser::{parse_markdown, MarkdownParserState};
+//! use std::sync::Arc;
+//!
+//! // Closures with the signature of `CodeHighlighter::highlight` are highlighters
+//! let highlighter = |info: Option<&str>, code: &str| match info {
+//!     Some("sh") => Some(format!("<pre class=\"shell\">$ {}</pre>", escape(code))),
+//!     _ => None,
+//! };
+//!
+//! let doc = parse_markdown(MarkdownParserState::default(), "```sh\nls <dir>\n```").unwrap();
+//! let html = render_html(&doc, Config::default().with_code_highlighter(Arc::new(highlighter)));
+//! assert_eq!(html, "<pre class=\"shell\">$ ls &lt;dir&gt;</pre>");
+//! # }
+//! ```
+
+/// Renders code blocks with syntax highlighting.
+///
+/// Used by the HTML and LaTeX printers. Must be `Send + Sync`, so that
+/// configurations can be shared between threads.
+pub trait CodeHighlighter: Send + Sync {
+    /// Render a code block to markup of the output format.
+    ///
+    /// `info` is the info string of a fenced code block (e.g. `rust` or
+    /// `rust,ignore`), `None` for indented code blocks and fences without
+    /// one. `code` is the content of the block.
+    ///
+    /// The returned markup replaces the whole code block and is written to the
+    /// output as is, so it must be escaped properly, and for HTML include the
+    /// `<pre>` element. Return `None` to render the block as usual, e.g. for
+    /// unknown languages.
+    fn highlight(&self, info: Option<&str>, code: &str) -> Option<String>;
+}
+
+impl<F> CodeHighlighter for F
+where
+    F: Fn(Option<&str>, &str) -> Option<String> + Send + Sync,
+{
+    fn highlight(&self, info: Option<&str>, code: &str) -> Option<String> {
+        self(info, code)
+    }
+}
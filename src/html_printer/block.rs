@@ -1,6 +1,6 @@
 use crate::ast::*;
 use crate::html_printer::util::tag;
-use crate::html_printer::ToDoc;
+use crate::html_printer::{NodeRenderer, ToDoc};
 use pretty::{Arena, DocAllocator, DocBuilder};
 
 impl<'a> ToDoc<'a> for Vec<Block> {
@@ -21,53 +21,88 @@ impl<'a> ToDoc<'a> for Vec<&Block> {
 
 impl<'a> ToDoc<'a> for Block {
     fn to_doc(&self, state: &'a crate::html_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
-        match self {
-            Block::Paragraph(inlines) => {
-                let inner = state
-                    .arena
-                    .concat(inlines.iter().map(|inline| inline.to_doc(state)));
-                tag(state, "p", Vec::new(), inner)
-            }
-            Block::Heading(v) => {
-                let htag = match v.kind {
-                    HeadingKind::Atx(1) => "h1",
-                    HeadingKind::Atx(2) => "h2",
-                    HeadingKind::Atx(3) => "h3",
-                    HeadingKind::Atx(4) => "h4",
-                    HeadingKind::Atx(5) => "h5",
-                    HeadingKind::Atx(_) => "h6",
-                    HeadingKind::Setext(SetextHeading::Level1) => "h1",
-                    HeadingKind::Setext(SetextHeading::Level2) => "h2",
-                };
-                let inner = state
-                    .arena
-                    .concat(v.content.iter().map(|inline| inline.to_doc(state)));
-                tag(state, htag, Vec::new(), inner)
-            }
-            Block::ThematicBreak => tag(state, "hr", Vec::new(), state.arena.nil()),
-            Block::BlockQuote(inner) => {
-                let inner = state
-                    .arena
-                    .concat(inner.iter().map(|inline| inline.to_doc(state)));
-                tag(state, "blockquote", Vec::new(), inner)
-            }
-            Block::List(v) => v.to_doc(state),
-            Block::CodeBlock(v) => v.to_doc(state),
-            Block::HtmlBlock(html) => state.arena.text(html.clone()),
-            Block::Definition(_) => state.arena.nil(),
-            Block::Empty => state.arena.nil(),
-            Block::Table(v) => v.to_doc(state),
-            Block::FootnoteDefinition(def) => def.to_doc(state),
-            Block::GitHubAlert(alert) => alert.to_doc(state),
+        let custom = state
+            .config
+            .block_renderer
+            .as_ref()
+            .and_then(|render| render(self, &NodeRenderer::new(state)));
+        match custom {
+            Some(html) => state.arena.text(html),
+            None => default_block_to_doc(self, state),
+        }
+    }
+}
+
+/// Render a block without the block renderer of the configuration
+pub(crate) fn default_block_to_doc<'a>(
+    block: &Block,
+    state: &'a crate::html_printer::State<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    match block {
+        Block::Paragraph(inlines) => {
+            let inner = state
+                .arena
+                .concat(inlines.iter().map(|inline| inline.to_doc(state)));
+            tag(state, "p", Vec::new(), inner)
+        }
+        Block::Heading(v) => {
+            let htag = match v.kind {
+                HeadingKind::Atx(1) => "h1",
+                HeadingKind::Atx(2) => "h2",
+                HeadingKind::Atx(3) => "h3",
+                HeadingKind::Atx(4) => "h4",
+                HeadingKind::Atx(5) => "h5",
+                HeadingKind::Atx(_) => "h6",
+                HeadingKind::Setext(SetextHeading::Level1) => "h1",
+                HeadingKind::Setext(SetextHeading::Level2) => "h2",
+            };
+            let attributes = if state.config.heading_ids {
+                vec![("id".to_owned(), state.heading_id(v))]
+            } else {
+                Vec::new()
+            };
+            let inner = state
+                .arena
+                .concat(v.content.iter().map(|inline| inline.to_doc(state)));
+            tag(state, htag, attributes, inner)
         }
+        Block::ThematicBreak => tag(state, "hr", Vec::new(), state.arena.nil()),
+        Block::BlockQuote(inner) => {
+            let inner = state
+                .arena
+                .concat(inner.iter().map(|inline| inline.to_doc(state)));
+            tag(state, "blockquote", Vec::new(), inner)
+        }
+        Block::List(v) => v.to_doc(state),
+        Block::DefinitionList(v) => v.to_doc(state),
+        Block::CodeBlock(v) => v.to_doc(state),
+        Block::Math(math) => tag(
+            state,
+            "div",
+            vec![("class".to_owned(), "math display".to_owned())],
+            state
+                .arena
+                .text(crate::html_printer::util::escape(&format!("\\[{math}\\]"))),
+        ),
+        Block::HtmlBlock(html) => state
+            .arena
+            .text(crate::html_printer::sanitize::raw_html(&state.config, html)),
+        Block::Definition(_) => state.arena.nil(),
+        Block::FrontMatter { .. } => state.arena.nil(),
+        Block::Empty => state.arena.nil(),
+        Block::Table(v) => v.to_doc(state),
+        Block::FootnoteDefinition(def) => def.to_doc(state),
+        Block::GitHubAlert(alert) => alert.to_doc(state),
     }
 }
 
 impl<'a> ToDoc<'a> for List {
     fn to_doc(&self, state: &'a crate::html_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
-        let items = state
-            .arena
-            .concat(self.items.iter().map(|item| item.to_doc(state)));
+        let items = state.arena.concat(
+            self.items
+                .iter()
+                .map(|item| list_item(item, self.tight, state)),
+        );
         match self.kind {
             ListKind::Ordered(ListOrderedKindOptions { start }) => tag(
                 state,
@@ -92,38 +127,69 @@ impl<'a> ToDoc<'a> for List {
     }
 }
 
-impl<'a> ToDoc<'a> for ListItem {
+/// Paragraphs of tight list items are rendered without `<p>`
+fn list_item<'a>(
+    item: &ListItem,
+    tight: bool,
+    state: &'a crate::html_printer::State<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let task = match item.task {
+        Some(TaskState::Complete) => tag(
+            state,
+            "span",
+            vec![("class".to_owned(), "markdown-list-task-complete".to_owned())],
+            state.arena.text("[X] "),
+        ),
+        Some(TaskState::Incomplete) => tag(
+            state,
+            "span",
+            vec![(
+                "class".to_owned(),
+                "markdown-list-task-incomplete".to_owned(),
+            )],
+            state.arena.text("[ ] "),
+        ),
+        None => state.arena.nil(),
+    };
+    let content = task.append(
+        state
+            .arena
+            .concat(item.blocks.iter().map(|block| match block {
+                Block::Paragraph(inlines) if tight => inlines.to_doc(state),
+                block => block.to_doc(state),
+            })),
+    );
+
+    tag(state, "li", vec![], content)
+}
+
+impl<'a> ToDoc<'a> for DefinitionList {
     fn to_doc(&self, state: &'a crate::html_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
-        let task = match self.task {
-            Some(TaskState::Complete) => tag(
-                state,
-                "span",
-                vec![("class".to_owned(), "markdown-list-task-complete".to_owned())],
-                state.arena.text("[X] "),
-            ),
-            Some(TaskState::Incomplete) => tag(
-                state,
-                "span",
-                vec![(
-                    "class".to_owned(),
-                    "markdown-list-task-incomplete".to_owned(),
-                )],
-                state.arena.text("[ ] "),
-            ),
-            None => state.arena.nil(),
-        };
-        let content = task.append(
-            state
-                .arena
-                .concat(self.blocks.iter().map(|block| block.to_doc(state))),
-        );
+        let items = state.arena.concat(self.items.iter().map(|item| {
+            let term = tag(state, "dt", Vec::new(), item.term.to_doc(state));
+            let definitions = state.arena.concat(
+                item.definitions
+                    .iter()
+                    .map(|blocks| tag(state, "dd", Vec::new(), blocks.to_doc(state))),
+            );
+            term.append(definitions)
+        }));
 
-        tag(state, "li", vec![], content)
+        tag(state, "dl", Vec::new(), items)
     }
 }
 
 impl<'a> ToDoc<'a> for CodeBlock {
     fn to_doc(&self, state: &'a crate::html_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        if let Some(highlighter) = &state.config.code_highlighter {
+            let info = match &self.kind {
+                CodeBlockKind::Fenced { info } => info.as_deref(),
+                CodeBlockKind::Indented => None,
+            };
+            if let Some(markup) = highlighter.highlight(info, &self.literal) {
+                return state.arena.text(markup);
+            }
+        }
         tag(
             state,
             "pre",
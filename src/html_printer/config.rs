@@ -1,7 +1,102 @@
+use crate::ast::{Block, Inline};
+use crate::highlight::CodeHighlighter;
+use crate::html_printer::NodeRenderer;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// Function type for custom rendering of blocks, see
+/// [`Config::with_block_renderer`].
+pub type BlockRendererFn = Arc<dyn Fn(&Block, &NodeRenderer<'_>) -> Option<String> + Send + Sync>;
+
+/// Function type for custom rendering of inlines, see
+/// [`Config::with_inline_renderer`].
+pub type InlineRendererFn = Arc<dyn Fn(&Inline, &NodeRenderer<'_>) -> Option<String> + Send + Sync>;
+
+/// How raw HTML (`Block::HtmlBlock` and `Inline::Html`) is rendered.
+///
+/// All policies except `Allow` are meant for untrusted input: unless set with
+/// [`Config::with_allowed_url_schemes`], they only allow `http`, `https` and
+/// `mailto` URLs (and relative ones) in links, images and autolinks.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum RawHtmlPolicy {
+    /// Output raw HTML verbatim. Only suitable for trusted input.
+    #[default]
+    Allow,
+    /// Escape raw HTML, so it is shown as text
+    Escape,
+    /// Omit raw HTML
+    Drop,
+    /// Keep only the allowed tags and attributes. Comments, other tags and
+    /// the content of `<script>` and `<style>` elements are removed.
+    Allowlist(HtmlAllowlist),
+}
+
+/// How soft line breaks ([`Inline::SoftBreak`]) are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SoftBreakStyle {
+    /// Output a newline, which browsers show as a space
+    #[default]
+    Newline,
+    /// Output a space
+    Space,
+    /// Output a `<br>` element followed by a newline, like GitHub comments
+    LineBreak,
+}
+
+/// Tags and attributes kept by [`RawHtmlPolicy::Allowlist`].
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::html_printer::config::*;
+///
+/// let allowlist = HtmlAllowlist::new()
+///     .with_tag("b", &[])
+///     .with_tag("a", &["href", "title"]);
+/// let config = Config::default().with_raw_html(RawHtmlPolicy::Allowlist(allowlist));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HtmlAllowlist {
+    tags: HashMap<String, HashSet<String>>,
+}
+
+impl HtmlAllowlist {
+    /// Create an allowlist that allows nothing.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow `tag` with the given attributes. Names are case-insensitive.
+    pub fn with_tag(mut self, tag: &str, attributes: &[&str]) -> Self {
+        self.tags
+            .entry(tag.to_ascii_lowercase())
+            .or_default()
+            .extend(attributes.iter().map(|a| a.to_ascii_lowercase()));
+        self
+    }
+
+    pub(crate) fn allows_tag(&self, tag: &str) -> bool {
+        self.tags.contains_key(&tag.to_ascii_lowercase())
+    }
+
+    pub(crate) fn allows_attribute(&self, tag: &str, attribute: &str) -> bool {
+        self.tags
+            .get(&tag.to_ascii_lowercase())
+            .is_some_and(|attributes| attributes.contains(&attribute.to_ascii_lowercase()))
+    }
+}
+
 /// Configuration for HTML rendering output.
 pub struct Config {
     pub(crate) width: usize,
     pub(crate) anchor_prefix: String,
+    pub(crate) heading_ids: bool,
+    pub(crate) raw_html: RawHtmlPolicy,
+    pub(crate) allowed_url_schemes: Option<Vec<String>>,
+    pub(crate) soft_break: SoftBreakStyle,
+    pub(crate) code_highlighter: Option<Arc<dyn CodeHighlighter>>,
+    pub(crate) block_renderer: Option<BlockRendererFn>,
+    pub(crate) inline_renderer: Option<InlineRendererFn>,
 }
 
 impl Default for Config {
@@ -9,6 +104,13 @@ impl Default for Config {
         Self {
             width: 80,
             anchor_prefix: String::new(),
+            heading_ids: false,
+            raw_html: RawHtmlPolicy::Allow,
+            allowed_url_schemes: None,
+            soft_break: SoftBreakStyle::Newline,
+            code_highlighter: None,
+            block_renderer: None,
+            inline_renderer: None,
         }
     }
 }
@@ -26,4 +128,130 @@ impl Config {
             ..self
         }
     }
+
+    /// Add `id` attributes to headings, so sections can be linked to.
+    ///
+    /// IDs are GitHub-compatible slugs of the heading text (see
+    /// [`Slugger`](crate::ast::Slugger)) prepended with the anchor prefix.
+    pub fn with_heading_ids(self, heading_ids: bool) -> Self {
+        Self {
+            heading_ids,
+            ..self
+        }
+    }
+
+    /// Set how raw HTML is rendered. The default is [`RawHtmlPolicy::Allow`].
+    pub fn with_raw_html(self, raw_html: RawHtmlPolicy) -> Self {
+        Self { raw_html, ..self }
+    }
+
+    /// Only link to URLs with one of the given schemes (e.g. `https`,
+    /// `mailto`). Relative URLs are always allowed.
+    ///
+    /// Links and autolinks to other URLs are rendered as text, and images as
+    /// their alt text. With [`RawHtmlPolicy::Allowlist`], URL attributes of
+    /// raw HTML are filtered too. By default all schemes are allowed with
+    /// [`RawHtmlPolicy::Allow`], and only `http`, `https` and `mailto` with
+    /// the other policies.
+    pub fn with_allowed_url_schemes(self, schemes: Vec<String>) -> Self {
+        Self {
+            allowed_url_schemes: Some(schemes),
+            ..self
+        }
+    }
+
+    /// Set how soft line breaks are rendered. The default is
+    /// [`SoftBreakStyle::Newline`].
+    pub fn with_soft_break(self, soft_break: SoftBreakStyle) -> Self {
+        Self { soft_break, ..self }
+    }
+
+    /// Render code blocks with `highlighter`. Code blocks it declines are
+    /// rendered as escaped text in `<pre><code>`.
+    pub fn with_code_highlighter(self, highlighter: Arc<dyn CodeHighlighter>) -> Self {
+        Self {
+            code_highlighter: Some(highlighter),
+            ..self
+        }
+    }
+
+    /// Render blocks with `renderer` instead of the default markup.
+    ///
+    /// The renderer is called for every block, also for nested ones, and
+    /// returns its HTML or `None` for the default markup. Its output is not
+    /// escaped or sanitized. Paragraphs of tight list items are rendered
+    /// without `<p>` and do not go through the renderer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::ast::*;
+    /// use markdown_ppp::html_printer::{config::Config, render_html};
+    /// use std::sync::Arc;
+    ///
+    /// // Wrap alerts in custom markup
+    /// let config = Config::default().with_block_renderer(Arc::new(|block, renderer| {
+    ///     let Block::GitHubAlert(alert) = block else {
+    ///         return None;
+    ///     };
+    ///     Some(format!("<aside>{}</aside>", renderer.blocks(&alert.blocks)))
+    /// }));
+    /// let doc = Document {
+    ///     blocks: vec![Block::GitHubAlert(GitHubAlert {
+    ///         alert_type: GitHubAlertType::Note,
+    ///         blocks: vec![Block::Paragraph(vec![Inline::Text("Hi".to_string())])],
+    ///     })],
+    /// };
+    /// assert_eq!(render_html(&doc, config), "<aside><p>Hi</p></aside>");
+    /// ```
+    pub fn with_block_renderer(self, renderer: BlockRendererFn) -> Self {
+        Self {
+            block_renderer: Some(renderer),
+            ..self
+        }
+    }
+
+    /// Render inlines with `renderer` instead of the default markup.
+    ///
+    /// Like [`with_block_renderer`](Self::with_block_renderer), for inlines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::ast::*;
+    /// use markdown_ppp::html_printer::{config::Config, escape, render_html};
+    /// use std::sync::Arc;
+    ///
+    /// // Open external links in a new tab
+    /// let config = Config::default().with_inline_renderer(Arc::new(|inline, renderer| {
+    ///     let Inline::Link(link) = inline else {
+    ///         return None;
+    ///     };
+    ///     if !link.destination.starts_with("https://") {
+    ///         return None;
+    ///     }
+    ///     Some(format!(
+    ///         "<a href=\"{}\" target=\"_blank\" rel=\"noopener\">{}</a>",
+    ///         escape(&link.destination),
+    ///         renderer.inlines(&link.children),
+    ///     ))
+    /// }));
+    /// let doc = Document {
+    ///     blocks: vec![Block::Paragraph(vec![Inline::Link(Link {
+    ///         destination: "https://example.com".to_string(),
+    ///         title: None,
+    ///         children: vec![Inline::Text("Example".to_string())],
+    ///     })])],
+    /// };
+    /// assert_eq!(
+    ///     render_html(&doc, config),
+    ///     "<p><a href=\"https://example.com\" target=\"_blank\" rel=\"noopener\">Example</a></p>"
+    /// );
+    /// ```
+    pub fn with_inline_renderer(self, renderer: InlineRendererFn) -> Self {
+        Self {
+            inline_renderer: Some(renderer),
+            ..self
+        }
+    }
 }
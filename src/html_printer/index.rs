@@ -65,6 +65,16 @@ fn collect_footnote_refs(footnotes: &mut FootnoteIndex, block: &Block) {
                 }
             }
         }
+        Block::DefinitionList(v) => {
+            for item in &v.items {
+                for inline in &item.term {
+                    collect_inline_refs(footnotes, inline);
+                }
+                for block in item.definitions.iter().flatten() {
+                    collect_footnote_refs(footnotes, block);
+                }
+            }
+        }
         Block::Definition(v) => {
             for inline in &v.label {
                 collect_inline_refs(footnotes, inline);
@@ -89,7 +99,12 @@ fn collect_footnote_refs(footnotes: &mut FootnoteIndex, block: &Block) {
                 collect_footnote_refs(footnotes, block);
             }
         }
-        Block::ThematicBreak | Block::CodeBlock(_) | Block::HtmlBlock(_) | Block::Empty => (),
+        Block::ThematicBreak
+        | Block::CodeBlock(_)
+        | Block::Math(_)
+        | Block::HtmlBlock(_)
+        | Block::FrontMatter { .. }
+        | Block::Empty => (),
     }
 }
 
@@ -98,8 +113,8 @@ fn collect_inline_refs(footnotes: &mut FootnoteIndex, inline: &Inline) {
         Inline::FootnoteReference(label) => {
             footnotes.add(label.clone());
         }
-        Inline::Emphasis(children)
-        | Inline::Strong(children)
+        Inline::Emphasis(Emphasis { content: children, .. })
+        | Inline::Strong(Emphasis { content: children, .. })
         | Inline::Strikethrough(children) => {
             for child in children {
                 collect_inline_refs(footnotes, child);
@@ -117,7 +132,9 @@ fn collect_inline_refs(footnotes: &mut FootnoteIndex, inline: &Inline) {
         }
         Inline::Text(_)
         | Inline::LineBreak
+        | Inline::SoftBreak
         | Inline::Code(_)
+        | Inline::Math(_)
         | Inline::Html(_)
         | Inline::Image(_)
         | Inline::Autolink(_)
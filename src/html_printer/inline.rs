@@ -1,6 +1,8 @@
 use crate::ast::*;
-use crate::html_printer::util::{escape, tag};
-use crate::html_printer::ToDoc;
+use crate::html_printer::config::SoftBreakStyle;
+use crate::html_printer::sanitize::{is_allowed_url, raw_html};
+use crate::html_printer::util::{escape, tag, void_tag};
+use crate::html_printer::{NodeRenderer, ToDoc};
 use pretty::{Arena, DocAllocator, DocBuilder};
 
 impl<'a> ToDoc<'a> for Vec<Inline> {
@@ -13,76 +15,120 @@ impl<'a> ToDoc<'a> for Vec<Inline> {
 
 impl<'a> ToDoc<'a> for Inline {
     fn to_doc(&self, state: &'a crate::html_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
-        match self {
-            Inline::Text(t) => state.arena.text(escape(t)),
-            Inline::LineBreak => tag(state, "br", Vec::new(), state.arena.nil()),
-            Inline::Code(code) => tag(state, "code", Vec::new(), state.arena.text(escape(code))),
-            Inline::Html(html) => state.arena.text(html.clone()),
-            Inline::Emphasis(children) => tag(state, "em", Vec::new(), children.to_doc(state)),
-            Inline::Strong(children) => tag(state, "b", Vec::new(), children.to_doc(state)),
-            Inline::Strikethrough(children) => tag(state, "s", Vec::new(), children.to_doc(state)),
-            Inline::Link(Link {
-                destination,
-                title,
-                children,
-            }) => {
-                let mut attributes = vec![("href".to_owned(), escape(destination))];
-                if let Some(title) = title {
-                    attributes.push(("title".to_owned(), escape(title)))
-                }
-                tag(state, "a", attributes, children.to_doc(state))
+        let custom = state
+            .config
+            .inline_renderer
+            .as_ref()
+            .and_then(|render| render(self, &NodeRenderer::new(state)));
+        match custom {
+            Some(html) => state.arena.text(html),
+            None => default_inline_to_doc(self, state),
+        }
+    }
+}
+
+/// Render an inline without the inline renderer of the configuration
+pub(crate) fn default_inline_to_doc<'a>(
+    inline: &Inline,
+    state: &'a crate::html_printer::State<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    match inline {
+        Inline::Text(t) => state.arena.text(escape(t)),
+        Inline::LineBreak => void_tag(state, "br", Vec::new()),
+        Inline::SoftBreak => match state.config.soft_break {
+            SoftBreakStyle::Newline => state.arena.text("\n"),
+            SoftBreakStyle::Space => state.arena.text(" "),
+            SoftBreakStyle::LineBreak => {
+                void_tag(state, "br", Vec::new()).append(state.arena.text("\n"))
+            }
+        },
+        Inline::Code(code) => tag(state, "code", Vec::new(), state.arena.text(escape(code))),
+        Inline::Math(math) => tag(
+            state,
+            "span",
+            vec![("class".to_owned(), "math inline".to_owned())],
+            state.arena.text(escape(&format!("\\({math}\\)"))),
+        ),
+        Inline::Html(html) => state.arena.text(raw_html(&state.config, html)),
+        Inline::Emphasis(Emphasis {
+            content: children, ..
+        }) => tag(state, "em", Vec::new(), children.to_doc(state)),
+        Inline::Strong(Emphasis {
+            content: children, ..
+        }) => tag(state, "b", Vec::new(), children.to_doc(state)),
+        Inline::Strikethrough(children) => tag(state, "s", Vec::new(), children.to_doc(state)),
+        Inline::Link(Link {
+            destination,
+            title,
+            children,
+        }) => {
+            if !is_allowed_url(&state.config, destination) {
+                return children.to_doc(state);
             }
-            Inline::Image(Image {
-                destination,
-                title,
-                alt,
-            }) => {
-                let mut attributes = vec![
-                    ("src".to_owned(), escape(destination)),
-                    ("alt".to_owned(), escape(alt)),
-                ];
-                if let Some(title) = title {
-                    attributes.push(("title".to_owned(), escape(title)))
-                }
-                tag(state, "img", attributes, state.arena.nil())
+            let mut attributes = vec![("href".to_owned(), escape(destination))];
+            if let Some(title) = title {
+                attributes.push(("title".to_owned(), escape(title)))
             }
-            Inline::Autolink(link) => tag(
+            tag(state, "a", attributes, children.to_doc(state))
+        }
+        Inline::Image(Image {
+            destination,
+            title,
+            alt,
+        }) => {
+            if !is_allowed_url(&state.config, destination) {
+                return state.arena.text(escape(alt));
+            }
+            let mut attributes = vec![
+                ("src".to_owned(), escape(destination)),
+                ("alt".to_owned(), escape(alt)),
+            ];
+            if let Some(title) = title {
+                attributes.push(("title".to_owned(), escape(title)))
+            }
+            tag(state, "img", attributes, state.arena.nil())
+        }
+        Inline::Autolink(link) if !is_allowed_url(&state.config, link) => {
+            state.arena.text(escape(link))
+        }
+        Inline::Autolink(link) => tag(
+            state,
+            "a",
+            vec![("href".to_owned(), escape(link))],
+            state.arena.text(escape(link)),
+        ),
+        Inline::FootnoteReference(label) => {
+            let index = match state.get_footnote_index(label) {
+                Some(v) => v,
+                None => return state.arena.nil(),
+            };
+            tag(
                 state,
                 "a",
-                vec![("href".to_owned(), escape(link))],
-                state.arena.text(escape(link)),
-            ),
-            Inline::FootnoteReference(label) => {
-                let index = match state.get_footnote_index(label) {
-                    Some(v) => v,
-                    None => return state.arena.nil(),
-                };
-                tag(
-                    state,
-                    "a",
-                    vec![
-                        ("class".to_owned(), "markdown-footnote-reference".to_owned()),
-                        (
-                            "href".to_owned(),
-                            escape(&format!("#{}{}", state.config.anchor_prefix, index)),
-                        ),
-                    ],
-                    state.arena.text(format!("[{index}]")),
-                )
+                vec![
+                    ("class".to_owned(), "markdown-footnote-reference".to_owned()),
+                    (
+                        "href".to_owned(),
+                        escape(&format!("#{}{}", state.config.anchor_prefix, index)),
+                    ),
+                ],
+                state.arena.text(format!("[{index}]")),
+            )
+        }
+        Inline::Empty => state.arena.nil(),
+        Inline::LinkReference(v) => {
+            let definition = match state.get_link_definition(&v.label) {
+                Some(v) => v,
+                None => return state.arena.nil(),
+            };
+            if !is_allowed_url(&state.config, &definition.destination) {
+                return v.text.to_doc(state);
             }
-            Inline::Empty => state.arena.nil(),
-            Inline::LinkReference(v) => {
-                let definition = match state.get_link_definition(&v.label) {
-                    Some(v) => v,
-                    None => return state.arena.nil(),
-                };
-                let mut attributes =
-                    vec![("href".to_owned(), escape(definition.destination.as_str()))];
-                if let Some(title) = &definition.title {
-                    attributes.push(("title".to_owned(), escape(title)))
-                }
-                tag(state, "a", attributes, v.text.to_doc(state))
+            let mut attributes = vec![("href".to_owned(), escape(definition.destination.as_str()))];
+            if let Some(title) = &definition.title {
+                attributes.push(("title".to_owned(), escape(title)))
             }
+            tag(state, "a", attributes, v.text.to_doc(state))
         }
     }
 }
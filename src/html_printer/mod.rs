@@ -27,7 +27,7 @@
 //!         }),
 //!         Block::Paragraph(vec![
 //!             Inline::Text("This is ".to_string()),
-//!             Inline::Strong(vec![Inline::Text("bold".to_string())]),
+//!             Inline::Strong(vec![Inline::Text("bold".to_string())].into()),
 //!             Inline::Text(" text.".to_string()),
 //!         ]),
 //!     ],
@@ -59,12 +59,15 @@ pub mod config;
 mod github_alert;
 mod index;
 mod inline;
+mod sanitize;
 mod tests;
 mod util;
 
 use crate::ast::*;
-use pretty::{Arena, DocBuilder};
-use std::{collections::HashMap, rc::Rc};
+use pretty::{Arena, DocAllocator, DocBuilder};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+pub use crate::html_printer::util::escape;
 
 /// Internal rendering state for HTML generation
 ///
@@ -75,11 +78,13 @@ pub(crate) struct State<'a> {
     /// Pretty-printing arena for efficient document building
     arena: Arena<'a>,
     /// HTML rendering configuration
-    config: crate::html_printer::config::Config,
+    config: Rc<crate::html_printer::config::Config>,
     /// Mapping of footnote labels to their indices in the footnote list
-    footnote_index: HashMap<String, usize>,
+    footnote_index: Rc<HashMap<String, usize>>,
     /// Mapping of link labels to their definitions for reference link resolution
-    link_definitions: HashMap<Vec<Inline>, LinkDefinition>,
+    link_definitions: Rc<HashMap<Vec<Inline>, LinkDefinition>>,
+    /// Generator of heading IDs, headings are rendered in document order
+    slugger: Rc<RefCell<Slugger>>,
 }
 
 impl State<'_> {
@@ -88,9 +93,22 @@ impl State<'_> {
         let arena = Arena::new();
         Self {
             arena,
-            config,
-            footnote_index,
-            link_definitions,
+            config: Rc::new(config),
+            footnote_index: Rc::new(footnote_index),
+            link_definitions: Rc::new(link_definitions),
+            slugger: Rc::new(RefCell::new(Slugger::new())),
+        }
+    }
+
+    /// State for rendering the next part of the same document with a fresh
+    /// arena, so that the nodes of rendered parts can be freed
+    fn with_new_arena<'b>(&self) -> State<'b> {
+        State {
+            arena: Arena::new(),
+            config: self.config.clone(),
+            footnote_index: self.footnote_index.clone(),
+            link_definitions: self.link_definitions.clone(),
+            slugger: self.slugger.clone(),
         }
     }
 
@@ -101,6 +119,67 @@ impl State<'_> {
     pub fn get_link_definition(&self, label: &[Inline]) -> Option<&LinkDefinition> {
         self.link_definitions.get(label)
     }
+
+    pub fn heading_id(&self, heading: &Heading) -> String {
+        let slug = self.slugger.borrow_mut().heading_slug(heading);
+        format!("{}{}", self.config.anchor_prefix, slug)
+    }
+}
+
+/// Renders nodes for the custom renderers of the configuration
+///
+/// Passed to the functions set with
+/// [`with_block_renderer`](config::Config::with_block_renderer) and
+/// [`with_inline_renderer`](config::Config::with_inline_renderer), to render
+/// the children of a node, or the node itself with the default markup. Nested
+/// nodes go through the custom renderers as well.
+pub struct NodeRenderer<'a> {
+    state: &'a State<'a>,
+}
+
+impl<'a> NodeRenderer<'a> {
+    pub(crate) fn new(state: &'a State<'a>) -> Self {
+        Self { state }
+    }
+
+    /// Render blocks, e.g. the content of a block quote.
+    pub fn blocks(&self, blocks: &[Block]) -> String {
+        let state = self.state;
+        self.render(state.arena.concat(blocks.iter().map(|block| block.to_doc(state))))
+    }
+
+    /// Render inlines, e.g. the text of a link.
+    pub fn inlines(&self, inlines: &[Inline]) -> String {
+        let state = self.state;
+        self.render(
+            state
+                .arena
+                .concat(inlines.iter().map(|inline| inline.to_doc(state))),
+        )
+    }
+
+    /// Render a block with the default markup. Its children still go through
+    /// the custom renderers.
+    pub fn default_block(&self, block: &Block) -> String {
+        self.render(crate::html_printer::block::default_block_to_doc(
+            block, self.state,
+        ))
+    }
+
+    /// Render an inline with the default markup. Its children still go
+    /// through the custom renderers.
+    pub fn default_inline(&self, inline: &Inline) -> String {
+        self.render(crate::html_printer::inline::default_inline_to_doc(
+            inline, self.state,
+        ))
+    }
+
+    fn render(&self, doc: DocBuilder<'a, Arena<'a>, ()>) -> String {
+        let mut buf = Vec::new();
+        doc.render(self.state.config.width, &mut buf)
+            .expect("Vec<u8> write is infallible");
+        String::from_utf8(buf).expect("pretty crate always produces valid UTF-8")
+    }
 }
 
 /// Render a Markdown AST to semantic HTML
@@ -134,7 +213,7 @@ impl State<'_> {
 ///         }),
 ///         Block::Paragraph(vec![
 ///             Inline::Text("Text with ".to_string()),
-///             Inline::Strong(vec![Inline::Text("emphasis".to_string())]),
+///             Inline::Strong(vec![Inline::Text("emphasis".to_string())].into()),
 ///         ]),
 ///     ],
 /// };
@@ -162,14 +241,54 @@ impl State<'_> {
 /// All user content is properly escaped to prevent XSS attacks.
 /// HTML content in the AST is preserved as-is (assumed to be trusted).
 pub fn render_html(ast: &Document, config: crate::html_printer::config::Config) -> String {
-    let state = Rc::new(State::new(config, ast));
-    let doc = ast.to_doc(&state);
-
     let mut buf = Vec::new();
-    doc.render(state.config.width, &mut buf).expect("Vec<u8> write is infallible");
+    render_html_to(ast, config, &mut buf).expect("Vec<u8> write is infallible");
     String::from_utf8(buf).expect("pretty crate always produces valid UTF-8")
 }
 
+/// Render a Markdown AST to semantic HTML, writing to `writer`
+///
+/// Produces the same output as [`render_html`], but renders and writes one
+/// top-level block at a time, so memory use is bounded by the largest block
+/// instead of the whole document. Useful for streaming large documents to
+/// files or network responses. The output is written in many small pieces, so
+/// unbuffered writers should be wrapped in a [`std::io::BufWriter`].
+///
+/// # Errors
+///
+/// Returns the first error of `writer`.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::html_printer::{render_html, render_html_to, config::Config};
+///
+/// let doc = Document {
+///     blocks: vec![
+///         Block::Paragraph(vec![Inline::Text("First".to_string())]),
+///         Block::Paragraph(vec![Inline::Text("Second".to_string())]),
+///     ],
+/// };
+///
+/// let mut out = Vec::new();
+/// render_html_to(&doc, Config::default(), &mut out).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), render_html(&doc, Config::default()));
+/// ```
+pub fn render_html_to<W: std::io::Write>(
+    ast: &Document,
+    config: crate::html_printer::config::Config,
+    mut writer: W,
+) -> std::io::Result<()> {
+    let state = State::new(config, ast);
+    for block in &ast.blocks {
+        let block_state = state.with_new_arena();
+        let doc = block.to_doc(&block_state);
+        doc.render(block_state.config.width, &mut writer)?;
+    }
+    Ok(())
+}
+
 trait ToDoc<'a> {
     fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()>;
 }
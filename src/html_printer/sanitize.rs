@@ -0,0 +1,242 @@
+//! Filtering of raw HTML and URLs for untrusted input.
+
+use crate::html_printer::config::{Config, HtmlAllowlist, RawHtmlPolicy};
+use crate::html_printer::util::escape;
+
+/// Attributes whose values are URLs.
+const URL_ATTRIBUTES: &[&str] = &[
+    "action",
+    "background",
+    "cite",
+    "formaction",
+    "href",
+    "poster",
+    "src",
+    "xlink:href",
+];
+
+/// URL schemes allowed with raw HTML policies other than
+/// [`RawHtmlPolicy::Allow`] unless configured otherwise with
+/// `Config::with_allowed_url_schemes`.
+const SAFE_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// Elements whose content is removed together with the element.
+const RAW_TEXT_ELEMENTS: &[&str] = &["script", "style"];
+
+/// Render raw HTML according to the configured policy.
+pub(crate) fn raw_html(config: &Config, html: &str) -> String {
+    match &config.raw_html {
+        RawHtmlPolicy::Allow => html.to_owned(),
+        RawHtmlPolicy::Escape => escape(html),
+        RawHtmlPolicy::Drop => String::new(),
+        RawHtmlPolicy::Allowlist(allowlist) => sanitize(config, allowlist, html),
+    }
+}
+
+/// Whether a link to `url` may be rendered.
+pub(crate) fn is_allowed_url(config: &Config, url: &str) -> bool {
+    let is_allowed_scheme = |scheme: &str| match &config.allowed_url_schemes {
+        Some(schemes) => schemes.iter().any(|s| s.eq_ignore_ascii_case(scheme)),
+        None => SAFE_URL_SCHEMES
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(scheme)),
+    };
+    // Trusted input may link anywhere unless configured otherwise
+    if config.allowed_url_schemes.is_none() && config.raw_html == RawHtmlPolicy::Allow {
+        return true;
+    }
+
+    // Browsers ignore whitespace and control characters in URLs
+    let url: String = url
+        .chars()
+        .filter(|c| !c.is_ascii_control() && *c != ' ')
+        .collect();
+    let prefix_end = url.find(['/', '?', '#']).unwrap_or(url.len());
+    let prefix = &url[..prefix_end];
+    match prefix.find(':') {
+        Some(colon) => is_allowed_scheme(&prefix[..colon]),
+        // Entities may hide a colon, so such prefixes are never relative
+        None => !prefix.contains('&'),
+    }
+}
+
+/// Keep the allowed tags and attributes of `html`, and escape the rest of the
+/// markup-like text.
+fn sanitize(config: &Config, allowlist: &HtmlAllowlist, html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    // Name of the raw text element whose content is being removed
+    let mut skipping: Option<String> = None;
+
+    while let Some(start) = rest.find('<') {
+        if skipping.is_none() {
+            out.push_str(&rest[..start].replace('>', "&gt;"));
+        }
+        rest = &rest[start..];
+
+        let Some((token, len)) = parse_token(rest) else {
+            if skipping.is_none() {
+                out.push_str("&lt;");
+            }
+            rest = &rest[1..];
+            continue;
+        };
+        rest = &rest[len..];
+
+        match token {
+            Token::Other => {}
+            Token::Close(name) => match &skipping {
+                Some(skipped) if skipped.eq_ignore_ascii_case(name) => skipping = None,
+                Some(_) => {}
+                None if allowlist.allows_tag(name) => {
+                    out.push_str(&format!("</{}>", name.to_ascii_lowercase()))
+                }
+                None => {}
+            },
+            Token::Open {
+                name,
+                attributes,
+                self_closing,
+            } => {
+                if skipping.is_some() {
+                    continue;
+                }
+                if !allowlist.allows_tag(name) {
+                    let is_raw_text = RAW_TEXT_ELEMENTS
+                        .iter()
+                        .any(|element| element.eq_ignore_ascii_case(name));
+                    if is_raw_text && !self_closing {
+                        skipping = Some(name.to_owned());
+                    }
+                    continue;
+                }
+
+                out.push('<');
+                out.push_str(&name.to_ascii_lowercase());
+                for (attribute, value) in attributes {
+                    if !allowlist.allows_attribute(name, attribute) {
+                        continue;
+                    }
+                    let is_url = URL_ATTRIBUTES
+                        .iter()
+                        .any(|url_attribute| url_attribute.eq_ignore_ascii_case(attribute));
+                    if is_url && !is_allowed_url(config, value) {
+                        continue;
+                    }
+                    out.push(' ');
+                    out.push_str(&attribute.to_ascii_lowercase());
+                    out.push_str("=\"");
+                    out.push_str(&value.replace('"', "&quot;").replace('<', "&lt;"));
+                    out.push('"');
+                }
+                out.push_str(if self_closing { " />" } else { ">" });
+            }
+        }
+    }
+
+    if skipping.is_none() {
+        out.push_str(&rest.replace('>', "&gt;"));
+    }
+    out
+}
+
+enum Token<'a> {
+    Open {
+        name: &'a str,
+        attributes: Vec<(&'a str, &'a str)>,
+        self_closing: bool,
+    },
+    Close(&'a str),
+    /// Comment, declaration, processing instruction or CDATA section
+    Other,
+}
+
+/// Parse the markup starting at `<`, returning the token and its length.
+fn parse_token(input: &str) -> Option<(Token<'_>, usize)> {
+    if let Some(comment) = input.strip_prefix("<!--") {
+        let len = comment.find("-->").map_or(input.len(), |end| end + 7);
+        return Some((Token::Other, len));
+    }
+    if input.starts_with("<!") || input.starts_with("<?") {
+        let len = input.find('>').map_or(input.len(), |end| end + 1);
+        return Some((Token::Other, len));
+    }
+    if let Some(after) = input.strip_prefix("</") {
+        let name = tag_name(after)?;
+        let rest = after[name.len()..].trim_start();
+        let rest = rest.strip_prefix('>')?;
+        return Some((Token::Close(name), input.len() - rest.len()));
+    }
+
+    let name = tag_name(&input[1..])?;
+    let mut rest = &input[1 + name.len()..];
+    let mut attributes = Vec::new();
+    loop {
+        let trimmed = rest.trim_start();
+        if let Some(after) = trimmed.strip_prefix("/>") {
+            return Some((
+                Token::Open {
+                    name,
+                    attributes,
+                    self_closing: true,
+                },
+                input.len() - after.len(),
+            ));
+        }
+        if let Some(after) = trimmed.strip_prefix('>') {
+            return Some((
+                Token::Open {
+                    name,
+                    attributes,
+                    self_closing: false,
+                },
+                input.len() - after.len(),
+            ));
+        }
+        // Attributes must be separated by whitespace
+        if trimmed.len() == rest.len() {
+            return None;
+        }
+
+        let attribute_len = trimmed
+            .find(|c: char| c.is_ascii_whitespace() || matches!(c, '/' | '>' | '=' | '"' | '\''))
+            .unwrap_or(trimmed.len());
+        if attribute_len == 0 {
+            return None;
+        }
+        let attribute = &trimmed[..attribute_len];
+        rest = &trimmed[attribute_len..];
+
+        let value = match rest.trim_start().strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+                let (value, len) = match after.chars().next()? {
+                    quote @ ('"' | '\'') => {
+                        let end = after[1..].find(quote)?;
+                        (&after[1..end + 1], end + 2)
+                    }
+                    _ => {
+                        let end = after
+                            .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                            .unwrap_or(after.len());
+                        (&after[..end], end)
+                    }
+                };
+                rest = &after[len..];
+                value
+            }
+            None => "",
+        };
+        attributes.push((attribute, value));
+    }
+}
+
+fn tag_name(input: &str) -> Option<&str> {
+    if !input.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    let len = input
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+        .unwrap_or(input.len());
+    Some(&input[..len])
+}
@@ -1,6 +1,9 @@
 #![cfg(test)]
+use crate::html_printer::config::{Config, RawHtmlPolicy, SoftBreakStyle};
 use rstest::rstest;
 
+mod spec;
+
 #[rstest]
 #[case("Hello, world!", "<p>Hello, world!</p>")]
 #[case("Hello, **world**!", "<p>Hello, <b>world</b>!</p>")]
@@ -10,12 +13,24 @@ use rstest::rstest;
 #[case("Hello, ~~world~~!", "<p>Hello, <s>world</s>!</p>")]
 #[case(
     "1. Item 1\n2. Item 2",
-    "<ol start=\"1\"><li><p>Item 1</p></li><li><p>Item 2</p></li></ol>"
+    "<ol start=\"1\"><li>Item 1</li><li>Item 2</li></ol>"
 )]
 #[case(
     "* Item 1\n* Item 2",
+    "<ul class=\"markdown-list-kind-star\"><li>Item 1</li><li>Item 2</li></ul>"
+)]
+#[case(
+    "* Item 1\n\n* Item 2",
     "<ul class=\"markdown-list-kind-star\"><li><p>Item 1</p></li><li><p>Item 2</p></li></ul>"
 )]
+#[case(
+    "* Item 1\n\n  More\n* Item 2",
+    "<ul class=\"markdown-list-kind-star\"><li><p>Item 1</p><p>More</p></li><li><p>Item 2</p></li></ul>"
+)]
+#[case(
+    "* Item 1\n  * Nested\n* Item 2",
+    "<ul class=\"markdown-list-kind-star\"><li>Item 1<ul class=\"markdown-list-kind-star\"><li>Nested</li></ul></li><li>Item 2</li></ul>"
+)]
 #[case("`code`", "<p><code>code</code></p>")]
 #[case("```rust\nfn main() {}\n```", "<pre><code>fn main() {}</code></pre>")]
 #[case(
@@ -45,3 +60,336 @@ fn render_to_html(#[case] input: &str, #[case] expected: &str) {
     let result = crate::html_printer::render_html(&ast, config);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn heading_ids() {
+    let ast = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::default(),
+        "# Hello, *World*!\n\n## Intro\n\n## Intro",
+    )
+    .unwrap();
+
+    let result = crate::html_printer::render_html(&ast, crate::html_printer::config::Config::default());
+    assert_eq!(
+        "<h1>Hello, <em>World</em>!</h1><h2>Intro</h2><h2>Intro</h2>",
+        result
+    );
+
+    let config = crate::html_printer::config::Config::default()
+        .with_heading_ids(true)
+        .with_anchor_prefix("doc-".to_owned());
+    let result = crate::html_printer::render_html(&ast, config);
+    assert_eq!(
+        "<h1 id=\"doc-hello-world\">Hello, <em>World</em>!</h1><h2 id=\"doc-intro\">Intro</h2><h2 id=\"doc-intro-1\">Intro</h2>",
+        result
+    );
+}
+
+#[test]
+fn definition_list() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+
+    let state = crate::parser::MarkdownParserState::with_config(
+        MarkdownParserConfig::default().with_block_definition_list_behavior(ElementBehavior::Parse),
+    );
+    let ast =
+        crate::parser::parse_markdown(state, "*Apple*\n: A fruit\n: A company\n\nOrange\n: Citrus")
+            .unwrap();
+    let result = crate::html_printer::render_html(&ast, crate::html_printer::config::Config::default());
+    assert_eq!(
+        "<dl><dt><em>Apple</em></dt><dd><p>A fruit</p></dd><dd><p>A company</p></dd><dt>Orange</dt><dd><p>Citrus</p></dd></dl>",
+        result
+    );
+}
+
+#[test]
+fn front_matter_is_omitted() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+
+    let state = crate::parser::MarkdownParserState::with_config(
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Parse),
+    );
+    let ast = crate::parser::parse_markdown(state, "---\ntitle: Hello\n---\n\nText").unwrap();
+    let result = crate::html_printer::render_html(&ast, crate::html_printer::config::Config::default());
+    assert_eq!("<p>Text</p>", result);
+}
+
+#[test]
+fn math() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+
+    let state = crate::parser::MarkdownParserState::with_config(
+        MarkdownParserConfig::default()
+            .with_block_math_behavior(ElementBehavior::Parse)
+            .with_inline_math_behavior(ElementBehavior::Parse),
+    );
+    let ast = crate::parser::parse_markdown(state, "Area $a<b$\n\n$$\nx^2\n$$").unwrap();
+    let result = crate::html_printer::render_html(&ast, crate::html_printer::config::Config::default());
+    assert_eq!(
+        "<p>Area <span class=\"math inline\">\\(a&lt;b\\)</span></p><div class=\"math display\">\\[x^2\\]</div>",
+        result
+    );
+}
+
+#[test]
+fn raw_html_policies() {
+    use crate::ast::{Block, Document, Inline};
+    use crate::html_printer::config::{Config, HtmlAllowlist, RawHtmlPolicy};
+
+    let ast = Document {
+        blocks: vec![
+            Block::HtmlBlock("<div onclick=\"x()\">".to_owned()),
+            Block::Paragraph(vec![
+                Inline::Text("Hi ".to_owned()),
+                Inline::Html("<i>".to_owned()),
+                Inline::Text("there".to_owned()),
+                Inline::Html("</i>".to_owned()),
+            ]),
+            Block::HtmlBlock("</div>".to_owned()),
+        ],
+    };
+
+    let result = crate::html_printer::render_html(&ast, Config::default());
+    assert_eq!("<div onclick=\"x()\"><p>Hi <i>there</i></p></div>", result);
+
+    let config = Config::default().with_raw_html(RawHtmlPolicy::Escape);
+    let result = crate::html_printer::render_html(&ast, config);
+    assert_eq!(
+        "&lt;div onclick=&quot;x()&quot;&gt;<p>Hi &lt;i&gt;there&lt;/i&gt;</p>&lt;/div&gt;",
+        result
+    );
+
+    let config = Config::default().with_raw_html(RawHtmlPolicy::Drop);
+    let result = crate::html_printer::render_html(&ast, config);
+    assert_eq!("<p>Hi there</p>", result);
+
+    let allowlist = HtmlAllowlist::new().with_tag("div", &[]);
+    let config = Config::default().with_raw_html(RawHtmlPolicy::Allowlist(allowlist));
+    let result = crate::html_printer::render_html(&ast, config);
+    assert_eq!("<div><p>Hi there</p></div>", result);
+}
+
+#[rstest]
+#[case(SoftBreakStyle::Newline, "<p>one\n<em>two</em>\nthree</p>")]
+#[case(SoftBreakStyle::Space, "<p>one <em>two</em> three</p>")]
+#[case(SoftBreakStyle::LineBreak, "<p>one<br>\n<em>two</em><br>\nthree</p>")]
+fn soft_break_styles(#[case] soft_break: SoftBreakStyle, #[case] expected: &str) {
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::default(),
+        "one\n*two*\nthree",
+    )
+    .unwrap();
+    let config = Config::default().with_soft_break(soft_break);
+    assert_eq!(expected, crate::html_printer::render_html(&doc, config));
+}
+
+#[rstest]
+#[case("<B>x</B>", "<b>x</b>")]
+#[case("<span>x</span>", "x")]
+#[case(
+    "<a href='https://a.b' title=t target=_blank>x</a>",
+    "<a href=\"https://a.b\" title=\"t\">x</a>"
+)]
+#[case("<a href=\"javascript:alert(1)\">x</a>", "<a>x</a>")]
+#[case("<a href=\"java&#115;cript:x\">x</a>", "<a>x</a>")]
+#[case("<a HREF=\" javascript:x\">x</a>", "<a>x</a>")]
+#[case(
+    "<img src=\"/a.png\" alt='say \"hi\"'/>",
+    "<img src=\"/a.png\" alt=\"say &quot;hi&quot;\" />"
+)]
+#[case("<script>alert('<b>')</script>after", "after")]
+#[case("<!-- comment -->x<![CDATA[y]]>", "x")]
+#[case("1 < 2 > 0 <b", "1 &lt; 2 &gt; 0 &lt;b")]
+fn raw_html_allowlist(#[case] html: &str, #[case] expected: &str) {
+    use crate::ast::{Block, Document};
+    use crate::html_printer::config::{Config, HtmlAllowlist, RawHtmlPolicy};
+
+    let allowlist = HtmlAllowlist::new()
+        .with_tag("b", &[])
+        .with_tag("a", &["href", "title"])
+        .with_tag("img", &["src", "alt"]);
+    let config = Config::default()
+        .with_raw_html(RawHtmlPolicy::Allowlist(allowlist))
+        .with_allowed_url_schemes(vec!["https".to_owned()]);
+    let ast = Document {
+        blocks: vec![Block::HtmlBlock(html.to_owned())],
+    };
+    assert_eq!(expected, crate::html_printer::render_html(&ast, config));
+}
+
+#[rstest]
+#[case("<a href=\"javascript:alert(1)\">x</a>", "<a>x</a>")]
+#[case("<img src=\"data:text/html,y\" alt=\"y\">", "<img alt=\"y\">")]
+#[case("<a href=\"https://a.b\">x</a>", "<a href=\"https://a.b\">x</a>")]
+#[case("<a href=\"mailto:a@b.c\">x</a>", "<a href=\"mailto:a@b.c\">x</a>")]
+#[case("<a href=\"/c?d=e:f\">x</a>", "<a href=\"/c?d=e:f\">x</a>")]
+fn raw_html_allowlist_default_url_schemes(#[case] html: &str, #[case] expected: &str) {
+    use crate::ast::{Block, Document};
+    use crate::html_printer::config::{Config, HtmlAllowlist, RawHtmlPolicy};
+
+    let allowlist = HtmlAllowlist::new()
+        .with_tag("a", &["href"])
+        .with_tag("img", &["src", "alt"]);
+    let config = Config::default().with_raw_html(RawHtmlPolicy::Allowlist(allowlist));
+    let ast = Document {
+        blocks: vec![Block::HtmlBlock(html.to_owned())],
+    };
+    assert_eq!(expected, crate::html_printer::render_html(&ast, config));
+}
+
+#[rstest]
+#[case(RawHtmlPolicy::Escape, "[x](javascript:alert(1))", "<p>x</p>")]
+#[case(RawHtmlPolicy::Escape, "![i](javascript:x)", "<p>i</p>")]
+#[case(RawHtmlPolicy::Escape, "<javascript:alert(1)>", "<p>javascript:alert(1)</p>")]
+#[case(RawHtmlPolicy::Drop, "[x](javascript:alert(1))", "<p>x</p>")]
+#[case(RawHtmlPolicy::Drop, "![i](javascript:x)", "<p>i</p>")]
+#[case(RawHtmlPolicy::Drop, "<javascript:alert(1)>", "<p>javascript:alert(1)</p>")]
+#[case(RawHtmlPolicy::Drop, "[x](/a) <https://a.b>", "<p><a href=\"/a\">x</a> <a href=\"https://a.b\">https://a.b</a></p>")]
+#[case(RawHtmlPolicy::Allow, "[x](javascript:alert(1))", "<p><a href=\"javascript:alert(1)\">x</a></p>")]
+fn safe_url_schemes_by_default(
+    #[case] policy: RawHtmlPolicy,
+    #[case] input: &str,
+    #[case] expected: &str,
+) {
+    let config = crate::html_printer::config::Config::default().with_raw_html(policy);
+    let ast = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    assert_eq!(expected, crate::html_printer::render_html(&ast, config));
+}
+
+#[rstest]
+#[case("[x](https://a.b)", "<p><a href=\"https://a.b\">x</a></p>")]
+#[case("[x](HTTPS://a.b)", "<p><a href=\"HTTPS://a.b\">x</a></p>")]
+#[case("[x](/path?q=a:b)", "<p><a href=\"/path?q=a:b\">x</a></p>")]
+#[case("[x](mailto:a@b.c)", "<p><a href=\"mailto:a@b.c\">x</a></p>")]
+#[case("[*x*](javascript:alert(1))", "<p><em>x</em></p>")]
+#[case("[x][r]\n\n[r]: data:text/html,y", "<p>x</p>")]
+#[case("![a & b](vbscript:x)", "<p>a &amp; b</p>")]
+#[case("<http://a.b>", "<p>http://a.b</p>")]
+fn allowed_url_schemes(#[case] input: &str, #[case] expected: &str) {
+    let config = crate::html_printer::config::Config::default()
+        .with_allowed_url_schemes(vec!["https".to_owned(), "mailto".to_owned()]);
+    let ast = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    assert_eq!(expected, crate::html_printer::render_html(&ast, config));
+}
+
+#[test]
+fn render_to_writer_shares_state_between_blocks() {
+    let config = || crate::html_printer::config::Config::default().with_heading_ids(true);
+    let ast = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::default(),
+        "# Intro\n\nSee [docs][d] and[^n].\n\n# Intro\n\nAgain[^m] and[^n].\n\n[d]: /docs\n\n[^n]: Note.\n\n[^m]: Other.",
+    )
+    .unwrap();
+    let mut out = Vec::new();
+    crate::html_printer::render_html_to(&ast, config(), &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            "<h1 id=\"intro\">Intro</h1>",
+            "<p>See <a href=\"/docs\">docs</a> and",
+            "<a class=\"markdown-footnote-reference\" href=\"#1\">[1]</a>.</p>",
+            "<h1 id=\"intro-1\">Intro</h1>",
+            "<p>Again<a class=\"markdown-footnote-reference\" href=\"#2\">[2]</a> and",
+            "<a class=\"markdown-footnote-reference\" href=\"#1\">[1]</a>.</p>",
+            "<div class=\"markdown-footnote-definition\">",
+            "<span class=\"markdown-footnote-definition-index\">1. </span>",
+            "<span class=\"markdown-footnote-definition-content\"><p>Note.</p></span></div>",
+            "<div class=\"markdown-footnote-definition\">",
+            "<span class=\"markdown-footnote-definition-index\">2. </span>",
+            "<span class=\"markdown-footnote-definition-content\"><p>Other.</p></span></div>",
+        )
+    );
+}
+
+#[test]
+fn render_to_writer_returns_write_errors() {
+    struct Full;
+    impl std::io::Write for Full {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(std::io::ErrorKind::WriteZero.into())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+    let ast = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), "text")
+        .unwrap();
+    let result = crate::html_printer::render_html_to(&ast, Default::default(), Full);
+    assert_eq!(
+        std::io::ErrorKind::WriteZero,
+        result.unwrap_err().kind()
+    );
+}
+
+#[rstest]
+#[case("```rust\nlet x = 1;\n```", "<pre class=\"rust\">let x = 1;</pre>")]
+#[case("```rust,ignore\n<a>\n```", "<pre class=\"rust,ignore\">&lt;a&gt;</pre>")]
+#[case("```text\n<a>\n```", "<pre><code>&lt;a&gt;</code></pre>")]
+#[case("    <a>", "<pre><code>&lt;a&gt;</code></pre>")]
+fn code_highlighter(#[case] input: &str, #[case] expected: &str) {
+    let highlighter = |info: Option<&str>, code: &str| {
+        let info = info.filter(|info| info.starts_with("rust"))?;
+        let code = crate::html_printer::util::escape(code);
+        Some(format!("<pre class=\"{info}\">{code}</pre>"))
+    };
+    let config = crate::html_printer::config::Config::default()
+        .with_code_highlighter(std::sync::Arc::new(highlighter));
+    let ast = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    assert_eq!(expected, crate::html_printer::render_html(&ast, config));
+}
+
+#[rstest]
+#[case("![a](/a.png)", "<p><img loading=\"lazy\" src=\"/a.png\" alt=\"a\"></img></p>")]
+#[case(
+    "> - [*x*](/x) and ![b](/b.png)",
+    "<blockquote><ul class=\"markdown-list-kind-dash\"><li><a href=\"/x\"><i>x</i></a> and <img loading=\"lazy\" src=\"/b.png\" alt=\"b\"></img></li></ul></blockquote>"
+)]
+#[case("# *Title*", "<h1><i>Title</i></h1>")]
+fn inline_renderer(#[case] input: &str, #[case] expected: &str) {
+    use crate::ast::Inline;
+
+    let config = crate::html_printer::config::Config::default().with_inline_renderer(
+        std::sync::Arc::new(|inline, renderer| match inline {
+            Inline::Image(_) => Some(
+                renderer
+                    .default_inline(inline)
+                    .replacen("<img ", "<img loading=\"lazy\" ", 1),
+            ),
+            Inline::Emphasis(emphasis) => {
+                Some(format!("<i>{}</i>", renderer.inlines(&emphasis.content)))
+            }
+            _ => None,
+        }),
+    );
+    let ast = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    assert_eq!(expected, crate::html_printer::render_html(&ast, config));
+}
+
+#[test]
+fn block_renderer_falls_back_to_default_markup() {
+    use crate::ast::Block;
+
+    let config = crate::html_printer::config::Config::default().with_block_renderer(
+        std::sync::Arc::new(|block, renderer| match block {
+            Block::BlockQuote(blocks) => Some(format!(
+                "<div class=\"quote\">{}</div>",
+                renderer.blocks(blocks)
+            )),
+            Block::ThematicBreak => Some(String::new()),
+            _ => None,
+        }),
+    );
+    let ast = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::default(),
+        "Text\n\n---\n\n> Quote\n>\n> > Nested",
+    )
+    .unwrap();
+    assert_eq!(
+        "<p>Text</p><div class=\"quote\"><p>Quote</p><div class=\"quote\"><p>Nested</p></div></div>",
+        crate::html_printer::render_html(&ast, config)
+    );
+}
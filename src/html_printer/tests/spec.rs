@@ -0,0 +1,254 @@
+//! CommonMark and GFM spec conformance
+//!
+//! Runs the examples of the official spec files vendored in `spec/*.json`
+//! through `parse_markdown`, with the matching dialect preset of
+//! `MarkdownParserConfig`, and `render_html` and compares the HTML after
+//! [`normalize`]. Examples listed in `spec/known_failures.txt` by their
+//! official example number are expected to fail. The test fails when any
+//! other example fails, and also when a known failure starts to pass, so that
+//! the list always reflects the current state of the parser.
+//!
+//! Run `make spec` to see the per-section report.
+
+use crate::html_printer::{config::Config, render_html};
+use crate::parser::config::MarkdownParserConfig;
+use crate::parser::{parse_markdown, MarkdownParserState};
+use std::collections::BTreeSet;
+
+type Suite = (&'static str, &'static str, fn() -> MarkdownParserConfig);
+
+const SUITES: &[Suite] = &[
+    (
+        "commonmark",
+        include_str!("spec/commonmark.json"),
+        MarkdownParserConfig::commonmark,
+    ),
+    (
+        "gfm",
+        include_str!("spec/gfm.json"),
+        MarkdownParserConfig::gfm,
+    ),
+];
+
+const KNOWN_FAILURES: &str = include_str!("spec/known_failures.txt");
+
+struct Example {
+    suite: &'static str,
+    number: u64,
+    section: String,
+    markdown: String,
+    html: String,
+}
+
+fn load(suite: &'static str, json: &str) -> Vec<Example> {
+    let examples: Vec<serde_json::Value> = serde_json::from_str(json).unwrap();
+    examples
+        .into_iter()
+        .map(|example| Example {
+            suite,
+            number: example["example"].as_u64().unwrap(),
+            section: example["section"].as_str().unwrap().to_owned(),
+            markdown: example["markdown"].as_str().unwrap().to_owned(),
+            html: example["html"].as_str().unwrap().to_owned(),
+        })
+        .collect()
+}
+
+/// `(suite, example)` pairs from `known_failures.txt`
+fn known_failures() -> BTreeSet<(String, u64)> {
+    KNOWN_FAILURES
+        .lines()
+        .map(|line| line.split('#').next().unwrap().trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (suite, number) = line.split_once(' ').unwrap();
+            (suite.to_owned(), number.trim().parse().unwrap())
+        })
+        .collect()
+}
+
+/// Rendered HTML, or `None` if the parser failed or panicked
+fn render(markdown: &str, config: fn() -> MarkdownParserConfig) -> Option<String> {
+    std::panic::catch_unwind(|| {
+        let doc = parse_markdown(MarkdownParserState::with_config(config()), markdown).ok()?;
+        Some(render_html(&doc, Config::default()))
+    })
+    .ok()
+    .flatten()
+}
+
+const BLOCK_TAGS: &[&str] = &[
+    "blockquote",
+    "div",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "thead",
+    "tr",
+    "ul",
+];
+
+/// Bring the spec HTML and the output of `render_html` to a common form.
+///
+/// Like the normalization of the spec's own test runner, line breaks next to
+/// block-level tags are dropped. In addition, differences of the HTML dialect
+/// of `render_html` that say nothing about parsing are evened out:
+///
+/// - void elements: `<br />` is `<br>`, and a line break after `<br>` is
+///   dropped;
+/// - `<b>` and `<s>` are `<strong>` and `<del>`;
+/// - `markdown-*` and `language-*` classes and `start="1"` are removed;
+/// - `&apos;` is `'`;
+/// - the final line break of a code block is removed.
+fn normalize(html: &str) -> String {
+    let html = html
+        .replace("&apos;", "'")
+        .replace(" />", ">")
+        .replace("/>", ">")
+        .replace("<br>\n", "<br>")
+        .replace("<b>", "<strong>")
+        .replace("</b>", "</strong>")
+        .replace("<s>", "<del>")
+        .replace("</s>", "</del>")
+        .replace("<ol start=\"1\">", "<ol>")
+        .replace("\n</code></pre>", "</code></pre>");
+
+    let mut out = String::new();
+    let mut rest = html.as_str();
+    let mut after_block_tag = false;
+    while !rest.is_empty() {
+        let (text, tag) = match rest.find('<') {
+            Some(start) => {
+                let end = rest[start..]
+                    .find('>')
+                    .map_or(rest.len(), |end| start + end + 1);
+                (&rest[..start], &rest[start..end])
+            }
+            None => (rest, ""),
+        };
+        rest = &rest[text.len() + tag.len()..];
+
+        let before_block_tag = is_block_tag(tag);
+        let mut text = text;
+        if after_block_tag {
+            text = text.trim_start_matches('\n');
+        }
+        if before_block_tag {
+            text = text.trim_end_matches('\n');
+        }
+        out.push_str(text);
+        out.push_str(&strip_classes(tag));
+        after_block_tag = before_block_tag;
+    }
+    out
+}
+
+fn is_block_tag(tag: &str) -> bool {
+    let name = tag
+        .trim_start_matches('<')
+        .trim_start_matches('/')
+        .split(|c: char| c.is_whitespace() || c == '>')
+        .next()
+        .unwrap_or_default();
+    tag.starts_with('<') && BLOCK_TAGS.contains(&name.to_ascii_lowercase().as_str())
+}
+
+fn strip_classes(tag: &str) -> String {
+    let mut tag = tag.to_owned();
+    for prefix in [" class=\"markdown-", " class=\"language-"] {
+        while let Some(start) = tag.find(prefix) {
+            let Some(end) = tag[start + prefix.len()..].find('"') else {
+                break;
+            };
+            tag.replace_range(start..start + prefix.len() + end + 1, "");
+        }
+    }
+    tag
+}
+
+#[test]
+fn spec_conformance() {
+    let known_failures = known_failures();
+    // Passed and total examples by section, in the order of the spec
+    let mut sections: Vec<(&str, String, usize, usize)> = Vec::new();
+    let mut failures = Vec::new();
+    let mut unexpected = Vec::new();
+    let mut fixed = Vec::new();
+
+    for (suite, json, config) in SUITES {
+        for example in load(suite, json) {
+            let passed = render(&example.markdown, *config)
+                .is_some_and(|html| normalize(&html) == normalize(&example.html));
+            let key = (example.suite.to_owned(), example.number);
+
+            let section = match sections.iter().position(|(suite, section, _, _)| {
+                *suite == example.suite && *section == example.section
+            }) {
+                Some(i) => &mut sections[i],
+                None => {
+                    sections.push((example.suite, example.section.clone(), 0, 0));
+                    sections.last_mut().unwrap()
+                }
+            };
+            section.3 += 1;
+            if passed {
+                section.2 += 1;
+                if known_failures.contains(&key) {
+                    fixed.push(key);
+                }
+            } else {
+                if !known_failures.contains(&key) {
+                    unexpected.push(key);
+                }
+                failures.push(example);
+            }
+        }
+    }
+
+    let (mut passed, mut total) = (0, 0);
+    println!();
+    for (suite, section, section_passed, section_total) in &sections {
+        println!("{suite:<10} {section:<45} {section_passed:>4}/{section_total:<4}");
+        passed += section_passed;
+        total += section_total;
+    }
+    println!("{:<56} {passed:>4}/{total:<4}", "total");
+
+    if !unexpected.is_empty() || !fixed.is_empty() {
+        println!("\nUpdated spec/known_failures.txt:\n");
+        for example in &failures {
+            println!(
+                "{} {:<5} # {}",
+                example.suite, example.number, example.section
+            );
+        }
+    }
+    assert!(
+        unexpected.is_empty(),
+        "spec examples that are not in known_failures.txt fail: {unexpected:?}"
+    );
+    assert!(
+        fixed.is_empty(),
+        "known failures pass now, remove them from known_failures.txt: {fixed:?}"
+    );
+}
+
+#[test]
+fn normalize_evens_out_html_dialect() {
+    assert_eq!(
+        normalize("<ul>\n<li>a<br />\nb</li>\n</ul>\n<pre><code class=\"language-rust\">x\n</code></pre>\n"),
+        normalize("<ul class=\"markdown-list-kind-dash\"><li>a<br>\nb</li></ul><pre><code>x</code></pre>"),
+    );
+}
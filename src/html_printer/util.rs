@@ -1,6 +1,9 @@
 use pretty::{Arena, DocAllocator, DocBuilder};
 
-pub(crate) fn escape(value: &str) -> String {
+/// Escape text for HTML content and attribute values.
+///
+/// Replaces `&`, `<`, `>`, `"` and `'` with character references.
+pub fn escape(value: &str) -> String {
     let mut escaped = String::new();
     for c in value.chars() {
         match c {
@@ -20,6 +23,22 @@ pub(crate) fn tag<'a>(
     tag: &'static str,
     attributes: Vec<(String, String)>,
     inner: DocBuilder<'a, Arena<'a>, ()>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let close_tag = state
+        .arena
+        .text("</")
+        .append(state.arena.text(tag))
+        .append(state.arena.text(">"));
+    void_tag(state, tag, attributes)
+        .append(inner)
+        .append(close_tag)
+}
+
+/// Render a void element such as `<br>`, which has no content and no end tag.
+pub(crate) fn void_tag<'a>(
+    state: &'a crate::html_printer::State<'a>,
+    tag: &'static str,
+    attributes: Vec<(String, String)>,
 ) -> DocBuilder<'a, Arena<'a>, ()> {
     let mut attrs = state.arena.nil();
     for (key, value) in attributes {
@@ -32,16 +51,10 @@ pub(crate) fn tag<'a>(
             .append(state.arena.text("\""));
         attrs = attrs.append(attr);
     }
-    let open_tag = state
+    state
         .arena
         .text("<")
         .append(state.arena.text(tag))
         .append(attrs)
-        .append(state.arena.text(">"));
-    let close_tag = state
-        .arena
-        .text("</")
-        .append(state.arena.text(tag))
-        .append(state.arena.text(">"));
-    open_tag.append(inner).append(close_tag)
+        .append(state.arena.text(">"))
 }
@@ -4,9 +4,14 @@ use crate::latex_printer::util::{command, environment, escape_latex};
 use crate::latex_printer::ToDoc;
 use pretty::{Arena, DocAllocator, DocBuilder};
 
+/// Whether a block is rendered. Front matter carries metadata, not content.
+pub(crate) fn is_content(block: &Block) -> bool {
+    !matches!(block, Block::FrontMatter { .. })
+}
+
 impl<'a> ToDoc<'a> for Vec<Block> {
     fn to_doc(&self, state: &'a crate::latex_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
-        let refs: Vec<_> = self.iter().collect();
+        let refs: Vec<_> = self.iter().filter(|block| is_content(block)).collect();
         refs.to_doc(state)
     }
 }
@@ -47,7 +52,13 @@ impl<'a> ToDoc<'a> for Block {
                     _ => "subparagraph", // 6+ levels default to subparagraph
                 };
 
-                command(&state.arena, cmd_name, &[], heading.content.to_doc(state))
+                let section = command(&state.arena, cmd_name, &[], heading.content.to_doc(state));
+                if state.config.heading_labels {
+                    let label = state.heading_label(heading);
+                    section.append(command(&state.arena, "label", &[], state.arena.text(label)))
+                } else {
+                    section
+                }
             }
 
             Block::ThematicBreak => command(&state.arena, "hrule", &[], state.arena.nil()),
@@ -58,8 +69,18 @@ impl<'a> ToDoc<'a> for Block {
 
             Block::List(list) => list.to_doc(state),
 
+            Block::DefinitionList(list) => list.to_doc(state),
+
             Block::CodeBlock(code_block) => render_code_block(state, code_block),
 
+            Block::Math(math) => state
+                .arena
+                .text(r"\[")
+                .append(state.arena.hardline())
+                .append(state.arena.text(math.clone()))
+                .append(state.arena.hardline())
+                .append(state.arena.text(r"\]")),
+
             Block::HtmlBlock(html) => {
                 // Render HTML as escaped text in LaTeX
                 state.arena.text(escape_latex(html))
@@ -110,6 +131,7 @@ impl<'a> ToDoc<'a> for Block {
                 )
             }
 
+            Block::FrontMatter { .. } => state.arena.nil(),
             Block::Empty => state.arena.nil(),
         }
     }
@@ -149,10 +171,52 @@ impl<'a> ToDoc<'a> for ListItem {
     }
 }
 
+impl<'a> ToDoc<'a> for DefinitionList {
+    fn to_doc(&self, state: &'a crate::latex_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        let mut content = state.arena.nil();
+        for item in &self.items {
+            content = content.append(item.to_doc(state));
+        }
+
+        environment(&state.arena, "description", None, content)
+    }
+}
+
+impl<'a> ToDoc<'a> for DefinitionListItem {
+    fn to_doc(&self, state: &'a crate::latex_printer::State<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        // Braces keep `]` in the term from closing the optional argument
+        let mut item_content = state
+            .arena
+            .text(r"\item[{")
+            .append(self.term.to_doc(state))
+            .append(state.arena.text("}] "));
+
+        for (i, definition) in self.definitions.iter().enumerate() {
+            if i > 0 {
+                item_content = item_content
+                    .append(state.arena.hardline())
+                    .append(state.arena.hardline());
+            }
+            item_content = item_content.append(definition.to_doc(state));
+        }
+        item_content.append(state.arena.hardline())
+    }
+}
+
 fn render_code_block<'a>(
     state: &'a crate::latex_printer::State<'a>,
     code_block: &CodeBlock,
 ) -> DocBuilder<'a, Arena<'a>, ()> {
+    if let Some(highlighter) = &state.config.code_highlighter {
+        let info = match &code_block.kind {
+            CodeBlockKind::Fenced { info } => info.as_deref(),
+            CodeBlockKind::Indented => None,
+        };
+        if let Some(latex) = highlighter.highlight(info, &code_block.literal) {
+            return state.arena.text(latex);
+        }
+    }
+
     match state.config.code_block_style {
         CodeBlockStyle::Verbatim => environment(
             &state.arena,
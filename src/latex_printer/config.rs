@@ -4,6 +4,9 @@
 //! style and format. The main configuration struct [`Config`] allows you to
 //! control table styles, code block formatting, and output width.
 
+use crate::highlight::CodeHighlighter;
+use std::sync::Arc;
+
 /// Table rendering style
 ///
 /// Controls how Markdown tables are converted to LaTeX table environments.
@@ -149,6 +152,8 @@ pub struct Config {
     pub(crate) width: usize,
     pub(crate) table_style: TableStyle,
     pub(crate) code_block_style: CodeBlockStyle,
+    pub(crate) heading_labels: bool,
+    pub(crate) code_highlighter: Option<Arc<dyn CodeHighlighter>>,
 }
 
 impl Default for Config {
@@ -158,11 +163,15 @@ impl Default for Config {
     /// - Width: 80 characters
     /// - Table style: [`TableStyle::Tabular`]
     /// - Code block style: [`CodeBlockStyle::Verbatim`]
+    /// - Heading labels: disabled
+    /// - Code highlighter: none
     fn default() -> Self {
         Self {
             width: 80,
             table_style: TableStyle::Tabular,
             code_block_style: CodeBlockStyle::Verbatim,
+            heading_labels: false,
+            code_highlighter: None,
         }
     }
 }
@@ -235,4 +244,73 @@ impl Config {
             ..self
         }
     }
+
+    /// Add a `\label{}` after every sectioning command
+    ///
+    /// Labels are GitHub-compatible slugs of the heading text (see
+    /// [`Slugger`](crate::ast::Slugger)), so `[link](#some-heading)` style
+    /// anchors and `\ref{some-heading}` refer to the same section.
+    ///
+    /// # Arguments
+    ///
+    /// * `heading_labels` - Whether to emit labels
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::ast::*;
+    /// use markdown_ppp::latex_printer::{config::Config, render_latex};
+    ///
+    /// let doc = Document {
+    ///     blocks: vec![Block::Heading(Heading {
+    ///         kind: HeadingKind::Atx(1),
+    ///         content: vec![Inline::Text("Getting Started".to_string())],
+    ///     })],
+    /// };
+    /// let latex = render_latex(&doc, Config::default().with_heading_labels(true));
+    /// assert_eq!(latex, "\\section{Getting Started}\\label{getting-started}");
+    /// ```
+    pub fn with_heading_labels(self, heading_labels: bool) -> Self {
+        Self {
+            heading_labels,
+            ..self
+        }
+    }
+
+    /// Render code blocks with a syntax highlighter
+    ///
+    /// The LaTeX returned by the highlighter replaces the whole code block
+    /// environment. Code blocks it declines are rendered in the
+    /// [`CodeBlockStyle`] environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `highlighter` - The highlighter, see [`CodeHighlighter`]
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use markdown_ppp::ast::*;
+    /// use markdown_ppp::latex_printer::{config::Config, render_latex};
+    /// use std::sync::Arc;
+    ///
+    /// let highlighter = |info: Option<&str>, code: &str| {
+    ///     let lang = info?;
+    ///     Some(format!("\\begin{{code}}{{{lang}}}\n{code}\n\\end{{code}}"))
+    /// };
+    /// let doc = Document {
+    ///     blocks: vec![Block::CodeBlock(CodeBlock {
+    ///         kind: CodeBlockKind::Fenced { info: Some("rust".to_string()) },
+    ///         literal: "let x = 1;".to_string(),
+    ///     })],
+    /// };
+    /// let latex = render_latex(&doc, Config::default().with_code_highlighter(Arc::new(highlighter)));
+    /// assert_eq!(latex, "\\begin{code}{rust}\nlet x = 1;\n\\end{code}");
+    /// ```
+    pub fn with_code_highlighter(self, highlighter: Arc<dyn CodeHighlighter>) -> Self {
+        Self {
+            code_highlighter: Some(highlighter),
+            ..self
+        }
+    }
 }
@@ -35,6 +35,9 @@ impl<'a> ToDoc<'a> for Inline {
 
             Inline::LineBreak => state.arena.text(r"\\").append(state.arena.hardline()),
 
+            // A line ending is a space in LaTeX
+            Inline::SoftBreak => state.arena.softline(),
+
             Inline::Code(code) => command(
                 &state.arena,
                 "texttt",
@@ -42,6 +45,8 @@ impl<'a> ToDoc<'a> for Inline {
                 state.arena.text(escape_latex(code)),
             ),
 
+            Inline::Math(math) => state.arena.text(format!("${math}$")),
+
             Inline::Html(html) => {
                 // For LaTeX, we'll just escape HTML as text
                 state.arena.text(escape_latex(html))
@@ -112,11 +117,13 @@ impl<'a> ToDoc<'a> for Inline {
                 cmd
             }
 
-            Inline::Emphasis(content) => {
+            Inline::Emphasis(Emphasis { content, .. }) => {
                 command(&state.arena, "textit", &[], content.to_doc(state))
             }
 
-            Inline::Strong(content) => command(&state.arena, "textbf", &[], content.to_doc(state)),
+            Inline::Strong(Emphasis { content, .. }) => {
+                command(&state.arena, "textbf", &[], content.to_doc(state))
+            }
 
             Inline::Strikethrough(content) => {
                 command(&state.arena, "sout", &[], content.to_doc(state))
@@ -27,9 +27,9 @@
 //!         }),
 //!         Block::Paragraph(vec![
 //!             Inline::Text("This is ".to_string()),
-//!             Inline::Strong(vec![Inline::Text("bold".to_string())]),
+//!             Inline::Strong(vec![Inline::Text("bold".to_string())].into()),
 //!             Inline::Text(" and ".to_string()),
-//!             Inline::Emphasis(vec![Inline::Text("italic".to_string())]),
+//!             Inline::Emphasis(vec![Inline::Text("italic".to_string())].into()),
 //!             Inline::Text(" text with special chars: $100 & 50%.".to_string()),
 //!         ]),
 //!     ],
@@ -84,7 +84,7 @@ mod tests;
 
 use crate::ast::*;
 use pretty::{Arena, DocBuilder};
-use std::{collections::HashMap, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 /// Internal state for LaTeX rendering
 ///
@@ -92,11 +92,13 @@ use std::{collections::HashMap, rc::Rc};
 /// configuration, and pre-processed indices for footnotes and link definitions.
 pub(crate) struct State<'a> {
     arena: Arena<'a>,
-    config: crate::latex_printer::config::Config,
+    config: Rc<crate::latex_printer::config::Config>,
     /// Mapping of footnote labels to their indices in the footnote list.
-    footnote_index: HashMap<String, usize>,
+    footnote_index: Rc<HashMap<String, usize>>,
     /// Mapping of link labels to their definitions.
-    link_definitions: HashMap<Vec<Inline>, LinkDefinition>,
+    link_definitions: Rc<HashMap<Vec<Inline>, LinkDefinition>>,
+    /// Generator of heading labels, headings are rendered in document order.
+    slugger: Rc<RefCell<Slugger>>,
 }
 
 impl State<'_> {
@@ -110,9 +112,24 @@ impl State<'_> {
         let arena = Arena::new();
         Self {
             arena,
-            config,
-            footnote_index,
-            link_definitions,
+            config: Rc::new(config),
+            footnote_index: Rc::new(footnote_index),
+            link_definitions: Rc::new(link_definitions),
+            slugger: Rc::new(RefCell::new(Slugger::new())),
+        }
+    }
+
+    /// Create a state for rendering the next part of the same document
+    ///
+    /// The new state shares the configuration and indices, but has a fresh
+    /// arena, so that the nodes of rendered parts can be freed.
+    fn with_new_arena<'b>(&self) -> State<'b> {
+        State {
+            arena: Arena::new(),
+            config: self.config.clone(),
+            footnote_index: self.footnote_index.clone(),
+            link_definitions: self.link_definitions.clone(),
+            slugger: self.slugger.clone(),
         }
     }
 
@@ -129,6 +146,11 @@ impl State<'_> {
     pub fn get_link_definition(&self, label: &[Inline]) -> Option<&LinkDefinition> {
         self.link_definitions.get(label)
     }
+
+    /// Get a unique label for a heading
+    pub fn heading_label(&self, heading: &Heading) -> String {
+        self.slugger.borrow_mut().heading_slug(heading)
+    }
 }
 
 /// Render the given Markdown AST to LaTeX
@@ -169,10 +191,11 @@ impl State<'_> {
 ///             items: vec![ListItem {
 ///                 task: Some(TaskState::Complete),
 ///                 blocks: vec![Block::Paragraph(vec![
-///                     Inline::Strong(vec![Inline::Text("Bold".to_string())]),
+///                     Inline::Strong(vec![Inline::Text("Bold".to_string())].into()),
 ///                     Inline::Text(" item with special chars: $100 & 50%".to_string()),
 ///                 ])],
 ///             }],
+///             tight: true,
 ///         }),
 ///     ],
 /// };
@@ -198,14 +221,60 @@ impl State<'_> {
 /// - `listings` - if using listings code style
 /// - `minted` - if using minted code style
 pub fn render_latex(ast: &Document, config: crate::latex_printer::config::Config) -> String {
-    let state = Rc::new(State::new(config, ast));
-    let doc = ast.to_doc(&state);
-
     let mut buf = Vec::new();
-    doc.render(state.config.width, &mut buf).expect("Vec<u8> write is infallible");
+    render_latex_to(ast, config, &mut buf).expect("Vec<u8> write is infallible");
     String::from_utf8(buf).expect("pretty crate always produces valid UTF-8")
 }
 
+/// Render the given Markdown AST to LaTeX, writing to `writer`
+///
+/// Produces the same output as [`render_latex`], but renders and writes one
+/// top-level block at a time, so memory use is bounded by the largest block
+/// instead of the whole document. Wrap unbuffered writers such as files in a
+/// [`std::io::BufWriter`].
+///
+/// # Errors
+///
+/// Returns the first error of `writer`.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::latex_printer::{render_latex_to, config::Config};
+///
+/// let doc = Document {
+///     blocks: vec![
+///         Block::Paragraph(vec![Inline::Text("First".to_string())]),
+///         Block::Paragraph(vec![Inline::Text("Second".to_string())]),
+///     ],
+/// };
+///
+/// let mut out = Vec::new();
+/// render_latex_to(&doc, Config::default(), &mut out).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "First\n\nSecond");
+/// ```
+pub fn render_latex_to<W: std::io::Write>(
+    ast: &Document,
+    config: crate::latex_printer::config::Config,
+    mut writer: W,
+) -> std::io::Result<()> {
+    let state = State::new(config, ast);
+    let blocks = ast
+        .blocks
+        .iter()
+        .filter(|block| crate::latex_printer::block::is_content(block));
+    for (i, block) in blocks.enumerate() {
+        if i > 0 {
+            writer.write_all(b"\n\n")?;
+        }
+        let block_state = state.with_new_arena();
+        let doc = block.to_doc(&block_state);
+        doc.render(block_state.config.width, &mut writer)?;
+    }
+    Ok(())
+}
+
 /// Internal trait for converting AST nodes to pretty-printer documents
 ///
 /// This trait is implemented by all AST node types and provides the core
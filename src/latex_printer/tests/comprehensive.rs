@@ -424,10 +424,11 @@ fn test_nested_elements() {
                         task: None,
                         blocks: vec![Block::Paragraph(vec![
                             Inline::Text("Item with ".to_string()),
-                            Inline::Strong(vec![Inline::Text("bold".to_string())]),
+                            Inline::Strong(vec![Inline::Text("bold".to_string())].into()),
                             Inline::Text(" text".to_string()),
                         ])],
                     }],
+                    tight: true,
                 }),
             ]),
             Block::List(List {
@@ -444,6 +445,7 @@ fn test_nested_elements() {
                         }),
                     ],
                 }],
+                tight: true,
             }),
         ],
     };
@@ -463,11 +465,14 @@ fn test_complex_inline_combinations() {
     let doc = Document {
         blocks: vec![Block::Paragraph(vec![
             Inline::Text("This is ".to_string()),
-            Inline::Strong(vec![
-                Inline::Text("bold with ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("italic".to_string())]),
-                Inline::Text(" inside".to_string()),
-            ]),
+            Inline::Strong(
+                vec![
+                    Inline::Text("bold with ".to_string()),
+                    Inline::Emphasis(vec![Inline::Text("italic".to_string())].into()),
+                    Inline::Text(" inside".to_string()),
+                ]
+                .into(),
+            ),
             Inline::Text(" and ".to_string()),
             Inline::Strikethrough(vec![
                 Inline::Text("struck ".to_string()),
@@ -278,9 +278,9 @@ fn test_mixed_content_with_different_configs() {
             }),
             Block::Paragraph(vec![
                 Inline::Text("This document contains a ".to_string()),
-                Inline::Strong(vec![Inline::Text("table".to_string())]),
+                Inline::Strong(vec![Inline::Text("table".to_string())].into()),
                 Inline::Text(" and a ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("code block".to_string())]),
+                Inline::Emphasis(vec![Inline::Text("code block".to_string())].into()),
                 Inline::Text(".".to_string()),
             ]),
             Block::Table(Table {
@@ -318,6 +318,7 @@ fn test_mixed_content_with_different_configs() {
                         )])],
                     },
                 ],
+                tight: true,
             }),
         ],
     };
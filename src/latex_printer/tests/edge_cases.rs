@@ -37,7 +37,7 @@ fn test_empty_emphasis() {
     let doc = Document {
         blocks: vec![Block::Paragraph(vec![
             Inline::Text("Text with ".to_string()),
-            Inline::Emphasis(vec![]),
+            Inline::Emphasis(vec![].into()),
             Inline::Text(" empty emphasis.".to_string()),
         ])],
     };
@@ -52,6 +52,7 @@ fn test_empty_list() {
         blocks: vec![Block::List(List {
             kind: ListKind::Bullet(ListBulletKind::Star),
             items: vec![],
+            tight: true,
         })],
     };
 
@@ -69,6 +70,7 @@ fn test_empty_list_item() {
                 task: None,
                 blocks: vec![],
             }],
+            tight: true,
         })],
     };
 
@@ -234,6 +236,7 @@ fn test_deeply_nested_lists() {
                     task: None,
                     blocks: create_nested_list(depth - 1),
                 }],
+                tight: true,
             })]
         }
     }
@@ -254,12 +257,12 @@ fn test_table_with_complex_cells() {
         blocks: vec![Block::Table(Table {
             rows: vec![
                 vec![
-                    vec![Inline::Strong(vec![Inline::Text(
-                        "Bold Header".to_string(),
-                    )])],
-                    vec![Inline::Emphasis(vec![Inline::Text(
-                        "Italic Header".to_string(),
-                    )])],
+                    vec![Inline::Strong(
+                        vec![Inline::Text("Bold Header".to_string())].into(),
+                    )],
+                    vec![Inline::Emphasis(
+                        vec![Inline::Text("Italic Header".to_string())].into(),
+                    )],
                 ],
                 vec![
                     vec![
@@ -317,6 +320,7 @@ fn test_task_list_states() {
                     )])],
                 },
             ],
+            tight: true,
         })],
     };
 
@@ -342,6 +346,7 @@ fn test_all_list_bullet_kinds() {
                     task: None,
                     blocks: vec![Block::Paragraph(vec![Inline::Text("Item".to_string())])],
                 }],
+                tight: true,
             })],
         };
 
@@ -366,6 +371,7 @@ fn test_ordered_list_with_custom_start() {
                     blocks: vec![Block::Paragraph(vec![Inline::Text("Second".to_string())])],
                 },
             ],
+            tight: true,
         })],
     };
 
@@ -17,6 +17,81 @@ fn test_simple_paragraph() {
     assert_eq!(result.trim(), "Hello, world!");
 }
 
+#[test]
+fn test_heading_labels() {
+    let heading = |text: &str| {
+        Block::Heading(Heading {
+            kind: HeadingKind::Atx(2),
+            content: vec![Inline::Text(text.to_string())],
+        })
+    };
+    let doc = Document {
+        blocks: vec![heading("Usage"), heading("Usage")],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, "\\subsection{Usage}\n\n\\subsection{Usage}");
+
+    let result = render_latex(&doc, Config::default().with_heading_labels(true));
+    assert_eq!(
+        result,
+        "\\subsection{Usage}\\label{usage}\n\n\\subsection{Usage}\\label{usage-1}"
+    );
+}
+
+#[test]
+fn test_definition_list() {
+    let doc = Document {
+        blocks: vec![Block::DefinitionList(DefinitionList {
+            items: vec![DefinitionListItem {
+                term: vec![Inline::Text("Apple".to_string())],
+                definitions: vec![
+                    vec![Block::Paragraph(vec![Inline::Text("A fruit".to_string())])],
+                    vec![Block::Paragraph(vec![Inline::Text("A company".to_string())])],
+                ],
+            }],
+        })],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(
+        result,
+        "\\begin{description}\n\\item[{Apple}] A fruit\n\nA company\n\n\\end{description}"
+    );
+}
+
+#[test]
+fn test_front_matter_is_omitted() {
+    let doc = Document {
+        blocks: vec![
+            Block::FrontMatter {
+                format: FrontMatterFormat::Yaml,
+                raw: "title: Hello".to_string(),
+            },
+            Block::Paragraph(vec![Inline::Text("Hello, world!".to_string())]),
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, "Hello, world!");
+}
+
+#[test]
+fn test_math_is_verbatim() {
+    let doc = Document {
+        blocks: vec![
+            Block::Paragraph(vec![
+                Inline::Text("Area ".to_string()),
+                Inline::Math(r"\pi r^2".to_string()),
+            ]),
+            Block::Math(r"\sum_{i=1}^n i".to_string()),
+        ],
+    };
+
+    let result = render_latex(&doc, Config::default());
+    assert_eq!(result, "Area $\\pi r^2$\n\n\\[\n\\sum_{i=1}^n i\n\\]");
+}
+
 #[test]
 fn test_latex_escaping() {
     let doc = Document {
@@ -68,9 +143,9 @@ fn test_emphasis() {
     let doc = Document {
         blocks: vec![Block::Paragraph(vec![
             Inline::Text("Normal ".to_string()),
-            Inline::Emphasis(vec![Inline::Text("italic".to_string())]),
+            Inline::Emphasis(vec![Inline::Text("italic".to_string())].into()),
             Inline::Text(" and ".to_string()),
-            Inline::Strong(vec![Inline::Text("bold".to_string())]),
+            Inline::Strong(vec![Inline::Text("bold".to_string())].into()),
             Inline::Text(" text.".to_string()),
         ])],
     };
@@ -150,6 +225,7 @@ fn test_lists() {
                         )])],
                     },
                 ],
+                tight: true,
             }),
             Block::List(List {
                 kind: ListKind::Ordered(ListOrderedKindOptions { start: 1 }),
@@ -157,6 +233,7 @@ fn test_lists() {
                     task: None,
                     blocks: vec![Block::Paragraph(vec![Inline::Text("Numbered".to_string())])],
                 }],
+                tight: true,
             }),
         ],
     };
@@ -265,11 +342,11 @@ fn test_width_works_for_complex_content() {
             Inline::Text("Start ".to_string()),
             Inline::Strong(vec![Inline::Text(
                 "bold very long text that should wrap".to_string(),
-            )]),
+            )].into()),
             Inline::Text(" middle ".to_string()),
             Inline::Emphasis(vec![Inline::Text(
                 "italic very long text that should also wrap".to_string(),
-            )]),
+            )].into()),
             Inline::Text(" end".to_string()),
         ])],
     };
@@ -333,3 +410,79 @@ fn test_code_blocks_no_wrapping() {
         wide_lines.len()
     );
 }
+
+#[test]
+fn test_render_to_writer_skips_front_matter() {
+    let doc = Document {
+        blocks: vec![
+            Block::FrontMatter {
+                format: FrontMatterFormat::Yaml,
+                raw: "title: x".to_string(),
+            },
+            Block::Heading(Heading {
+                kind: HeadingKind::Atx(1),
+                content: vec![Inline::Text("Title".to_string())],
+            }),
+            Block::Paragraph(vec![Inline::Text("Text".to_string())]),
+        ],
+    };
+
+    let mut out = Vec::new();
+    crate::latex_printer::render_latex_to(&doc, Config::default(), &mut out).unwrap();
+    let result = String::from_utf8(out).unwrap();
+    assert_eq!(result, "\\section{Title}\n\nText");
+}
+
+#[test]
+fn test_render_to_writer_numbers_footnotes_across_blocks() {
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::default(),
+        "# Intro\n\nSee[^n].\n\nAgain[^m] and[^n].\n\n[^n]: Note.\n\n[^m]: Other.",
+    )
+    .unwrap();
+
+    let mut out = Vec::new();
+    crate::latex_printer::render_latex_to(&doc, Config::default(), &mut out).unwrap();
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        concat!(
+            "\\section{Intro}\n\n",
+            "See\\footnotemark{[1]}.\n\n",
+            "Again\\footnotemark{[2]} and\\footnotemark{[1]}.\n\n",
+            "\\footnotetext{[1] Note.}\n\n",
+            "\\footnotetext{[2] Other.}",
+        )
+    );
+}
+
+#[test]
+fn test_code_highlighter_falls_back_to_code_block_style() {
+    let code_block = |info: Option<&str>| {
+        Block::CodeBlock(CodeBlock {
+            kind: match info {
+                Some(info) => CodeBlockKind::Fenced {
+                    info: Some(info.to_string()),
+                },
+                None => CodeBlockKind::Indented,
+            },
+            literal: "x = 1".to_string(),
+        })
+    };
+    let doc = Document {
+        blocks: vec![code_block(Some("python")), code_block(Some("text")), code_block(None)],
+    };
+    let highlighter = |info: Option<&str>, code: &str| {
+        (info == Some("python")).then(|| format!("\\begin{{py}}\n{code}\n\\end{{py}}"))
+    };
+
+    let config = Config::default()
+        .with_code_block_style(CodeBlockStyle::Minted)
+        .with_code_highlighter(std::sync::Arc::new(highlighter));
+    let result = render_latex(&doc, config);
+    assert_eq!(
+        result,
+        "\\begin{py}\nx = 1\n\\end{py}\n\n\
+         \\begin{minted}{text}\nx = 1\n\\end{minted}\n\n\
+         \\begin{minted}{text}\nx = 1\n\\end{minted}"
+    );
+}
@@ -47,6 +47,10 @@ pub mod html_printer;
 #[cfg(feature = "latex-printer")]
 pub mod latex_printer;
 
+/// Syntax highlighting hook for code blocks in the HTML and LaTeX renderers.
+#[cfg(any(feature = "html-printer", feature = "latex-printer"))]
+pub mod highlight;
+
 /// Plaintext renderer for converting Markdown AST to plain text.
 ///
 /// Render AST to plaintext using [`render_plaintext`](plaintext_printer::render_plaintext).
@@ -56,3 +60,9 @@ pub mod plaintext_printer;
 /// AST transformation utilities for manipulating parsed Markdown.
 #[cfg(feature = "ast-transform")]
 pub mod ast_transform;
+
+/// Markdown linter with pluggable rules.
+///
+/// Check documents with a [`Linter`](lint::Linter).
+#[cfg(feature = "lint")]
+pub mod lint;
@@ -0,0 +1,357 @@
+//! Markdown linter with pluggable rules
+//!
+//! A [`Linter`] runs a set of [`Rule`]s over a document and collects the
+//! problems they find as [`Diagnostic`]s. [`Linter::default`] enables all
+//! built-in rules of the [`rules`] module; custom rules implement [`Rule`],
+//! usually with an [`ast_transform::Visitor`](crate::ast_transform::Visitor).
+//!
+//! Documents parsed with
+//! [`parse_markdown_with_spans`](crate::parser::parse_markdown_with_spans) can
+//! be linted with [`Linter::lint_with_spans`], which adds the source location
+//! of the reported node to every diagnostic.
+//!
+//! Some problems can be fixed automatically with the transformers of
+//! [`ast_transform::fixes`](crate::ast_transform::fixes).
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(feature = "parser")]
+//! # {
+//! use markdown_ppp::lint::{Linter, Severity};
+//! use markdown_ppp::parser::{parse_markdown_with_spans, MarkdownParserState};
+//!
+//! let input = "# Title\n\n### Details.\n";
+//! let doc = parse_markdown_with_spans(MarkdownParserState::default(), input).unwrap();
+//!
+//! let linter = Linter::default().with_severity("heading-increment", Severity::Error);
+//! let diagnostics = linter.lint_with_spans(&doc);
+//!
+//! assert_eq!(diagnostics.len(), 2);
+//! assert_eq!(diagnostics[0].rule, "heading-increment");
+//! assert_eq!(diagnostics[0].severity, Severity::Error);
+//! assert_eq!(
+//!     diagnostics[1].to_string(),
+//!     "3:1: warning: Heading ends with punctuation '.' (no-trailing-punctuation)"
+//! );
+//! # }
+//! ```
+
+pub mod rules;
+
+#[cfg(test)]
+mod tests;
+
+use crate::ast::convert::StripData;
+use crate::ast::{generic, Block, Document, Inline, Span};
+use std::collections::HashMap;
+use std::fmt;
+
+/// How serious a problem found by a rule is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// A hint, e.g. about style
+    Info,
+    /// A likely problem
+    Warning,
+    /// A definite problem
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        })
+    }
+}
+
+/// A problem found by a rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Name of the rule that found the problem
+    pub rule: &'static str,
+    /// Severity of the problem
+    pub severity: Severity,
+    /// Description of the problem
+    pub message: String,
+    /// Source range of the node with the problem. Only available with
+    /// [`Linter::lint_with_spans`].
+    pub span: Option<Span>,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(span) = self.span {
+            write!(f, "{}:{}: ", span.start.line, span.start.column)?;
+        }
+        write!(f, "{}: {} ({})", self.severity, self.message, self.rule)
+    }
+}
+
+/// A lint rule.
+///
+/// Rules are `Send + Sync`, so that linters can be shared between threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::ast_transform::Visitor;
+/// use markdown_ppp::lint::{LintContext, Linter, Rule};
+///
+/// /// Reports raw HTML blocks
+/// struct NoHtmlBlocks;
+///
+/// impl Rule for NoHtmlBlocks {
+///     fn name(&self) -> &'static str {
+///         "no-html-blocks"
+///     }
+///
+///     fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+///         struct Check<'c, 'a>(&'c mut LintContext<'a>);
+///
+///         impl Visitor for Check<'_, '_> {
+///             fn visit_block(&mut self, block: &Block) {
+///                 if let Block::HtmlBlock(_) = block {
+///                     self.0.report_block(block, "Raw HTML block");
+///                 }
+///                 self.walk_block(block);
+///             }
+///         }
+///
+///         Check(ctx).visit_document(doc);
+///     }
+/// }
+///
+/// let doc = Document {
+///     blocks: vec![Block::HtmlBlock("<div></div>".to_string())],
+/// };
+/// let diagnostics = Linter::new().with_rule(NoHtmlBlocks).lint(&doc);
+/// assert_eq!(diagnostics[0].message, "Raw HTML block");
+/// ```
+pub trait Rule: Send + Sync {
+    /// Name of the rule, e.g. `heading-increment`. Used in diagnostics and to
+    /// configure the linter.
+    fn name(&self) -> &'static str;
+
+    /// Severity of the problems found by the rule, unless configured
+    /// otherwise with [`Linter::with_severity`].
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    /// Report the problems of `doc` to `ctx`.
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>);
+}
+
+/// Collects the problems reported by a rule.
+pub struct LintContext<'a> {
+    rule: &'static str,
+    severity: Severity,
+    spans: Option<&'a SpanIndex>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl LintContext<'_> {
+    /// Report a problem of the whole document.
+    pub fn report(&mut self, message: impl Into<String>) {
+        self.push(message.into(), None);
+    }
+
+    /// Report a problem of `block`, which must be a node of the linted
+    /// document to get a span.
+    pub fn report_block(&mut self, block: &Block, message: impl Into<String>) {
+        let span = self
+            .spans
+            .and_then(|spans| spans.blocks.get(&(block as *const Block)))
+            .copied();
+        self.push(message.into(), span);
+    }
+
+    /// Report a problem of `inline`, which must be a node of the linted
+    /// document to get a span.
+    pub fn report_inline(&mut self, inline: &Inline, message: impl Into<String>) {
+        let span = self
+            .spans
+            .and_then(|spans| spans.inlines.get(&(inline as *const Inline)))
+            .copied();
+        self.push(message.into(), span);
+    }
+
+    fn push(&mut self, message: String, span: Option<Span>) {
+        self.diagnostics.push(Diagnostic {
+            rule: self.rule,
+            severity: self.severity,
+            message,
+            span,
+        });
+    }
+}
+
+/// Runs lint rules over documents.
+///
+/// [`Linter::default`] enables all rules of the [`rules`] module,
+/// [`Linter::new`] none.
+pub struct Linter {
+    rules: Vec<Box<dyn Rule>>,
+    severities: HashMap<String, Severity>,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self {
+            rules: rules::all(),
+            severities: HashMap::new(),
+        }
+    }
+}
+
+impl Linter {
+    /// Linter without rules
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            severities: HashMap::new(),
+        }
+    }
+
+    /// Add `rule`.
+    pub fn with_rule(mut self, rule: impl Rule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Remove the rules named `name`.
+    pub fn without_rule(mut self, name: &str) -> Self {
+        self.rules.retain(|rule| rule.name() != name);
+        self
+    }
+
+    /// Report the problems found by the rule named `name` with `severity`
+    /// instead of its default severity.
+    pub fn with_severity(mut self, name: &str, severity: Severity) -> Self {
+        self.severities.insert(name.to_owned(), severity);
+        self
+    }
+
+    /// Lint `doc`. Diagnostics have no spans.
+    ///
+    /// Diagnostics are grouped by rule, in the order the rules were added, and
+    /// in document order for each rule.
+    pub fn lint(&self, doc: &Document) -> Vec<Diagnostic> {
+        self.run(doc, None)
+    }
+
+    /// Lint a document with source spans, as returned by
+    /// [`parse_markdown_with_spans`](crate::parser::parse_markdown_with_spans).
+    ///
+    /// Diagnostics of nodes have the span of the node and are sorted by
+    /// position. Diagnostics of the whole document come last.
+    pub fn lint_with_spans(&self, doc: &generic::Document<Span>) -> Vec<Diagnostic> {
+        let plain = doc.clone().strip_data();
+        let mut spans = SpanIndex::default();
+        spans.blocks(&plain.blocks, &doc.blocks);
+
+        let mut diagnostics = self.run(&plain, Some(&spans));
+        diagnostics.sort_by_key(|d| (d.span.is_none(), d.span.map(|s| s.start.offset)));
+        diagnostics
+    }
+
+    fn run(&self, doc: &Document, spans: Option<&SpanIndex>) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        for rule in &self.rules {
+            let mut ctx = LintContext {
+                rule: rule.name(),
+                severity: self
+                    .severities
+                    .get(rule.name())
+                    .copied()
+                    .unwrap_or_else(|| rule.default_severity()),
+                spans,
+                diagnostics: Vec::new(),
+            };
+            rule.check(doc, &mut ctx);
+            diagnostics.append(&mut ctx.diagnostics);
+        }
+        diagnostics
+    }
+}
+
+/// Spans of the nodes of a document without spans, by node address
+#[derive(Default)]
+struct SpanIndex {
+    blocks: HashMap<*const Block, Span>,
+    inlines: HashMap<*const Inline, Span>,
+}
+
+impl SpanIndex {
+    /// Index `plain`, which was stripped from `spanned`
+    fn blocks(&mut self, plain: &[Block], spanned: &[generic::Block<Span>]) {
+        for (block, spanned) in plain.iter().zip(spanned) {
+            self.blocks.insert(block, *spanned.user_data());
+            match (block, spanned) {
+                (Block::Paragraph(content), generic::Block::Paragraph { content: s, .. }) => {
+                    self.inlines(content, s)
+                }
+                (Block::Heading(heading), generic::Block::Heading(s)) => {
+                    self.inlines(&heading.content, &s.content)
+                }
+                (Block::BlockQuote(blocks), generic::Block::BlockQuote { blocks: s, .. }) => {
+                    self.blocks(blocks, s)
+                }
+                (Block::List(list), generic::Block::List(s)) => {
+                    for (item, s) in list.items.iter().zip(&s.items) {
+                        self.blocks(&item.blocks, &s.blocks);
+                    }
+                }
+                (Block::DefinitionList(list), generic::Block::DefinitionList(s)) => {
+                    for (item, s) in list.items.iter().zip(&s.items) {
+                        self.inlines(&item.term, &s.term);
+                        for (definition, s) in item.definitions.iter().zip(&s.definitions) {
+                            self.blocks(definition, s);
+                        }
+                    }
+                }
+                (Block::Table(table), generic::Block::Table(s)) => {
+                    for (row, s) in table.rows.iter().zip(&s.rows) {
+                        for (cell, s) in row.iter().zip(s) {
+                            self.inlines(cell, s);
+                        }
+                    }
+                }
+                (Block::FootnoteDefinition(footnote), generic::Block::FootnoteDefinition(s)) => {
+                    self.blocks(&footnote.blocks, &s.blocks)
+                }
+                (Block::GitHubAlert(alert), generic::Block::GitHubAlert(s)) => {
+                    self.blocks(&alert.blocks, &s.blocks)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn inlines(&mut self, plain: &[Inline], spanned: &[generic::Inline<Span>]) {
+        for (inline, spanned) in plain.iter().zip(spanned) {
+            self.inlines.insert(inline, *spanned.user_data());
+            match (inline, spanned) {
+                (Inline::Emphasis(e), generic::Inline::Emphasis { content, .. })
+                | (Inline::Strong(e), generic::Inline::Strong { content, .. }) => {
+                    self.inlines(&e.content, content)
+                }
+                (Inline::Strikethrough(e), generic::Inline::Strikethrough { content, .. }) => {
+                    self.inlines(e, content)
+                }
+                (Inline::Link(link), generic::Inline::Link(s)) => {
+                    self.inlines(&link.children, &s.children)
+                }
+                (Inline::LinkReference(reference), generic::Inline::LinkReference(s)) => {
+                    self.inlines(&reference.text, &s.text)
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,354 @@
+//! Built-in lint rules
+//!
+//! All rules are enabled by [`Linter::default`](super::Linter::default) and
+//! can be disabled with [`Linter::without_rule`](super::Linter::without_rule)
+//! by their name:
+//!
+//! | Rule                      | Name                      |
+//! |---------------------------|---------------------------|
+//! | [`HeadingIncrement`]      | `heading-increment`       |
+//! | [`DuplicateHeadings`]     | `no-duplicate-heading`    |
+//! | [`EmptyLinks`]            | `no-empty-links`          |
+//! | [`MissingAltText`]        | `no-alt-text`             |
+//! | [`UndefinedReferences`]   | `undefined-reference`     |
+//! | [`UnusedDefinitions`]     | `unused-definition`       |
+//! | [`UnusedFootnotes`]       | `unused-footnote`         |
+//! | [`TrailingPunctuation`]   | `no-trailing-punctuation` |
+
+use super::{LintContext, Rule};
+use crate::ast::*;
+use crate::ast_transform::toc::heading_level;
+use crate::ast_transform::Visitor;
+use std::collections::HashSet;
+
+/// All built-in rules with their default settings
+pub fn all() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(HeadingIncrement),
+        Box::new(DuplicateHeadings),
+        Box::new(EmptyLinks),
+        Box::new(MissingAltText),
+        Box::new(UndefinedReferences::default()),
+        Box::new(UnusedDefinitions),
+        Box::new(UnusedFootnotes),
+        Box::new(TrailingPunctuation::default()),
+    ]
+}
+
+/// Heading levels only increase by one at a time, e.g. no `###` directly
+/// after `#`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeadingIncrement;
+
+impl Rule for HeadingIncrement {
+    fn name(&self) -> &'static str {
+        "heading-increment"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        let mut previous = None;
+        for_each_heading(doc, |block, heading| {
+            let level = heading_level(heading);
+            if let Some(previous) = previous.filter(|&p| level > p + 1) {
+                ctx.report_block(
+                    block,
+                    format!("Heading level jumps from {previous} to {level}"),
+                );
+            }
+            previous = Some(level);
+        });
+    }
+}
+
+/// No two headings have the same text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DuplicateHeadings;
+
+impl Rule for DuplicateHeadings {
+    fn name(&self) -> &'static str {
+        "no-duplicate-heading"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        let mut seen = HashSet::new();
+        for_each_heading(doc, |block, heading| {
+            let text = collapse_whitespace(&inline_text(&heading.content));
+            if !seen.insert(text.clone()) {
+                ctx.report_block(block, format!("Duplicate heading '{text}'"));
+            }
+        });
+    }
+}
+
+/// Links have a destination other than an empty fragment (`#`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EmptyLinks;
+
+impl Rule for EmptyLinks {
+    fn name(&self) -> &'static str {
+        "no-empty-links"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        for_each_inline(doc, |inline| {
+            if let Inline::Link(link) = inline {
+                if matches!(link.destination.trim(), "" | "#") {
+                    ctx.report_inline(inline, "Link has no destination");
+                }
+            }
+        });
+    }
+}
+
+/// Images have alternative text.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MissingAltText;
+
+impl Rule for MissingAltText {
+    fn name(&self) -> &'static str {
+        "no-alt-text"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        for_each_inline(doc, |inline| {
+            if let Inline::Image(image) = inline {
+                if image.alt.trim().is_empty() {
+                    ctx.report_inline(inline, "Image has no alternative text");
+                }
+            }
+        });
+    }
+}
+
+/// Link references and footnote references have a matching definition.
+///
+/// Shortcut references (`[text]`) are not checked by default, because the
+/// parser can not tell them apart from text in brackets.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UndefinedReferences {
+    shortcut_references: bool,
+}
+
+impl UndefinedReferences {
+    /// Also check shortcut references (`[text]`)
+    pub fn with_shortcut_references(self, shortcut_references: bool) -> Self {
+        Self {
+            shortcut_references,
+        }
+    }
+}
+
+impl Rule for UndefinedReferences {
+    fn name(&self) -> &'static str {
+        "undefined-reference"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        let labels = DefinedLabels::of(doc);
+        for_each_inline(doc, |inline| match inline {
+            Inline::LinkReference(reference) => {
+                if reference.label == reference.text && !self.shortcut_references {
+                    return;
+                }
+                let label = normalize_label(&reference.label);
+                if !labels.links.contains(&label) {
+                    ctx.report_inline(inline, format!("Undefined reference '{label}'"));
+                }
+            }
+            Inline::FootnoteReference(label) if !labels.footnotes.contains(label) => {
+                ctx.report_inline(inline, format!("Undefined footnote '{label}'"));
+            }
+            _ => {}
+        });
+    }
+}
+
+/// Link definitions (`[label]: destination`) are referenced.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnusedDefinitions;
+
+impl Rule for UnusedDefinitions {
+    fn name(&self) -> &'static str {
+        "unused-definition"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        let used = UsedLabels::of(doc);
+        for_each_block(doc, |block| {
+            if let Block::Definition(definition) = block {
+                let label = normalize_label(&definition.label);
+                if !used.links.contains(&label) {
+                    ctx.report_block(block, format!("Unused link definition '{label}'"));
+                }
+            }
+        });
+    }
+}
+
+/// Footnote definitions are referenced.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnusedFootnotes;
+
+impl Rule for UnusedFootnotes {
+    fn name(&self) -> &'static str {
+        "unused-footnote"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        let used = UsedLabels::of(doc);
+        for_each_block(doc, |block| {
+            if let Block::FootnoteDefinition(footnote) = block {
+                if !used.footnotes.contains(&footnote.label) {
+                    let label = &footnote.label;
+                    ctx.report_block(block, format!("Unused footnote '{label}'"));
+                }
+            }
+        });
+    }
+}
+
+/// Headings do not end with punctuation.
+#[derive(Debug, Clone)]
+pub struct TrailingPunctuation {
+    punctuation: String,
+}
+
+impl Default for TrailingPunctuation {
+    fn default() -> Self {
+        Self {
+            punctuation: ".,;:!。，；：！".to_owned(),
+        }
+    }
+}
+
+impl TrailingPunctuation {
+    /// Characters that headings may not end with. Default: `.,;:!。，；：！`
+    pub fn with_punctuation(self, punctuation: impl Into<String>) -> Self {
+        Self {
+            punctuation: punctuation.into(),
+        }
+    }
+}
+
+impl Rule for TrailingPunctuation {
+    fn name(&self) -> &'static str {
+        "no-trailing-punctuation"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        for_each_heading(doc, |block, heading| {
+            let text = inline_text(&heading.content);
+            if let Some(c) = text.trim_end().chars().last() {
+                if self.punctuation.contains(c) {
+                    ctx.report_block(block, format!("Heading ends with punctuation '{c}'"));
+                }
+            }
+        });
+    }
+}
+
+/// Labels of the link and footnote definitions of a document
+struct DefinedLabels {
+    links: HashSet<String>,
+    footnotes: HashSet<String>,
+}
+
+impl DefinedLabels {
+    fn of(doc: &Document) -> Self {
+        let mut labels = Self {
+            links: HashSet::new(),
+            footnotes: HashSet::new(),
+        };
+        for_each_block(doc, |block| match block {
+            Block::Definition(definition) => {
+                labels.links.insert(normalize_label(&definition.label));
+            }
+            Block::FootnoteDefinition(footnote) => {
+                labels.footnotes.insert(footnote.label.clone());
+            }
+            _ => {}
+        });
+        labels
+    }
+}
+
+/// Labels of the link and footnote references of a document
+struct UsedLabels {
+    links: HashSet<String>,
+    footnotes: HashSet<String>,
+}
+
+impl UsedLabels {
+    fn of(doc: &Document) -> Self {
+        let mut labels = Self {
+            links: HashSet::new(),
+            footnotes: HashSet::new(),
+        };
+        for_each_inline(doc, |inline| match inline {
+            Inline::LinkReference(reference) => {
+                labels.links.insert(normalize_label(&reference.label));
+            }
+            Inline::FootnoteReference(label) => {
+                labels.footnotes.insert(label.clone());
+            }
+            _ => {}
+        });
+        labels
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Call `f` with every block of `doc`, in document order
+fn for_each_block(doc: &Document, f: impl FnMut(&Block)) {
+    struct Blocks<F>(F);
+
+    impl<F: FnMut(&Block)> Visitor for Blocks<F> {
+        fn visit_block(&mut self, block: &Block) {
+            (self.0)(block);
+            self.walk_block(block);
+        }
+    }
+
+    Blocks(f).visit_document(doc);
+}
+
+/// Call `f` with every heading block of `doc` and its heading
+fn for_each_heading(doc: &Document, mut f: impl FnMut(&Block, &Heading)) {
+    for_each_block(doc, |block| {
+        if let Block::Heading(heading) = block {
+            f(block, heading);
+        }
+    });
+}
+
+/// Call `f` with every inline of `doc`, in document order. The label of a
+/// link reference is not visited, only its text.
+fn for_each_inline(doc: &Document, f: impl FnMut(&Inline)) {
+    struct Inlines<F>(F);
+
+    impl<F: FnMut(&Inline)> Visitor for Inlines<F> {
+        fn visit_block(&mut self, block: &Block) {
+            // Definition labels are not content
+            if !matches!(block, Block::Definition(_)) {
+                self.walk_block(block);
+            }
+        }
+
+        fn visit_inline(&mut self, inline: &Inline) {
+            (self.0)(inline);
+            match inline {
+                Inline::LinkReference(reference) => {
+                    for inline in &reference.text {
+                        self.visit_inline(inline);
+                    }
+                }
+                _ => self.walk_inline(inline),
+            }
+        }
+    }
+
+    Inlines(f).visit_document(doc);
+}
@@ -0,0 +1,114 @@
+#![cfg(feature = "parser")]
+use crate::ast::{Block, Document, Position, Span};
+use crate::lint::{Diagnostic, LintContext, Linter, Rule, Severity};
+
+/// Reports the document if it is empty
+struct NotEmpty;
+
+impl Rule for NotEmpty {
+    fn name(&self) -> &'static str {
+        "not-empty"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Info
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        if doc.blocks.iter().all(|b| matches!(b, Block::Empty)) {
+            ctx.report("Document is empty");
+        }
+    }
+}
+
+/// Reports documents with less than two blocks
+struct Short;
+
+impl Rule for Short {
+    fn name(&self) -> &'static str {
+        "short"
+    }
+
+    fn check(&self, doc: &Document, ctx: &mut LintContext<'_>) {
+        if doc.blocks.len() < 2 {
+            ctx.report("Document is short");
+        }
+    }
+}
+
+fn lint_with_spans(input: &str, linter: Linter) -> Vec<Diagnostic> {
+    let doc = crate::parser::parse_markdown_with_spans(
+        crate::parser::MarkdownParserState::default(),
+        input,
+    )
+    .unwrap();
+    linter.lint_with_spans(&doc)
+}
+
+#[test]
+fn custom_rules_and_severities() {
+    let doc = Document { blocks: vec![] };
+
+    let diagnostics = Linter::new().with_rule(NotEmpty).lint(&doc);
+    assert_eq!(
+        vec![Diagnostic {
+            rule: "not-empty",
+            severity: Severity::Info,
+            message: "Document is empty".to_owned(),
+            span: None,
+        }],
+        diagnostics
+    );
+    assert_eq!(
+        "info: Document is empty (not-empty)",
+        diagnostics[0].to_string()
+    );
+
+    let linter = Linter::default()
+        .with_rule(NotEmpty)
+        .with_severity("not-empty", Severity::Error);
+    assert_eq!(Severity::Error, linter.lint(&doc)[0].severity);
+
+    assert!(Linter::new().lint(&doc).is_empty());
+    assert!(Linter::default().lint(&doc).is_empty());
+    let linter = Linter::new().with_rule(NotEmpty).without_rule("not-empty");
+    assert!(linter.lint(&doc).is_empty());
+}
+
+#[test]
+fn diagnostics_have_spans() {
+    let input = "# a\n\n> - ### b.\n\nText ![](x.png) and [*y*](#).\n";
+    let diagnostics = lint_with_spans(input, Linter::default());
+    let formatted: Vec<_> = diagnostics.iter().map(|d| d.to_string()).collect();
+    assert_eq!(
+        vec![
+            "3:5: warning: Heading level jumps from 1 to 3 (heading-increment)",
+            "3:5: warning: Heading ends with punctuation '.' (no-trailing-punctuation)",
+            "5:6: warning: Image has no alternative text (no-alt-text)",
+            "5:21: warning: Link has no destination (no-empty-links)",
+        ],
+        formatted
+    );
+    assert_eq!(
+        Some(Span {
+            start: Position {
+                offset: 37,
+                line: 5,
+                column: 21,
+            },
+            end: Position {
+                offset: 45,
+                line: 5,
+                column: 29,
+            },
+        }),
+        diagnostics[3].span
+    );
+}
+
+#[test]
+fn diagnostics_of_the_document_come_last() {
+    let diagnostics = lint_with_spans("# a.", Linter::default().with_rule(Short));
+    let rules: Vec<_> = diagnostics.iter().map(|d| d.rule).collect();
+    assert_eq!(vec!["no-trailing-punctuation", "short"], rules);
+}
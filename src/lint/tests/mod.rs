@@ -0,0 +1,7 @@
+//! Tests for the linter
+
+#[cfg(test)]
+mod linter;
+
+#[cfg(test)]
+mod rules;
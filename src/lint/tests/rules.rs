@@ -0,0 +1,107 @@
+#![cfg(feature = "parser")]
+use crate::lint::rules::{TrailingPunctuation, UndefinedReferences};
+use crate::lint::{Linter, Rule};
+use rstest::rstest;
+
+fn messages(input: &str, linter: Linter) -> Vec<String> {
+    let doc = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    linter
+        .lint(&doc)
+        .into_iter()
+        .map(|d| format!("{}: {}", d.rule, d.message))
+        .collect()
+}
+
+fn only(rule: impl Rule + 'static) -> Linter {
+    Linter::new().with_rule(rule)
+}
+
+#[rstest(input, expected,
+    case("# a\n\n## b\n\n### c\n\n# d\n\n## e", &[]),
+    case("## a\n\n#### b", &["heading-increment: Heading level jumps from 2 to 4"]),
+    case("a\n===\n\n### b", &["heading-increment: Heading level jumps from 1 to 3"]),
+    case("# a\n\n> ### b", &["heading-increment: Heading level jumps from 1 to 3"]),
+)]
+fn heading_increment_is_checked(input: &str, expected: &[&str]) {
+    let linter = Linter::default().without_rule("no-duplicate-heading");
+    assert_eq!(expected, messages(input, linter));
+}
+
+#[rstest(input, expected,
+    case("# a\n\n## b", &[]),
+    case("# a\n\n## a", &["no-duplicate-heading: Duplicate heading 'a'"]),
+    case("# *a* b\n\n- # a  b", &["no-duplicate-heading: Duplicate heading 'a b'"]),
+)]
+fn duplicate_headings_are_checked(input: &str, expected: &[&str]) {
+    let linter = Linter::default().without_rule("heading-increment");
+    assert_eq!(expected, messages(input, linter));
+}
+
+#[rstest(input, expected,
+    case("[a](b) [c](#c)", &[]),
+    case("[a](<>) and [b](#)", &[
+        "no-empty-links: Link has no destination",
+        "no-empty-links: Link has no destination",
+    ]),
+    case("![](a.png) ![b](b.png)", &["no-alt-text: Image has no alternative text"]),
+    case("[![](a.png)](#)", &[
+        "no-empty-links: Link has no destination",
+        "no-alt-text: Image has no alternative text",
+    ]),
+)]
+fn links_and_images_are_checked(input: &str, expected: &[&str]) {
+    assert_eq!(expected, messages(input, Linter::default()));
+}
+
+#[rstest(input, expected,
+    case("[a][b]\n\n[B]: /b", &[]),
+    case("[a][b  c]\n\n[B C]: /b", &[]),
+    case("[a][b]", &["undefined-reference: Undefined reference 'b'"]),
+    case("[a] and [b][]", &[]),
+    case("a[^1]\n\n[^1]: b", &[]),
+    case("a[^1]", &["undefined-reference: Undefined footnote '1'"]),
+)]
+fn undefined_references_are_checked(input: &str, expected: &[&str]) {
+    let rule = UndefinedReferences::default();
+    assert_eq!(expected, messages(input, only(rule)));
+}
+
+#[test]
+fn shortcut_references_are_checked_if_enabled() {
+    let rule = UndefinedReferences::default().with_shortcut_references(true);
+    assert_eq!(
+        vec!["undefined-reference: Undefined reference 'a'"],
+        messages("[a] and [b]\n\n[b]: /b", only(rule))
+    );
+}
+
+#[rstest(input, expected,
+    case("[a]\n\n[a]: /a", &[]),
+    case("[x][A]\n\n[a]: /a", &[]),
+    case("a\n\n[a]: /a", &["unused-definition: Unused link definition 'a'"]),
+    case("a[^1]\n\n[^1]: b", &[]),
+    case("a\n\n[^1]: b", &["unused-footnote: Unused footnote '1'"]),
+)]
+fn unused_definitions_are_checked(input: &str, expected: &[&str]) {
+    assert_eq!(expected, messages(input, Linter::default()));
+}
+
+#[rstest(input, expected,
+    case("# a?", &[]),
+    case("# a.", &["no-trailing-punctuation: Heading ends with punctuation '.'"]),
+    case("a：\n---", &["no-trailing-punctuation: Heading ends with punctuation '：'"]),
+    case("# *a!*", &["no-trailing-punctuation: Heading ends with punctuation '!'"]),
+)]
+fn trailing_punctuation_is_checked(input: &str, expected: &[&str]) {
+    assert_eq!(expected, messages(input, Linter::default()));
+}
+
+#[test]
+fn trailing_punctuation_is_configurable() {
+    let rule = TrailingPunctuation::default().with_punctuation("?");
+    assert_eq!(
+        vec!["no-trailing-punctuation: Heading ends with punctuation '?'"],
+        messages("# a.\n\n# b?", only(rule))
+    );
+}
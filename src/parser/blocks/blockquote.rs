@@ -1,4 +1,5 @@
 use crate::ast::Block;
+use crate::parser::span::SourceText;
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::{
@@ -14,17 +15,18 @@ pub(crate) fn blockquote<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Block>> {
     move |input: &'a str| {
+        let text = input;
         // Block quote marker: 0-3 leading spaces, '>', optional space
         // Per CommonMark spec, the space after '>' is part of the marker and should be stripped
         let prefix = preceded(many_m_n(0, 3, char(' ')), (char('>'), opt(char(' '))));
 
         let (input, lines) =
             many1(preceded(prefix, line_terminated(not_eof_or_eol0))).parse(input)?;
-        let inner = lines.join("\n");
+        let inner = SourceText::join_lines(&state, text, &lines);
 
-        let nested_state = Rc::new(state.nested());
+        let nested_state = Rc::new(inner.state(&state.nested()));
         let (_, inner) = many1(crate::parser::blocks::block(nested_state))
-            .parse(&inner)
+            .parse(inner.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
         let inner = inner.into_iter().flatten().collect();
@@ -0,0 +1,141 @@
+use crate::ast::{DefinitionList, DefinitionListItem, Inline};
+use crate::parser::span::{spanned, SourceText};
+use crate::parser::util::*;
+use crate::parser::MarkdownParserState;
+use nom::{
+    branch::alt,
+    character::complete::{char, space0},
+    combinator::{map, not, peek, recognize},
+    multi::{many0, many1, many_m_n},
+    sequence::{delimited, preceded},
+    IResult, Parser,
+};
+use std::rc::Rc;
+
+/// Parse the `: ` marker of a definition, returning the width of the prefix
+/// that continuation lines must be indented by.
+fn definition_marker(input: &str) -> IResult<&str, usize> {
+    let (remaining, _) = delimited(
+        many_m_n(0, 3, char(' ')),
+        char(':'),
+        many_m_n(1, 4, char(' ')),
+    )
+    .parse(input)?;
+
+    Ok((remaining, input.len() - remaining.len()))
+}
+
+fn definition_rest_line(prefix_length: usize) -> impl FnMut(&str) -> IResult<&str, Vec<&str>> {
+    move |input: &str| {
+        line_terminated(alt((
+            // Line indented by prefix_length spaces
+            preceded(
+                many_m_n(prefix_length, prefix_length, char(' ')),
+                map(not_eof_or_eol1, |v| vec![v]),
+            ),
+            // Empty lines, followed by a line indented by prefix_length spaces
+            map(
+                (
+                    recognize(many1(line_terminated(space0))),
+                    preceded(
+                        many_m_n(prefix_length, prefix_length, char(' ')),
+                        not_eof_or_eol1,
+                    ),
+                ),
+                |(newlines, content)| vec![newlines, content],
+            ),
+        )))
+        .parse(input)
+    }
+}
+
+fn definition<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<crate::ast::Block>> {
+    move |input: &'a str| {
+        let text = input;
+        let (input, prefix_length) = definition_marker(input)?;
+        let (input, first_line) = line_terminated(not_eof_or_eol1).parse(input)?;
+        let (input, rest_lines) = many0(definition_rest_line(prefix_length)).parse(input)?;
+
+        let mut content = SourceText::new(&state);
+        content.push(first_line);
+        let mut previous = first_line;
+        for line in rest_lines {
+            content.push_line_ending(text, previous);
+            for subline in line {
+                content.push(subline);
+                previous = subline;
+            }
+        }
+
+        let nested_state = Rc::new(content.state(&state.nested()));
+        let (_, blocks) = many0(crate::parser::blocks::block(nested_state))
+            .parse(content.as_str())
+            .map_err(|err| err.map_input(|_| input))?;
+
+        Ok((input, blocks.into_iter().flatten().collect()))
+    }
+}
+
+fn term<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
+    move |input: &'a str| {
+        let (input, line) = line_terminated(not_eof_or_eol1).parse(input)?;
+        let (_, content) = crate::parser::inline::inline_many1(state.clone())
+            .parse(line.trim_end())
+            .map_err(|err| err.map_input(|_| input))?;
+
+        Ok((input, content))
+    }
+}
+
+fn definition_list_item<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DefinitionListItem> {
+    move |input: &'a str| {
+        // The term is a single line that does not start any other block
+        let (input, term) = preceded(
+            (
+                crate::parser::blocks::paragraph::is_paragraph_line_start(state.clone()),
+                peek(not(definition_marker)),
+                many_m_n(0, 3, char(' ')),
+            ),
+            spanned(&state, true, |_| 1, term(state.clone())),
+        )
+        .parse(input)?;
+
+        let (input, first) =
+            spanned(&state, true, |_| 1, definition(state.clone())).parse(input)?;
+        let (input, rest) = many0(preceded(
+            many_empty_lines0,
+            spanned(&state, true, |_| 1, definition(state.clone())),
+        ))
+        .parse(input)?;
+
+        let mut definitions = vec![first];
+        definitions.extend(rest);
+
+        Ok((input, DefinitionListItem { term, definitions }))
+    }
+}
+
+pub(crate) fn definition_list<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, DefinitionList> {
+    move |input: &'a str| {
+        let (input, first) =
+            spanned(&state, true, |_| 1, definition_list_item(state.clone())).parse(input)?;
+        let (input, rest) = many0(preceded(
+            many_empty_lines0,
+            spanned(&state, true, |_| 1, definition_list_item(state.clone())),
+        ))
+        .parse(input)?;
+
+        let mut items = vec![first];
+        items.extend(rest);
+
+        Ok((input, DefinitionList { items }))
+    }
+}
@@ -1,4 +1,5 @@
 use crate::ast::FootnoteDefinition;
+use crate::parser::span::SourceText;
 use crate::parser::util::{line_terminated, not_eof_or_eol1};
 use crate::parser::MarkdownParserState;
 use nom::character::complete::{char, none_of};
@@ -15,6 +16,7 @@ pub(crate) fn footnote_definition<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, FootnoteDefinition> {
     move |input: &'a str| {
+        let text = input;
         let (input, _) = many_m_n(0, 3, char(' ')).parse(input)?;
         let (input, _) = tag("[^").parse(input)?;
         let (input, label) = recognize(many1(verify(none_of("]"), |c| *c != ']'))).parse(input)?;
@@ -27,19 +29,20 @@ pub(crate) fn footnote_definition<'a>(
         ))
         .parse(input)?;
 
-        let total_size = first_line.len() + rest_lines.len();
-        let mut footnote_content = String::with_capacity(total_size);
+        let mut footnote_content = SourceText::new(&state);
         if !first_line.is_empty() {
-            footnote_content.push_str(first_line)
+            footnote_content.push(first_line)
         }
+        let mut previous = first_line;
         for line in rest_lines {
-            footnote_content.push('\n');
-            footnote_content.push_str(line)
+            footnote_content.push_line_ending(text, previous);
+            footnote_content.push(line);
+            previous = line;
         }
 
-        let nested_state = Rc::new(state.nested());
+        let nested_state = Rc::new(footnote_content.state(&state.nested()));
         let (_, blocks) = many0(crate::parser::blocks::block(nested_state))
-            .parse(&footnote_content)
+            .parse(footnote_content.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
         let blocks = blocks.into_iter().flatten().collect();
@@ -0,0 +1,48 @@
+use crate::ast::{Block, FrontMatterFormat};
+use crate::parser::util::*;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::space0,
+    combinator::{eof, not, value},
+    multi::many0,
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+pub(crate) fn front_matter(input: &str) -> IResult<&str, Block> {
+    let (input, format) = line_terminated(terminated(
+        alt((
+            value(FrontMatterFormat::Yaml, tag("---")),
+            value(FrontMatterFormat::Toml, tag("+++")),
+        )),
+        space0,
+    ))
+    .parse(input)?;
+
+    // YAML documents may also be closed with `...`
+    let alternative_fence = match format {
+        FrontMatterFormat::Yaml => "...",
+        FrontMatterFormat::Toml => "+++",
+    };
+    let closing_fence = || {
+        line_terminated(terminated(
+            alt((tag(format.fence()), tag(alternative_fence))),
+            space0,
+        ))
+    };
+
+    let (input, lines) = many0(preceded(
+        not(alt((closing_fence(), eof))),
+        line_terminated(not_eof_or_eol0),
+    ))
+    .parse(input)?;
+    let (input, _) = closing_fence().parse(input)?;
+
+    let front_matter = Block::FrontMatter {
+        format,
+        raw: lines.join("\n"),
+    };
+
+    Ok((input, front_matter))
+}
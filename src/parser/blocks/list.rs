@@ -1,11 +1,12 @@
 use crate::ast::{ListBulletKind, ListItem, ListKind, ListOrderedKindOptions, TaskState};
+use crate::parser::span::{rollback, spanned, SourceText};
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::combinator::verify;
 use nom::{
     branch::alt,
     character::complete::{char, one_of, space0},
-    combinator::{map, not, opt, peek, recognize, value},
+    combinator::{consumed, fail, map, not, opt, peek, recognize, value},
     multi::{many0, many1, many_m_n},
     sequence::{delimited, preceded, terminated},
     IResult, Parser,
@@ -56,6 +57,7 @@ fn list_marker_ordered(input: &str) -> IResult<&str, ListKind> {
 
 fn list_marker_followed_by_spaces(
     input: &str,
+    task_list_items: bool,
 ) -> IResult<&str, (ListKind, usize, Option<TaskState>)> {
     let (remaining, kind) = delimited(
         many_m_n(0, 3, char(' ')),
@@ -66,6 +68,9 @@ fn list_marker_followed_by_spaces(
 
     let consumed = input.len() - remaining.len();
 
+    if !task_list_items {
+        return Ok((remaining, (kind, consumed, None)));
+    }
     let (input, task_state) = opt(terminated(list_item_task_state, char(' '))).parse(remaining)?;
 
     Ok((input, (kind, consumed, task_state)))
@@ -73,6 +78,7 @@ fn list_marker_followed_by_spaces(
 
 fn list_marker_followed_by_newline(
     input: &str,
+    task_list_items: bool,
 ) -> IResult<&str, (ListKind, usize, Option<TaskState>)> {
     let (remaining, kind) = preceded(many_m_n(0, 3, char(' ')), list_marker).parse(input)?;
 
@@ -85,6 +91,9 @@ fn list_marker_followed_by_newline(
 
         return Ok((tail, (kind, consumed, None)));
     }
+    if !task_list_items {
+        return fail().parse(input);
+    }
 
     let (remaining, _) = many_m_n(0, 3, char(' ')).parse(remaining)?;
     let consumed = input.len() - remaining.len() + 1;
@@ -96,22 +105,19 @@ fn list_marker_followed_by_newline(
 
 pub(crate) fn list_marker_with_span_size(
     input: &str,
-) -> IResult<&str, (ListKind, usize, Option<TaskState>, String)> {
+    task_list_items: bool,
+) -> IResult<&str, (ListKind, usize, Option<TaskState>, &str)> {
     alt((
         map(
-            list_marker_followed_by_newline,
-            |(list_kind, prefix_length, task_state)| {
-                (list_kind, prefix_length, task_state, String::new())
-            },
+            |input| list_marker_followed_by_newline(input, task_list_items),
+            |(list_kind, prefix_length, task_state)| (list_kind, prefix_length, task_state, ""),
         ),
         (map(
             (
-                list_marker_followed_by_spaces,
+                |input| list_marker_followed_by_spaces(input, task_list_items),
                 line_terminated(not_eof_or_eol0),
             ),
-            |((list_kind, prefix_length, task_state), s)| {
-                (list_kind, prefix_length, task_state, s.to_string())
-            },
+            |((list_kind, prefix_length, task_state), s)| (list_kind, prefix_length, task_state, s),
         )),
     ))
     .parse(input)
@@ -193,40 +199,56 @@ fn list_item_lines(
     }
 }
 
+/// A list item, its marker kind and whether two of its blocks are separated by
+/// a blank line
 pub(crate) fn list_item(
     state: Rc<MarkdownParserState>,
-) -> impl FnMut(&str) -> IResult<&str, (ListKind, ListItem)> {
+) -> impl FnMut(&str) -> IResult<&str, (ListKind, ListItem, bool)> {
+    move |input: &str| spanned(&state, true, |_| 1, list_item_inner(state.clone())).parse(input)
+}
+
+fn list_item_inner(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&str) -> IResult<&str, (ListKind, ListItem, bool)> {
     move |input: &str| {
+        let text = input;
         let (input, (list_kind, item_prefix_length, task_state, first_line)) =
-            list_marker_with_span_size(input)?;
+            list_marker_with_span_size(input, state.config.allow_task_list_items)?;
 
         let (input, rest_lines) =
             list_item_lines(state.clone(), list_kind.clone(), item_prefix_length).parse(input)?;
 
-        let total_size = first_line.len() + rest_lines.len();
-        let mut item_content = String::with_capacity(total_size);
+        let mut item_content = SourceText::new(&state);
         if !first_line.is_empty() {
-            item_content.push_str(&first_line)
+            item_content.push(first_line)
         }
+        let mut previous = first_line;
         for line in rest_lines {
-            item_content.push('\n');
+            item_content.push_line_ending(text, previous);
             for subline in line {
-                item_content.push_str(subline)
+                item_content.push(subline);
+                previous = subline;
             }
         }
 
-        let nested_state = Rc::new(state.nested());
-        let (_, blocks) = many0(crate::parser::blocks::block(nested_state))
-            .parse(&item_content)
+        let nested_state = Rc::new(item_content.state(&state.nested()));
+        let (_, blocks) = many0(consumed(crate::parser::blocks::block(nested_state)))
+            .parse(item_content.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
-        let blocks = blocks.into_iter().flatten().collect();
+        let has_blank_line = blocks.windows(2).any(|pair| {
+            let ((prev, _), (next, _)) = (&pair[0], &pair[1]);
+            let trailing = &prev[prev.trim_end().len()..];
+            let leading = &next[..next.len() - next.trim_start().len()];
+            trailing.matches('\n').count() + leading.matches('\n').count() > 1
+        });
+        let blocks = blocks.into_iter().flat_map(|(_, blocks)| blocks).collect();
 
         let item = ListItem {
             task: task_state,
             blocks,
         };
-        Ok((input, (list_kind, item)))
+        Ok((input, (list_kind, item, has_blank_line)))
     }
 }
 
@@ -234,16 +256,43 @@ pub(crate) fn list(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&str) -> IResult<&str, crate::ast::List> {
     move |input: &str| {
-        let (input, items) = many1(list_item(state.clone())).parse(input)?;
+        let (mut input, (kind, first_item, has_blank_line)) =
+            list_item(state.clone()).parse(input)?;
+        let mut items = vec![first_item];
+        let mut tight = !has_blank_line;
 
-        // With many1(), first element always present
-        let first_item = items.first().unwrap();
+        // Items may be separated by blank lines, which make the list loose
+        while !input.is_empty() {
+            let (rest, blank_lines) = many_empty_lines0(input)?;
+            let next_item = rollback(
+                &state,
+                verify(list_item(state.clone()), |(next_kind, _, _)| {
+                    same_list(&kind, next_kind)
+                }),
+            )
+            .parse(rest);
+            match next_item {
+                Ok((rest, (_, item, has_blank_line))) => {
+                    tight &= blank_lines.is_empty() && !has_blank_line;
+                    items.push(item);
+                    input = rest;
+                }
+                Err(nom::Err::Error(_)) => break,
+                Err(err) => return Err(err),
+            }
+        }
 
-        let list = crate::ast::List {
-            kind: first_item.0.clone(),
-            items: items.into_iter().map(|(_, item)| item).collect(),
-        };
+        let list = crate::ast::List { kind, items, tight };
 
         Ok((input, list))
     }
 }
+
+/// Whether an item with marker `next` continues a list started with `first`
+fn same_list(first: &ListKind, next: &ListKind) -> bool {
+    match (first, next) {
+        (ListKind::Ordered(_), ListKind::Ordered(_)) => true,
+        (ListKind::Bullet(first), ListKind::Bullet(next)) => first == next,
+        _ => false,
+    }
+}
@@ -0,0 +1,43 @@
+use crate::parser::util::*;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_until},
+    character::complete::{char, space0},
+    combinator::{eof, not, verify},
+    multi::{many0, many_m_n},
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+
+pub(crate) fn math(input: &str) -> IResult<&str, String> {
+    let (input, _) = (many_m_n(0, 3, char(' ')), tag("$$")).parse(input)?;
+    alt((math_single_line, math_fenced)).parse(input)
+}
+
+/// `$$ x^2 $$` on a single line
+fn math_single_line(input: &str) -> IResult<&str, String> {
+    let (input, content) = line_terminated(terminated(
+        verify(take_until("$$"), |s: &str| {
+            !s.contains('\n') && !s.trim().is_empty()
+        }),
+        (tag("$$"), space0),
+    ))
+    .parse(input)?;
+
+    Ok((input, content.trim().to_owned()))
+}
+
+/// `$$` fences on their own lines around the content
+fn math_fenced(input: &str) -> IResult<&str, String> {
+    let closing_fence = || line_terminated((many_m_n(0, 3, char(' ')), tag("$$"), space0));
+
+    let (input, _) = line_terminated(space0).parse(input)?;
+    let (input, lines) = many0(preceded(
+        not(alt((closing_fence().map(|_| ""), eof))),
+        line_terminated(not_eof_or_eol0),
+    ))
+    .parse(input)?;
+    let (input, _) = closing_fence().parse(input)?;
+
+    Ok((input, lines.join("\n")))
+}
@@ -1,11 +1,14 @@
 mod blockquote;
 mod code_block;
+mod definition_list;
 mod footnote_definition;
+mod front_matter;
 mod github_alert;
 mod heading;
 mod html_block;
 mod link_definition;
 mod list;
+mod math;
 pub(crate) mod paragraph;
 mod table;
 mod thematic_break;
@@ -14,6 +17,7 @@ mod thematic_break;
 mod tests;
 
 use crate::ast::Block;
+use crate::parser::span::{rollback, spanned};
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::branch::alt;
@@ -27,91 +31,178 @@ pub(crate) fn block<'a>(
     move |input: &'a str| {
         preceded(
             many_empty_lines0,
-            alt((
-                conditional_block(
-                    state.config.block_code_block_behavior.clone(),
-                    map(
-                        crate::parser::blocks::code_block::code_block(state.clone()),
-                        Block::CodeBlock,
+            spanned(
+                &state,
+                true,
+                Vec::len,
+                alt((
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_code_block_behavior.clone(),
+                            map(
+                                crate::parser::blocks::code_block::code_block(state.clone()),
+                                Block::CodeBlock,
+                            ),
+                        ),
                     ),
-                ),
-                conditional_block(
-                    state.config.block_heading_v1_behavior.clone(),
-                    map(
-                        crate::parser::blocks::heading::heading_v1(state.clone()),
-                        Block::Heading,
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_math_behavior.clone(),
+                            map(crate::parser::blocks::math::math, Block::Math),
+                        ),
                     ),
-                ),
-                conditional_block(
-                    state.config.block_heading_v2_behavior.clone(),
-                    crate::parser::blocks::heading::heading_v2_or_paragraph(state.clone()),
-                ),
-                conditional_block(
-                    state.config.block_thematic_break_behavior.clone(),
-                    map(
-                        crate::parser::blocks::thematic_break::thematic_break(state.clone()),
-                        |()| Block::ThematicBreak,
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_heading_v1_behavior.clone(),
+                            map(
+                                crate::parser::blocks::heading::heading_v1(state.clone()),
+                                Block::Heading,
+                            ),
+                        ),
                     ),
-                ),
-                // GitHub alerts should be checked before regular blockquotes
-                conditional_block_vec(
-                    state.config.block_github_alert_behavior.clone(),
-                    crate::parser::blocks::github_alert::github_alert(state.clone()),
-                ),
-                conditional_block(
-                    state.config.block_blockquote_behavior.clone(),
-                    map(
-                        crate::parser::blocks::blockquote::blockquote(state.clone()),
-                        Block::BlockQuote,
+                    // Terms look like paragraphs, so definition lists go first
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_definition_list_behavior.clone(),
+                            map(
+                                crate::parser::blocks::definition_list::definition_list(
+                                    state.clone(),
+                                ),
+                                Block::DefinitionList,
+                            ),
+                        ),
                     ),
-                ),
-                conditional_block(
-                    state.config.block_list_behavior.clone(),
-                    map(
-                        crate::parser::blocks::list::list(state.clone()),
-                        Block::List,
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_heading_v2_behavior.clone(),
+                            crate::parser::blocks::heading::heading_v2_or_paragraph(state.clone()),
+                        ),
                     ),
-                ),
-                conditional_block(
-                    state.config.block_html_block_behavior.clone(),
-                    map(
-                        crate::parser::blocks::html_block::html_block(state.clone()),
-                        |s| Block::HtmlBlock(s.to_owned()),
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_thematic_break_behavior.clone(),
+                            map(
+                                crate::parser::blocks::thematic_break::thematic_break(
+                                    state.clone(),
+                                ),
+                                |()| Block::ThematicBreak,
+                            ),
+                        ),
                     ),
-                ),
-                // Alway try before link definition
-                conditional_block(
-                    state.config.block_footnote_definition_behavior.clone(),
-                    map(
-                        crate::parser::blocks::footnote_definition::footnote_definition(
-                            state.clone(),
+                    // GitHub alerts should be checked before regular blockquotes
+                    rollback(
+                        &state,
+                        conditional_block_vec(
+                            state.config.block_github_alert_behavior.clone(),
+                            crate::parser::blocks::github_alert::github_alert(state.clone()),
                         ),
-                        Block::FootnoteDefinition,
                     ),
-                ),
-                conditional_block(
-                    state.config.block_link_definition_behavior.clone(),
-                    map(
-                        crate::parser::blocks::link_definition::link_definition(state.clone()),
-                        Block::Definition,
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_blockquote_behavior.clone(),
+                            map(
+                                crate::parser::blocks::blockquote::blockquote(state.clone()),
+                                Block::BlockQuote,
+                            ),
+                        ),
                     ),
-                ),
-                conditional_block(
-                    state.config.block_table_behavior.clone(),
-                    map(
-                        crate::parser::blocks::table::table(state.clone()),
-                        Block::Table,
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_list_behavior.clone(),
+                            map(
+                                crate::parser::blocks::list::list(state.clone()),
+                                Block::List,
+                            ),
+                        ),
                     ),
-                ),
-                custom_parser(state.clone()),
-                conditional_block(
-                    state.config.block_paragraph_behavior.clone(),
-                    map(
-                        crate::parser::blocks::paragraph::paragraph(state.clone(), false),
-                        Block::Paragraph,
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_html_block_behavior.clone(),
+                            map(
+                                crate::parser::blocks::html_block::html_block(state.clone()),
+                                |s| Block::HtmlBlock(s.to_owned()),
+                            ),
+                        ),
+                    ),
+                    // Alway try before link definition
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_footnote_definition_behavior.clone(),
+                            map(
+                                crate::parser::blocks::footnote_definition::footnote_definition(
+                                    state.clone(),
+                                ),
+                                Block::FootnoteDefinition,
+                            ),
+                        ),
+                    ),
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_link_definition_behavior.clone(),
+                            map(
+                                crate::parser::blocks::link_definition::link_definition(
+                                    state.clone(),
+                                ),
+                                Block::Definition,
+                            ),
+                        ),
+                    ),
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_table_behavior.clone(),
+                            map(
+                                crate::parser::blocks::table::table(state.clone()),
+                                Block::Table,
+                            ),
+                        ),
+                    ),
+                    rollback(&state, custom_parser(state.clone())),
+                    rollback(
+                        &state,
+                        conditional_block(
+                            state.config.block_paragraph_behavior.clone(),
+                            map(
+                                crate::parser::blocks::paragraph::paragraph(state.clone(), false),
+                                Block::Paragraph,
+                            ),
+                        ),
                     ),
+                )),
+            ),
+        )
+        .parse(input)
+    }
+}
+
+/// Front matter is only recognized at the very beginning of the document, so
+/// unlike other blocks it is not a part of [`block`].
+pub(crate) fn front_matter<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Block>> {
+    move |input: &'a str| {
+        spanned(
+            &state,
+            true,
+            Vec::len,
+            rollback(
+                &state,
+                conditional_block(
+                    state.config.block_front_matter_behavior.clone(),
+                    crate::parser::blocks::front_matter::front_matter,
                 ),
-            )),
+            ),
         )
         .parse(input)
     }
@@ -122,8 +213,7 @@ pub(crate) fn custom_parser(
 ) -> impl FnMut(&str) -> IResult<&str, Vec<Block>> {
     move |input: &str| {
         if let Some(custom_parser) = state.config.custom_block_parser.as_ref() {
-            let mut p = (**custom_parser).borrow_mut();
-            (p.as_mut())(input)
+            custom_parser(input)
         } else {
             fail().parse(input)
         }
@@ -1,4 +1,5 @@
 use crate::ast::Inline;
+use crate::parser::span::{discard, SourceText};
 use crate::parser::util::*;
 use crate::parser::MarkdownParserState;
 use nom::{
@@ -16,6 +17,7 @@ pub(crate) fn paragraph<'a>(
     check_first_line: bool,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
     move |input: &'a str| {
+        let text = input;
         let mut lines = Vec::new();
         let input = if check_first_line {
             input
@@ -37,9 +39,9 @@ pub(crate) fn paragraph<'a>(
         let (input, rest_lines) = line_terminated(paragraph_parser).parse(input)?;
         lines.extend(rest_lines);
 
-        let content = lines.join("\n");
+        let content = SourceText::join_lines(&state, text, &lines);
 
-        let (_, content) = crate::parser::inline::inline_many1(state.clone())
+        let (_, content) = crate::parser::inline::inline_many1(Rc::new(content.state(&state)))
             .parse(content.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
@@ -51,74 +53,84 @@ pub(crate) fn is_paragraph_line_start<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, ()> {
     move |input: &'a str| {
-        peek(not(alt((
-            conditional_block_unit(
-                state.config.block_heading_v1_behavior.clone(),
-                value(
-                    (),
-                    crate::parser::blocks::heading::heading_v1(state.clone()),
+        // Only a lookahead: spans recorded by the nested parsers are dropped
+        discard(
+            &state,
+            peek(not(alt((
+                conditional_block_unit(
+                    state.config.block_heading_v1_behavior.clone(),
+                    value(
+                        (),
+                        crate::parser::blocks::heading::heading_v1(state.clone()),
+                    ),
                 ),
-            ),
-            conditional_block_unit(
-                state.config.block_heading_v2_behavior.clone(),
-                value(
-                    (),
-                    crate::parser::blocks::heading::heading_v2_level(state.clone()),
+                conditional_block_unit(
+                    state.config.block_heading_v2_behavior.clone(),
+                    value(
+                        (),
+                        crate::parser::blocks::heading::heading_v2_level(state.clone()),
+                    ),
                 ),
-            ),
-            conditional_block_unit(
-                state.config.block_thematic_break_behavior.clone(),
-                crate::parser::blocks::thematic_break::thematic_break(state.clone()),
-            ),
-            conditional_block_unit(
-                state.config.block_blockquote_behavior.clone(),
-                value(
-                    (),
-                    crate::parser::blocks::blockquote::blockquote(state.clone()),
+                conditional_block_unit(
+                    state.config.block_thematic_break_behavior.clone(),
+                    crate::parser::blocks::thematic_break::thematic_break(state.clone()),
                 ),
-            ),
-            conditional_block_unit(
-                state.config.block_list_behavior.clone(),
-                value((), crate::parser::blocks::list::list_item(state.clone())),
-            ),
-            conditional_block_unit(
-                state.config.block_code_block_behavior.clone(),
-                value(
-                    (),
-                    crate::parser::blocks::code_block::code_block_fenced(state.clone()),
+                conditional_block_unit(
+                    state.config.block_blockquote_behavior.clone(),
+                    value(
+                        (),
+                        crate::parser::blocks::blockquote::blockquote(state.clone()),
+                    ),
                 ),
-            ),
-            conditional_block_unit(
-                state.config.block_html_block_behavior.clone(),
-                value(
-                    (),
-                    crate::parser::blocks::html_block::html_block(state.clone()),
+                conditional_block_unit(
+                    state.config.block_list_behavior.clone(),
+                    value((), crate::parser::blocks::list::list_item(state.clone())),
                 ),
-            ),
-            conditional_block_unit(
-                state.config.block_link_definition_behavior.clone(),
-                value(
-                    (),
-                    crate::parser::blocks::link_definition::link_definition(state.clone()),
+                conditional_block_unit(
+                    state.config.block_code_block_behavior.clone(),
+                    value(
+                        (),
+                        crate::parser::blocks::code_block::code_block_fenced(state.clone()),
+                    ),
+                ),
+                conditional_block_unit(
+                    state.config.block_math_behavior.clone(),
+                    value((), crate::parser::blocks::math::math),
+                ),
+                conditional_block_unit(
+                    state.config.block_html_block_behavior.clone(),
+                    value(
+                        (),
+                        crate::parser::blocks::html_block::html_block(state.clone()),
+                    ),
+                ),
+                conditional_block_unit(
+                    state.config.block_link_definition_behavior.clone(),
+                    value(
+                        (),
+                        crate::parser::blocks::link_definition::link_definition(state.clone()),
+                    ),
+                ),
+                conditional_block_unit(
+                    state.config.block_footnote_definition_behavior.clone(),
+                    value(
+                        (),
+                        crate::parser::blocks::footnote_definition::footnote_definition(
+                            state.clone(),
+                        ),
+                    ),
+                ),
+                conditional_block_unit(
+                    state.config.block_table_behavior.clone(),
+                    value((), crate::parser::blocks::table::table(state.clone())),
                 ),
-            ),
-            conditional_block_unit(
-                state.config.block_footnote_definition_behavior.clone(),
                 value(
-                    (),
-                    crate::parser::blocks::footnote_definition::footnote_definition(state.clone()),
+                    vec![()],
+                    crate::parser::blocks::custom_parser(state.clone()),
                 ),
-            ),
-            conditional_block_unit(
-                state.config.block_table_behavior.clone(),
-                value((), crate::parser::blocks::table::table(state.clone())),
-            ),
-            value(
-                vec![()],
-                crate::parser::blocks::custom_parser(state.clone()),
-            ),
-            value(vec![()], line_terminated(space0)),
-        ))))
+                value(vec![()], line_terminated(space0)),
+            )))),
+        )
         .parse(input)
     }
 }
@@ -1,5 +1,6 @@
 use super::{eof_or_eol, line_terminated};
 use crate::ast::{Alignment, Inline, Table, TableRow};
+use crate::parser::span::{spanned, SourceText};
 use crate::parser::MarkdownParserState;
 use nom::multi::many_m_n;
 use nom::{
@@ -103,15 +104,18 @@ fn parse_table_row<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, TableRow> {
     move |input: &'a str| {
-        line_terminated(preceded(
+        let row = line_terminated(preceded(
             many_m_n(0, 3, char(' ')),
             delimited(
                 char('|'),
-                separated_list1(char('|'), cell_content(state.clone())),
+                separated_list1(
+                    char('|'),
+                    spanned(&state, false, |_| 1, cell_content(state.clone())),
+                ),
                 opt(char('|')),
             ),
-        ))
-        .parse(input)
+        ));
+        spanned(&state, true, |_| 1, row).parse(input)
     }
 }
 
@@ -119,16 +123,25 @@ fn cell_content<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
     move |input: &'a str| {
-        let (input, chars) = many1(preceded(
+        let (input, raw) = recognize(many1(preceded(
             not(alt((value((), eof_or_eol), value((), char('|'))))),
-            alt((value('|', tag("\\|")), anychar)),
-        ))
+            alt((value((), tag("\\|")), value((), anychar))),
+        )))
         .parse(input)?;
 
-        let content = chars.iter().collect::<String>();
-        let trimmed_content = content.trim();
-        let (_, content) = crate::parser::inline::inline_many0(state.clone())
-            .parse(trimmed_content)
+        // Unescape pipes, keeping track of where the cell text comes from
+        let raw = raw.trim();
+        let mut content = SourceText::new(&state);
+        let mut last = 0;
+        for (i, escaped) in raw.match_indices("\\|") {
+            content.push(&raw[last..i]);
+            content.push_replacement("|", &raw[i..i + escaped.len()]);
+            last = i + escaped.len();
+        }
+        content.push(&raw[last..]);
+
+        let (_, content) = crate::parser::inline::inline_many0(Rc::new(content.state(&state)))
+            .parse(content.as_str())
             .map_err(|err| err.map_input(|_| input))?;
 
         Ok((input, content))
@@ -67,9 +67,13 @@ fn blockquote_ignore1() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Text(
-                "> a\n>\n>> b".to_owned()
-            )]),]
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("> a".to_owned()),
+                Inline::SoftBreak,
+                Inline::Text(">".to_owned()),
+                Inline::SoftBreak,
+                Inline::Text(">> b".to_owned()),
+            ]),]
         }
     );
 }
@@ -82,9 +86,15 @@ fn blockquote_ignore2() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Text(
-                "a\n> a\n>\n>> b".to_owned()
-            )]),]
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("a".to_owned()),
+                Inline::SoftBreak,
+                Inline::Text("> a".to_owned()),
+                Inline::SoftBreak,
+                Inline::Text(">".to_owned()),
+                Inline::SoftBreak,
+                Inline::Text(">> b".to_owned()),
+            ]),]
         }
     );
 }
@@ -1,16 +1,15 @@
 use crate::ast::*;
 use crate::parser::{parse_markdown, MarkdownParserState};
 use nom::combinator::value;
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::sync::Arc;
 
 #[test]
 fn custom_parser1() {
     use nom::Parser;
     let config = crate::parser::config::MarkdownParserConfig::default().with_custom_block_parser(
-        Rc::new(RefCell::new(Box::new(|input: &str| {
+        Arc::new(|input: &str| {
             value(vec![Block::ThematicBreak], nom::bytes::complete::tag("///")).parse(input)
-        }))),
+        }),
     );
     let doc = parse_markdown(MarkdownParserState::with_config(config), "///\ntext\n===").unwrap();
     assert_eq!(
@@ -26,3 +25,51 @@ fn custom_parser1() {
         }
     );
 }
+
+#[test]
+fn config_is_shared_between_threads() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+    use nom::Parser;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<MarkdownParserConfig>();
+    assert_send_sync::<MarkdownParserState>();
+
+    let mapped = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&mapped);
+    let config = Arc::new(
+        MarkdownParserConfig::default()
+            .with_custom_block_parser(Arc::new(|input: &str| {
+                value(vec![Block::ThematicBreak], nom::bytes::complete::tag("///")).parse(input)
+            }))
+            .with_block_heading_v1_behavior(ElementBehavior::Map(Arc::new(move |block| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                block
+            }))),
+    );
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let config = Arc::clone(&config);
+            std::thread::spawn(move || {
+                parse_markdown(MarkdownParserState::with_config(config), "///\n# text").unwrap()
+            })
+        })
+        .collect();
+    for handle in handles {
+        assert_eq!(
+            handle.join().unwrap(),
+            Document {
+                blocks: vec![
+                    Block::ThematicBreak,
+                    Block::Heading(Heading {
+                        kind: HeadingKind::Atx(1),
+                        content: vec![Inline::Text("text".to_owned())]
+                    })
+                ]
+            }
+        );
+    }
+    assert_eq!(mapped.load(Ordering::Relaxed), 4);
+}
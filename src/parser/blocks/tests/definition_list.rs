@@ -0,0 +1,144 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn state() -> MarkdownParserState {
+    MarkdownParserState::with_config(
+        MarkdownParserConfig::default()
+            .with_block_definition_list_behavior(ElementBehavior::Parse),
+    )
+}
+
+fn text(s: &str) -> Inline {
+    Inline::Text(s.to_owned())
+}
+
+/// A paragraph with the lines of `s`
+fn paragraph(s: &str) -> Block {
+    let mut content = Vec::new();
+    for (i, line) in s.split('\n').enumerate() {
+        if i > 0 {
+            content.push(Inline::SoftBreak);
+        }
+        content.push(text(line));
+    }
+    Block::Paragraph(content)
+}
+
+#[test]
+fn definition_list1() {
+    let doc = parse_markdown(state(), "Apple\n: A fruit").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::DefinitionList(DefinitionList {
+                items: vec![DefinitionListItem {
+                    term: vec![text("Apple")],
+                    definitions: vec![vec![paragraph("A fruit")]],
+                }],
+            })]
+        }
+    );
+}
+
+#[test]
+fn definition_list_multiple_items_and_definitions() {
+    let doc = parse_markdown(
+        state(),
+        "*Apple*\n:   A fruit\n:   A company\n\nOrange\n: Another fruit\n\nText",
+    )
+    .unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::DefinitionList(DefinitionList {
+                    items: vec![
+                        DefinitionListItem {
+                            term: vec![Inline::Emphasis(vec![text("Apple")].into())],
+                            definitions: vec![
+                                vec![paragraph("A fruit")],
+                                vec![paragraph("A company")],
+                            ],
+                        },
+                        DefinitionListItem {
+                            term: vec![text("Orange")],
+                            definitions: vec![vec![paragraph("Another fruit")]],
+                        },
+                    ],
+                }),
+                paragraph("Text"),
+            ]
+        }
+    );
+}
+
+#[test]
+fn definition_list_continuation() {
+    let doc = parse_markdown(
+        state(),
+        "Term\n: First line\n  second line\n\n  Second paragraph\n\n  - item\nAfter",
+    )
+    .unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::DefinitionList(DefinitionList {
+                    items: vec![DefinitionListItem {
+                        term: vec![text("Term")],
+                        definitions: vec![vec![
+                            paragraph("First line\nsecond line"),
+                            paragraph("Second paragraph"),
+                            Block::List(List {
+                                kind: ListKind::Bullet(ListBulletKind::Dash),
+                                items: vec![ListItem {
+                                    task: None,
+                                    blocks: vec![paragraph("item")],
+                                }],
+                                tight: true,
+                            }),
+                        ]],
+                    }],
+                }),
+                paragraph("After"),
+            ]
+        }
+    );
+}
+
+#[test]
+fn definition_list_requires_term() {
+    let doc = parse_markdown(state(), "First\nSecond\n: Not a definition").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![paragraph("First\nSecond\n: Not a definition")]
+        }
+    );
+
+    let doc = parse_markdown(state(), "# Heading\n: Not a definition").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::Heading(Heading {
+                    kind: HeadingKind::Atx(1),
+                    content: vec![text("Heading")],
+                }),
+                paragraph(": Not a definition"),
+            ]
+        }
+    );
+}
+
+#[test]
+fn definition_list_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "Apple\n: A fruit").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![paragraph("Apple\n: A fruit")]
+        }
+    );
+}
@@ -31,9 +31,11 @@ fn footnote_definition2() {
         Document {
             blocks: vec![Block::FootnoteDefinition(FootnoteDefinition {
                 label: "foo".to_owned(),
-                blocks: vec![Block::Paragraph(vec![Inline::Text(
-                    "line1\nline2".to_owned()
-                ),])]
+                blocks: vec![Block::Paragraph(vec![
+                    Inline::Text("line1".to_owned()),
+                    Inline::SoftBreak,
+                    Inline::Text("line2".to_owned()),
+                ])]
             })]
         }
     );
@@ -0,0 +1,142 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn state() -> MarkdownParserState {
+    MarkdownParserState::with_config(
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Parse),
+    )
+}
+
+#[test]
+fn front_matter_yaml() {
+    let doc = parse_markdown(state(), "---\ntitle: Hello\ntags: [a, b]\n---\n\n# Hello").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::FrontMatter {
+                    format: FrontMatterFormat::Yaml,
+                    raw: "title: Hello\ntags: [a, b]".to_owned(),
+                },
+                Block::Heading(Heading {
+                    kind: HeadingKind::Atx(1),
+                    content: vec![Inline::Text("Hello".to_owned())],
+                }),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_yaml_dots() {
+    let doc = parse_markdown(state(), "---\ntitle: Hello\n...\nText").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::FrontMatter {
+                    format: FrontMatterFormat::Yaml,
+                    raw: "title: Hello".to_owned(),
+                },
+                Block::Paragraph(vec![Inline::Text("Text".to_owned())]),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_toml() {
+    let doc = parse_markdown(state(), "+++\ntitle = \"Hello\"\n\n[extra]\n+++\n").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::FrontMatter {
+                format: FrontMatterFormat::Toml,
+                raw: "title = \"Hello\"\n\n[extra]".to_owned(),
+            }]
+        }
+    );
+}
+
+#[test]
+fn front_matter_empty() {
+    let doc = parse_markdown(state(), "---\n---").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::FrontMatter {
+                format: FrontMatterFormat::Yaml,
+                raw: String::new(),
+            }]
+        }
+    );
+}
+
+#[test]
+fn front_matter_unclosed() {
+    let doc = parse_markdown(state(), "---\ntitle: Hello").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::ThematicBreak,
+                Block::Paragraph(vec![Inline::Text("title: Hello".to_owned())]),
+            ]
+        }
+    );
+}
+
+#[test]
+fn front_matter_only_at_document_start() {
+    let doc = parse_markdown(state(), "Text\n\n+++\na = 1\n+++").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::Paragraph(vec![Inline::Text("Text".to_owned())]),
+                Block::Paragraph(vec![
+                    Inline::Text("+++".to_owned()),
+                    Inline::SoftBreak,
+                    Inline::Text("a = 1".to_owned()),
+                    Inline::SoftBreak,
+                    Inline::Text("+++".to_owned())
+                ]),
+            ]
+        }
+    );
+
+    let doc = parse_markdown(state(), "\n---\na: 1\n---").unwrap();
+    assert!(!matches!(doc.blocks[0], Block::FrontMatter { .. }));
+}
+
+#[test]
+fn front_matter_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "---\n---").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::ThematicBreak, Block::ThematicBreak]
+        }
+    );
+}
+
+#[test]
+fn front_matter_skip() {
+    let config =
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Skip);
+    let doc = parse_markdown(
+        MarkdownParserState::with_config(config),
+        "---\ntitle: Hello\n---\nText",
+    )
+    .unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::Empty,
+                Block::Paragraph(vec![Inline::Text("Text".to_owned())]),
+            ]
+        }
+    );
+}
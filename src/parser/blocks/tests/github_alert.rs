@@ -117,7 +117,11 @@ fn github_alert_multiline() {
             blocks: vec![Block::GitHubAlert(GitHubAlert {
                 alert_type: GitHubAlertType::Note,
                 blocks: vec![
-                    Block::Paragraph(vec![Inline::Text("Line 1\nLine 2".to_string())]),
+                    Block::Paragraph(vec![
+                        Inline::Text("Line 1".to_string()),
+                        Inline::SoftBreak,
+                        Inline::Text("Line 2".to_string())
+                    ]),
                     Block::Paragraph(vec![Inline::Text("Line 3".to_string())])
                 ],
             })],
@@ -140,9 +144,9 @@ fn github_alert_with_formatting() {
                 alert_type: GitHubAlertType::Tip,
                 blocks: vec![Block::Paragraph(vec![
                     Inline::Text("Use ".to_string()),
-                    Inline::Strong(vec![Inline::Text("bold".to_string())]),
+                    Inline::Strong(vec![Inline::Text("bold".to_string())].into()),
                     Inline::Text(" and ".to_string()),
-                    Inline::Emphasis(vec![Inline::Text("italic".to_string())]),
+                    Inline::Emphasis(vec![Inline::Text("italic".to_string())].into()),
                     Inline::Text(" text".to_string())
                 ])],
             })],
@@ -177,7 +181,9 @@ fn regular_blockquote_not_alert() {
         doc,
         Document {
             blocks: vec![Block::BlockQuote(vec![Block::Paragraph(vec![
-                Inline::Text("This is not an alert\nJust a regular blockquote".to_string())
+                Inline::Text("This is not an alert".to_string()),
+                Inline::SoftBreak,
+                Inline::Text("Just a regular blockquote".to_string())
             ])])],
         }
     );
@@ -306,7 +312,8 @@ fn github_alert_invalid_custom_starts_with_number() {
                     label: vec![Inline::Text("!123INVALID".to_string())],
                     text: vec![Inline::Text("!123INVALID".to_string())],
                 }),
-                Inline::Text("\nShould not be parsed as alert".to_string())
+                Inline::SoftBreak,
+                Inline::Text("Should not be parsed as alert".to_string())
             ])])],
         }
     );
@@ -330,7 +337,8 @@ fn github_alert_invalid_custom_with_special_chars() {
                     label: vec![Inline::Text("!CUSTOM-ALERT".to_string())],
                     text: vec![Inline::Text("!CUSTOM-ALERT".to_string())],
                 }),
-                Inline::Text("\nShould not be parsed as alert".to_string())
+                Inline::SoftBreak,
+                Inline::Text("Should not be parsed as alert".to_string())
             ])])],
         }
     );
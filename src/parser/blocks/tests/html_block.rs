@@ -158,9 +158,11 @@ fn html_block_ignore1() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Text(
-                "<script>\n</script>".to_owned()
-            )])]
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("<script>".to_owned()),
+                Inline::SoftBreak,
+                Inline::Text("</script>".to_owned()),
+            ])]
         }
     );
 
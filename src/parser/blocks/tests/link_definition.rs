@@ -1,8 +1,7 @@
 use crate::ast::*;
 use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
 use crate::parser::{parse_markdown, MarkdownParserState};
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::sync::Arc;
 
 #[test]
 fn link_definition1() {
@@ -142,7 +141,7 @@ fn link_definition7() {
 #[test]
 fn link_definition_mapped1() {
     let config = MarkdownParserConfig::default().with_block_link_definition_behavior(
-        ElementBehavior::Map(Rc::new(RefCell::new(Box::new(|block| {
+        ElementBehavior::Map(Arc::new(|block| {
             if let Block::Definition(v) = block {
                 let mut label = vec![Inline::Text("mapped ".to_owned())];
                 label.extend(v.label);
@@ -154,7 +153,7 @@ fn link_definition_mapped1() {
             } else {
                 block
             }
-        })))),
+        })),
     );
     let doc = parse_markdown(
         MarkdownParserState::with_config(config),
@@ -179,7 +178,7 @@ fn link_definition_mapped1() {
 #[test]
 fn link_definition_mapped2() {
     let config = MarkdownParserConfig::default().with_block_link_definition_behavior(
-        ElementBehavior::FlatMap(Rc::new(RefCell::new(Box::new(|block| {
+        ElementBehavior::FlatMap(Arc::new(|block| {
             if let Block::Definition(v) = block {
                 let mut label = vec![Inline::Text("mapped ".to_owned())];
                 label.extend(v.label);
@@ -197,7 +196,7 @@ fn link_definition_mapped2() {
             } else {
                 vec![block]
             }
-        })))),
+        })),
     );
     let doc = parse_markdown(
         MarkdownParserState::with_config(config),
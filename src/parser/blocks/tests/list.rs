@@ -12,7 +12,8 @@ fn list1() {
                 items: vec![ListItem {
                     task: None,
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
-                }]
+                }],
+                tight: true,
             })]
         }
     );
@@ -29,7 +30,8 @@ fn list2() {
                 items: vec![ListItem {
                     task: None,
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
-                }]
+                }],
+                tight: true,
             })]
         }
     );
@@ -46,7 +48,8 @@ fn list3() {
                 items: vec![ListItem {
                     task: None,
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
-                }]
+                }],
+                tight: true,
             })]
         }
     );
@@ -63,7 +66,8 @@ fn list4() {
                 items: vec![ListItem {
                     task: None,
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
-                }]
+                }],
+                tight: true,
             })]
         }
     );
@@ -86,7 +90,8 @@ fn list5() {
                         task: None,
                         blocks: vec![Block::Paragraph(vec![Inline::Text("b".to_owned())])]
                     }
-                ]
+                ],
+                tight: true,
             })]
         }
     );
@@ -102,8 +107,13 @@ fn list6() {
                 kind: ListKind::Bullet(ListBulletKind::Dash),
                 items: vec![ListItem {
                     task: None,
-                    blocks: vec![Block::Paragraph(vec![Inline::Text("a\nb".to_owned())])]
-                }]
+                    blocks: vec![Block::Paragraph(vec![
+                        Inline::Text("a".to_owned()),
+                        Inline::SoftBreak,
+                        Inline::Text("b".to_owned())
+                    ])]
+                }],
+                tight: true,
             })]
         }
     );
@@ -120,8 +130,13 @@ fn list7() {
                     kind: ListKind::Bullet(ListBulletKind::Dash),
                     items: vec![ListItem {
                         task: None,
-                        blocks: vec![Block::Paragraph(vec![Inline::Text("a\nb".to_owned())])]
-                    }]
+                        blocks: vec![Block::Paragraph(vec![
+                            Inline::Text("a".to_owned()),
+                            Inline::SoftBreak,
+                            Inline::Text("b".to_owned())
+                        ])]
+                    }],
+                    tight: true,
                 }),
                 Block::Paragraph(vec![Inline::Text("c".to_owned())])
             ]
@@ -140,10 +155,15 @@ fn list8() {
                 items: vec![ListItem {
                     task: None,
                     blocks: vec![
-                        Block::Paragraph(vec![Inline::Text("a\nb".to_owned())]),
+                        Block::Paragraph(vec![
+                            Inline::Text("a".to_owned()),
+                            Inline::SoftBreak,
+                            Inline::Text("b".to_owned())
+                        ]),
                         Block::Paragraph(vec![Inline::Text("c".to_owned())]),
                     ]
-                }]
+                }],
+                tight: false,
             })]
         },
     );
@@ -181,7 +201,8 @@ fn list9() {
                                             "list2".to_owned()
                                         )]),]
                                     }
-                                ]
+                                ],
+                                tight: true,
                             })
                         ]
                     },
@@ -189,7 +210,8 @@ fn list9() {
                         task: None,
                         blocks: vec![Block::Paragraph(vec![Inline::Text("list1".to_owned())])]
                     }
-                ]
+                ],
+                tight: true,
             })]
         },
     );
@@ -212,7 +234,8 @@ fn list10() {
                         task: None,
                         blocks: vec![Block::Paragraph(vec![Inline::Text("list1".to_owned())])]
                     }
-                ]
+                ],
+                tight: true,
             })]
         },
     );
@@ -229,7 +252,8 @@ fn task_list1() {
                 items: vec![ListItem {
                     task: Some(TaskState::Incomplete),
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
-                }]
+                }],
+                tight: true,
             })]
         },
     );
@@ -246,7 +270,8 @@ fn task_list2() {
                 items: vec![ListItem {
                     task: Some(TaskState::Complete),
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
-                }]
+                }],
+                tight: true,
             })]
         },
     );
@@ -263,7 +288,8 @@ fn task_list3() {
                 items: vec![ListItem {
                     task: Some(TaskState::Complete),
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
-                }]
+                }],
+                tight: true,
             })]
         },
     );
@@ -280,7 +306,8 @@ fn task_list4() {
                 items: vec![ListItem {
                     task: Some(TaskState::Incomplete),
                     blocks: vec![]
-                }]
+                }],
+                tight: true,
             })]
         },
     );
@@ -297,7 +324,142 @@ fn task_list5() {
                 items: vec![ListItem {
                     task: Some(TaskState::Incomplete),
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
-                }]
+                }],
+                tight: true,
+            })]
+        },
+    );
+}
+
+#[test]
+fn loose_list() {
+    let doc = parse_markdown(MarkdownParserState::default(), "- a\n\n- b\n- c").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::List(List {
+                kind: ListKind::Bullet(ListBulletKind::Dash),
+                items: vec![
+                    ListItem {
+                        task: None,
+                        blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
+                    },
+                    ListItem {
+                        task: None,
+                        blocks: vec![Block::Paragraph(vec![Inline::Text("b".to_owned())])]
+                    },
+                    ListItem {
+                        task: None,
+                        blocks: vec![Block::Paragraph(vec![Inline::Text("c".to_owned())])]
+                    },
+                ],
+                tight: false,
+            })]
+        },
+    );
+}
+
+#[test]
+fn tight_list_with_loose_sublist() {
+    let doc = parse_markdown(
+        MarkdownParserState::default(),
+        "1. a\n   - b\n\n   - c\n2. d\n\ne",
+    )
+    .unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::List(List {
+                    kind: ListKind::Ordered(ListOrderedKindOptions { start: 1 }),
+                    items: vec![
+                        ListItem {
+                            task: None,
+                            blocks: vec![
+                                Block::Paragraph(vec![Inline::Text("a".to_owned())]),
+                                Block::List(List {
+                                    kind: ListKind::Bullet(ListBulletKind::Dash),
+                                    items: vec![
+                                        ListItem {
+                                            task: None,
+                                            blocks: vec![Block::Paragraph(vec![Inline::Text(
+                                                "b".to_owned()
+                                            )])]
+                                        },
+                                        ListItem {
+                                            task: None,
+                                            blocks: vec![Block::Paragraph(vec![Inline::Text(
+                                                "c".to_owned()
+                                            )])]
+                                        },
+                                    ],
+                                    tight: false,
+                                }),
+                            ]
+                        },
+                        ListItem {
+                            task: None,
+                            blocks: vec![Block::Paragraph(vec![Inline::Text("d".to_owned())])]
+                        },
+                    ],
+                    tight: true,
+                }),
+                Block::Paragraph(vec![Inline::Text("e".to_owned())]),
+            ]
+        },
+    );
+}
+
+#[test]
+fn changed_bullet_starts_new_list() {
+    let doc = parse_markdown(MarkdownParserState::default(), "- a\n\n* b").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::List(List {
+                    kind: ListKind::Bullet(ListBulletKind::Dash),
+                    items: vec![ListItem {
+                        task: None,
+                        blocks: vec![Block::Paragraph(vec![Inline::Text("a".to_owned())])]
+                    }],
+                    tight: true,
+                }),
+                Block::List(List {
+                    kind: ListKind::Bullet(ListBulletKind::Star),
+                    items: vec![ListItem {
+                        task: None,
+                        blocks: vec![Block::Paragraph(vec![Inline::Text("b".to_owned())])]
+                    }],
+                    tight: true,
+                }),
+            ]
+        },
+    );
+}
+
+#[test]
+fn task_list_items_disabled() {
+    use crate::parser::config::MarkdownParserConfig;
+
+    let config = MarkdownParserConfig::default().with_allow_task_list_items(false);
+    let doc = parse_markdown(MarkdownParserState::with_config(config), " - [ ] a\n - [ ]").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::List(List {
+                kind: ListKind::Bullet(ListBulletKind::Dash),
+                items: vec![
+                    ListItem {
+                        task: None,
+                        blocks: vec![Block::Paragraph(vec![Inline::Text("[ ] a".to_owned())])]
+                    },
+                    ListItem {
+                        task: None,
+                        blocks: vec![Block::Paragraph(vec![Inline::Text("[ ]".to_owned())])]
+                    },
+                ],
+                tight: true,
             })]
         },
     );
@@ -0,0 +1,86 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+fn state() -> MarkdownParserState {
+    MarkdownParserState::with_config(
+        MarkdownParserConfig::default()
+            .with_block_math_behavior(ElementBehavior::Parse)
+            .with_inline_math_behavior(ElementBehavior::Parse),
+    )
+}
+
+#[test]
+fn math_fenced() {
+    let doc = parse_markdown(state(), "$$\n\\int_0^1 x\\,dx\n\n= \\frac{1}{2}\n$$").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Math("\\int_0^1 x\\,dx\n\n= \\frac{1}{2}".to_owned())]
+        }
+    );
+}
+
+#[test]
+fn math_single_line() {
+    let doc = parse_markdown(state(), "  $$ x^2 $$  \nText").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::Math("x^2".to_owned()),
+                Block::Paragraph(vec![Inline::Text("Text".to_owned())]),
+            ]
+        }
+    );
+}
+
+#[test]
+fn math_interrupts_paragraph() {
+    let doc = parse_markdown(state(), "Text\n$$\nx\n$$").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![
+                Block::Paragraph(vec![Inline::Text("Text".to_owned())]),
+                Block::Math("x".to_owned()),
+            ]
+        }
+    );
+}
+
+#[test]
+fn math_in_list() {
+    let doc = parse_markdown(state(), "- item\n\n  $$\n  x\n  $$").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::List(List {
+                kind: ListKind::Bullet(ListBulletKind::Dash),
+                items: vec![ListItem {
+                    task: None,
+                    blocks: vec![
+                        Block::Paragraph(vec![Inline::Text("item".to_owned())]),
+                        Block::Math("x".to_owned()),
+                    ],
+                }],
+                tight: false,
+            })]
+        }
+    );
+}
+
+#[test]
+fn math_unclosed() {
+    let doc = parse_markdown(state(), "$$\nx").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("$$".to_owned()),
+                Inline::SoftBreak,
+                Inline::Text("x".to_owned())
+            ])]
+        }
+    );
+}
@@ -1,12 +1,15 @@
 mod blockquote;
 mod code_block;
 mod custom_parser;
+mod definition_list;
 mod footnote_definition;
+mod front_matter;
 mod github_alert;
 mod heading;
 mod html_block;
 mod link_definition;
 mod list;
+mod math;
 mod paragraph;
 mod table;
 mod thematic_break;
@@ -23,7 +23,13 @@ fn minimal_paragraph() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Text("a\nb\nc".to_string())])]
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("a".to_string()),
+                Inline::SoftBreak,
+                Inline::Text("b".to_string()),
+                Inline::SoftBreak,
+                Inline::Text("c".to_string())
+            ])]
         }
     );
 }
@@ -90,7 +96,11 @@ fn paragraph_with_indented_line1() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Text("a\n b".to_string())])],
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("a".to_string()),
+                Inline::SoftBreak,
+                Inline::Text("b".to_string())
+            ])],
         }
     );
 }
@@ -1,21 +1,26 @@
 use nom::IResult;
-use std::cell::RefCell;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// Function type for mapping elements.
-type ElementMapFn<ELT> = Rc<RefCell<Box<dyn FnMut(ELT) -> ELT>>>;
+///
+/// Functions are shared between all parses using the configuration, possibly
+/// on several threads at once. Use a `Mutex` or atomics for mutable state.
+///
+/// Before 3.0 this was `Rc<RefCell<Box<dyn FnMut(ELT) -> ELT>>>`, which made
+/// the configuration `!Send`. The same applies to the other function types.
+pub type ElementMapFn<ELT> = Arc<dyn Fn(ELT) -> ELT + Send + Sync>;
 
 /// Function type for mapping elements.
-type ElementFlatMapFn<ELT> = Rc<RefCell<Box<dyn FnMut(ELT) -> Vec<ELT>>>>;
+pub type ElementFlatMapFn<ELT> = Arc<dyn Fn(ELT) -> Vec<ELT> + Send + Sync>;
 
 /// Function type for custom block parsers.
-type CustomBlockParserFn =
-    Rc<RefCell<Box<dyn for<'a> FnMut(&'a str) -> IResult<&'a str, Vec<crate::ast::Block>>>>>;
+pub type CustomBlockParserFn =
+    Arc<dyn for<'a> Fn(&'a str) -> IResult<&'a str, Vec<crate::ast::Block>> + Send + Sync>;
 
 /// Function type for custom inline parsers.
-type CustomInlineParserFn =
-    Rc<RefCell<Box<dyn for<'a> FnMut(&'a str) -> IResult<&'a str, Vec<crate::ast::Inline>>>>>;
+pub type CustomInlineParserFn =
+    Arc<dyn for<'a> Fn(&'a str) -> IResult<&'a str, Vec<crate::ast::Inline>> + Send + Sync>;
 
 /// Behavior of the parser when encountering certain elements.
 #[derive(Clone)]
@@ -38,11 +43,23 @@ pub enum ElementBehavior<ELT> {
 }
 
 /// A configuration for the Markdown parser.
+///
+/// Start from [`default`](Self::default) or from one of the dialect presets
+/// [`commonmark`](Self::commonmark), [`gfm`](Self::gfm) and
+/// [`extended`](Self::extended), and adjust single elements with the `with_*`
+/// methods.
+///
+/// The configuration is `Send + Sync`: build it once, wrap it in an `Arc` and
+/// create a [`MarkdownParserState`](crate::parser::MarkdownParserState) from
+/// it on every thread that parses.
 #[derive(Clone)]
 pub struct MarkdownParserConfig {
     /// If true, the parser will allow headings without a space after the hash marks.
     pub(crate) allow_no_space_in_headings: bool,
 
+    /// If true, list items starting with `[ ]` or `[x]` are parsed as task list items.
+    pub(crate) allow_task_list_items: bool,
+
     /// A map of HTML entities to their corresponding `Entity` structs.
     pub(crate) html_entities_map: HashMap<String, &'static entities::Entity>,
 
@@ -82,6 +99,15 @@ pub struct MarkdownParserConfig {
     /// The behavior of the parser when encountering block paragraphs.
     pub(crate) block_paragraph_behavior: ElementBehavior<crate::ast::Block>,
 
+    /// The behavior of the parser when encountering front matter at the start of the document.
+    pub(crate) block_front_matter_behavior: ElementBehavior<crate::ast::Block>,
+
+    /// The behavior of the parser when encountering display math (e.g., `$$ x^2 $$`).
+    pub(crate) block_math_behavior: ElementBehavior<crate::ast::Block>,
+
+    /// The behavior of the parser when encountering definition lists (`Term` followed by `: definition`).
+    pub(crate) block_definition_list_behavior: ElementBehavior<crate::ast::Block>,
+
     /// The behavior of the parser when encountering inline autolinks.
     pub(crate) inline_autolink_behavior: ElementBehavior<crate::ast::Inline>,
 
@@ -112,6 +138,13 @@ pub struct MarkdownParserConfig {
     /// The behavior of the parser when encountering inline text.
     pub(crate) inline_text_behavior: ElementBehavior<crate::ast::Inline>,
 
+    /// The behavior of the parser when encountering inline math (e.g., `$x^2$`).
+    pub(crate) inline_math_behavior: ElementBehavior<crate::ast::Inline>,
+
+    /// The behavior of the parser when encountering identifiers with underscores
+    /// (e.g., `PKG_CONFIG_PATH`).
+    pub(crate) inline_environment_variable_behavior: ElementBehavior<crate::ast::Inline>,
+
     /// A custom parser for blocks. This is a function that takes a string and returns a `Block`.
     pub(crate) custom_block_parser: Option<CustomBlockParserFn>,
 
@@ -123,6 +156,7 @@ impl Default for MarkdownParserConfig {
     fn default() -> Self {
         Self {
             allow_no_space_in_headings: false,
+            allow_task_list_items: true,
             html_entities_map: Self::make_html_entities_map(),
             block_blockquote_behavior: ElementBehavior::Parse,
             block_github_alert_behavior: ElementBehavior::Parse,
@@ -136,6 +170,9 @@ impl Default for MarkdownParserConfig {
             block_link_definition_behavior: ElementBehavior::Parse,
             block_table_behavior: ElementBehavior::Parse,
             block_paragraph_behavior: ElementBehavior::Parse,
+            block_front_matter_behavior: ElementBehavior::Ignore,
+            block_math_behavior: ElementBehavior::Ignore,
+            block_definition_list_behavior: ElementBehavior::Ignore,
             inline_autolink_behavior: ElementBehavior::Parse,
             inline_link_behavior: ElementBehavior::Parse,
             inline_footnote_reference_behavior: ElementBehavior::Parse,
@@ -146,6 +183,8 @@ impl Default for MarkdownParserConfig {
             inline_emphasis_behavior: ElementBehavior::Parse,
             inline_strikethrough_behavior: ElementBehavior::Parse,
             inline_text_behavior: ElementBehavior::Parse,
+            inline_math_behavior: ElementBehavior::Ignore,
+            inline_environment_variable_behavior: ElementBehavior::Parse,
             custom_block_parser: None,
             custom_inline_parser: None,
         }
@@ -161,6 +200,61 @@ impl MarkdownParserConfig {
         map
     }
 
+    /// Plain CommonMark, without extensions and heuristics.
+    ///
+    /// GitHub alerts, footnotes, tables, strikethrough, task list items, front
+    /// matter, math and definition lists are not recognized, and the heuristics
+    /// disabled by [`with_strict_commonmark`](Self::with_strict_commonmark) are
+    /// turned off.
+    pub fn commonmark() -> Self {
+        Self {
+            allow_task_list_items: false,
+            block_github_alert_behavior: ElementBehavior::Ignore,
+            block_footnote_definition_behavior: ElementBehavior::Ignore,
+            block_table_behavior: ElementBehavior::Ignore,
+            block_front_matter_behavior: ElementBehavior::Ignore,
+            block_math_behavior: ElementBehavior::Ignore,
+            block_definition_list_behavior: ElementBehavior::Ignore,
+            inline_footnote_reference_behavior: ElementBehavior::Ignore,
+            inline_strikethrough_behavior: ElementBehavior::Ignore,
+            inline_math_behavior: ElementBehavior::Ignore,
+            ..Self::default()
+        }
+        .with_strict_commonmark()
+    }
+
+    /// Markdown as rendered by GitHub: CommonMark with tables, strikethrough and
+    /// task list items from the GFM specification, plus footnotes and GitHub
+    /// alerts.
+    ///
+    /// Like [`commonmark`](Self::commonmark), no heuristics are applied. The
+    /// default configuration is this dialect plus the heuristics.
+    pub fn gfm() -> Self {
+        Self {
+            allow_task_list_items: true,
+            block_github_alert_behavior: ElementBehavior::Parse,
+            block_footnote_definition_behavior: ElementBehavior::Parse,
+            block_table_behavior: ElementBehavior::Parse,
+            inline_footnote_reference_behavior: ElementBehavior::Parse,
+            inline_strikethrough_behavior: ElementBehavior::Parse,
+            ..Self::commonmark()
+        }
+    }
+
+    /// Every syntax the parser knows: [`gfm`](Self::gfm) plus front matter,
+    /// math and definition lists, with the heuristics of the default
+    /// configuration.
+    pub fn extended() -> Self {
+        Self {
+            block_front_matter_behavior: ElementBehavior::Parse,
+            block_math_behavior: ElementBehavior::Parse,
+            block_definition_list_behavior: ElementBehavior::Parse,
+            inline_math_behavior: ElementBehavior::Parse,
+            inline_environment_variable_behavior: ElementBehavior::Parse,
+            ..Self::gfm()
+        }
+    }
+
     /// Enable the parser to allow headings without a space after the hash marks.
     pub fn with_allow_no_space_in_headings(self) -> Self {
         Self {
@@ -169,6 +263,16 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Enable or disable task list items (`- [ ] todo`, `- [x] done`).
+    ///
+    /// When disabled, the checkbox is kept as the text of the item.
+    pub fn with_allow_task_list_items(self, allow: bool) -> Self {
+        Self {
+            allow_task_list_items: allow,
+            ..self
+        }
+    }
+
     /// Set a custom map of HTML entities.
     pub fn with_html_entities_map(
         self,
@@ -306,6 +410,49 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Set the behavior of the parser when encountering front matter.
+    ///
+    /// Front matter is a YAML (`---`) or TOML (`+++`) fenced block at the very
+    /// beginning of the document. It is not recognized by default
+    /// ([`ElementBehavior::Ignore`]), so a leading `---` is parsed as a thematic
+    /// break; use [`ElementBehavior::Parse`] to enable it.
+    pub fn with_block_front_matter_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_front_matter_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering display math.
+    ///
+    /// Display math is a `$$` fenced block, or a single line wrapped in `$$`.
+    /// It is not recognized by default ([`ElementBehavior::Ignore`]).
+    pub fn with_block_math_behavior(self, behavior: ElementBehavior<crate::ast::Block>) -> Self {
+        Self {
+            block_math_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering definition lists.
+    ///
+    /// A definition list item is a single-line term followed by one or more
+    /// definitions starting with `: `. Continuation lines of a definition are
+    /// indented to the width of its marker. Definition lists are not
+    /// recognized by default ([`ElementBehavior::Ignore`]).
+    pub fn with_block_definition_list_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Block>,
+    ) -> Self {
+        Self {
+            block_definition_list_behavior: behavior,
+            ..self
+        }
+    }
+
     /// Set the behavior of the parser when encountering inline autolinks.
     pub fn with_inline_autolink_behavior(
         self,
@@ -407,6 +554,51 @@ impl MarkdownParserConfig {
         }
     }
 
+    /// Set the behavior of the parser when encountering inline math.
+    ///
+    /// Inline math is text between single `$` signs. The opening `$` must not be
+    /// followed by whitespace, and the closing `$` must not be preceded by
+    /// whitespace or followed by a digit, so `$5 and $10` stays text. It is not
+    /// recognized by default ([`ElementBehavior::Ignore`]).
+    pub fn with_inline_math_behavior(self, behavior: ElementBehavior<crate::ast::Inline>) -> Self {
+        Self {
+            inline_math_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Set the behavior of the parser when encountering identifiers with underscores.
+    ///
+    /// Words like `PKG_CONFIG_PATH` or `snake_case_name` are consumed as plain
+    /// text before emphasis is tried, so their underscores never open or close
+    /// emphasis. This is a heuristic on top of CommonMark, which already keeps
+    /// most intraword underscores literal; with [`ElementBehavior::Ignore`] only
+    /// the rules of the spec apply.
+    pub fn with_inline_environment_variable_behavior(
+        self,
+        behavior: ElementBehavior<crate::ast::Inline>,
+    ) -> Self {
+        Self {
+            inline_environment_variable_behavior: behavior,
+            ..self
+        }
+    }
+
+    /// Disable the heuristics that are not part of the CommonMark specification.
+    ///
+    /// This turns off headings without a space after the hash marks and the
+    /// recognition of identifiers with underscores (see
+    /// [`with_inline_environment_variable_behavior`](Self::with_inline_environment_variable_behavior)).
+    /// Syntax extensions such as tables, footnotes or GitHub alerts are not
+    /// affected and can be disabled through their own behaviors.
+    pub fn with_strict_commonmark(self) -> Self {
+        Self {
+            allow_no_space_in_headings: false,
+            inline_environment_variable_behavior: ElementBehavior::Ignore,
+            ..self
+        }
+    }
+
     /// Set a custom parser for blocks.
     pub fn with_custom_block_parser(self, parser: CustomBlockParserFn) -> Self {
         Self {
@@ -423,3 +615,63 @@ impl MarkdownParserConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Block, Inline, TaskState};
+    use crate::parser::{parse_markdown, MarkdownParserState};
+
+    const INPUT: &str = "- [x] done\n\n| a |\n|---|\n| b |\n\n~~old~~ $x$\n\nTerm\n: Definition";
+
+    fn parse(config: MarkdownParserConfig) -> Vec<Block> {
+        parse_markdown(MarkdownParserState::with_config(config), INPUT)
+            .unwrap()
+            .blocks
+    }
+
+    fn task(blocks: &[Block]) -> Option<TaskState> {
+        match &blocks[0] {
+            Block::List(list) => list.items[0].task,
+            other => panic!("expected a list, got {other:?}"),
+        }
+    }
+
+    fn has_strikethrough(blocks: &[Block]) -> bool {
+        matches!(&blocks[2], Block::Paragraph(p) if matches!(p[0], Inline::Strikethrough(_)))
+    }
+
+    fn has_math(blocks: &[Block]) -> bool {
+        matches!(&blocks[2], Block::Paragraph(p) if p.iter().any(|i| matches!(i, Inline::Math(_))))
+    }
+
+    #[test]
+    fn commonmark_preset() {
+        let blocks = parse(MarkdownParserConfig::commonmark());
+        assert_eq!(task(&blocks), None);
+        assert!(matches!(blocks[1], Block::Paragraph(_)));
+        assert!(!has_strikethrough(&blocks));
+        assert!(!has_math(&blocks));
+        assert!(matches!(blocks[3], Block::Paragraph(_)));
+    }
+
+    #[test]
+    fn gfm_preset() {
+        let blocks = parse(MarkdownParserConfig::gfm());
+        assert_eq!(task(&blocks), Some(TaskState::Complete));
+        assert!(matches!(blocks[1], Block::Table(_)));
+        assert!(has_strikethrough(&blocks));
+        assert!(!has_math(&blocks));
+        assert!(matches!(blocks[3], Block::Paragraph(_)));
+    }
+
+    #[test]
+    fn extended_preset() {
+        let blocks = parse(MarkdownParserConfig::extended());
+        assert_eq!(task(&blocks), Some(TaskState::Complete));
+        assert!(matches!(blocks[1], Block::Table(_)));
+        assert!(has_strikethrough(&blocks));
+        assert!(has_math(&blocks));
+        assert!(matches!(blocks[3], Block::DefinitionList(_)));
+    }
+}
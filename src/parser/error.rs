@@ -0,0 +1,370 @@
+use crate::ast::Position;
+use std::fmt;
+
+/// Maximum number of characters of the input quoted in error messages.
+const FOUND_SNIPPET_LEN: usize = 40;
+
+/// Kind of block found at the position where parsing failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlockKind {
+    /// Ordinary paragraph
+    Paragraph,
+    /// ATX (`# Heading`) heading
+    Heading,
+    /// Thematic break (horizontal rule)
+    ThematicBreak,
+    /// Block quote
+    BlockQuote,
+    /// GitHub alert (`> [!NOTE]`)
+    GitHubAlert,
+    /// List (bullet or ordered)
+    List,
+    /// Fenced or indented code block
+    CodeBlock,
+    /// Raw HTML block
+    HtmlBlock,
+    /// Link reference definition
+    Definition,
+    /// Footnote definition
+    FootnoteDefinition,
+    /// Table
+    Table,
+    /// YAML or TOML front matter
+    FrontMatter,
+    /// Display math (`$$`)
+    Math,
+    /// Definition list (`: definition`)
+    DefinitionList,
+}
+
+impl BlockKind {
+    /// Guess the kind of block `line` starts, judging by its leading marker.
+    fn detect(line: &str) -> Option<Self> {
+        if line.trim().is_empty() {
+            return None;
+        }
+        if line.starts_with("    ") || line.starts_with('\t') {
+            return Some(BlockKind::CodeBlock);
+        }
+
+        let line = line.trim_start_matches(' ');
+        let first = line.chars().next()?;
+        let after_marker = |marker_len: usize| {
+            line[marker_len..]
+                .chars()
+                .next()
+                .is_none_or(|c| c == ' ' || c == '\t')
+        };
+
+        let kind = match first {
+            '`' | '~' if line.starts_with("```") || line.starts_with("~~~") => BlockKind::CodeBlock,
+            '#' => BlockKind::Heading,
+            '$' if line.starts_with("$$") => BlockKind::Math,
+            ':' if after_marker(1) => BlockKind::DefinitionList,
+            '>' if line[1..].trim_start().starts_with("[!") => BlockKind::GitHubAlert,
+            '>' => BlockKind::BlockQuote,
+            '[' if line.starts_with("[^") => BlockKind::FootnoteDefinition,
+            '[' => BlockKind::Definition,
+            '<' => BlockKind::HtmlBlock,
+            '|' => BlockKind::Table,
+            '-' | '*' | '_' if is_thematic_break(line, first) => BlockKind::ThematicBreak,
+            '-' | '*' | '+' if after_marker(1) => BlockKind::List,
+            '0'..='9' => {
+                let digits = line.chars().take_while(char::is_ascii_digit).count();
+                match line[digits..].chars().next() {
+                    Some('.') | Some(')') if after_marker(digits + 1) => BlockKind::List,
+                    _ => BlockKind::Paragraph,
+                }
+            }
+            _ => BlockKind::Paragraph,
+        };
+        Some(kind)
+    }
+}
+
+/// Whether the line at `line_start` is a part of front matter at the beginning
+/// of `input`, including the fences
+fn in_front_matter(input: &str, line_start: usize) -> bool {
+    let mut lines = input.split_inclusive('\n');
+    let Some(first) = lines.next() else {
+        return false;
+    };
+    let fence = first.trim_end();
+    if fence != "---" && fence != "+++" {
+        return false;
+    }
+
+    let mut end = first.len();
+    for line in lines {
+        end += line.len();
+        let line = line.trim_end();
+        if line == fence || (fence == "---" && line == "...") {
+            return line_start < end;
+        }
+    }
+    false
+}
+
+fn is_thematic_break(line: &str, marker: char) -> bool {
+    line.chars().all(|c| c == marker || c == ' ' || c == '\t')
+        && line.chars().filter(|&c| c == marker).count() >= 3
+}
+
+impl fmt::Display for BlockKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            BlockKind::Paragraph => "paragraph",
+            BlockKind::Heading => "heading",
+            BlockKind::ThematicBreak => "thematic break",
+            BlockKind::BlockQuote => "block quote",
+            BlockKind::GitHubAlert => "GitHub alert",
+            BlockKind::List => "list",
+            BlockKind::CodeBlock => "code block",
+            BlockKind::HtmlBlock => "HTML block",
+            BlockKind::Definition => "link definition",
+            BlockKind::FootnoteDefinition => "footnote definition",
+            BlockKind::Table => "table",
+            BlockKind::FrontMatter => "front matter block",
+            BlockKind::Math => "math block",
+            BlockKind::DefinitionList => "definition list",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Error returned when a Markdown document cannot be parsed.
+///
+/// The error points at the position in the input where no block parser
+/// could continue, and describes what was expected there and what was found.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::parser::config::{ElementBehavior, MarkdownParserConfig};
+/// use markdown_ppp::parser::{parse_markdown, BlockKind, MarkdownParserState};
+///
+/// // Without paragraphs, plain text cannot be parsed
+/// let config = MarkdownParserConfig::default()
+///     .with_block_heading_v2_behavior(ElementBehavior::Ignore)
+///     .with_block_paragraph_behavior(ElementBehavior::Ignore);
+/// let state = MarkdownParserState::with_config(config);
+///
+/// let err = parse_markdown(state, "# Title\n\nplain text").unwrap_err();
+/// assert_eq!(err.position().line, 3);
+/// assert_eq!(err.block_kind(), Some(BlockKind::Paragraph));
+/// assert_eq!(
+///     err.to_string(),
+///     "3:1: expected a block, found \"plain text\" (while parsing a paragraph)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    position: Position,
+    block_kind: Option<BlockKind>,
+    expected: String,
+    found: String,
+}
+
+impl ParseError {
+    /// Position of the error in the input
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Kind of block found at the error position, if any
+    pub fn block_kind(&self) -> Option<BlockKind> {
+        self.block_kind
+    }
+
+    /// Description of what the parser expected
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Description of the input found at the error position
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    pub(crate) fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        let (rest, expected) = match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => (e.input, describe_error_kind(e.code)),
+            nom::Err::Incomplete(_) => ("", "more input".to_owned()),
+        };
+
+        // Errors raised inside containers may point into re-parsed text, so
+        // only trust the remaining input if it is a part of `input`.
+        let base = input.as_ptr() as usize;
+        let ptr = rest.as_ptr() as usize;
+        let offset = if ptr >= base && ptr + rest.len() <= base + input.len() {
+            ptr - base
+        } else {
+            input.len().saturating_sub(rest.len())
+        };
+        let position = Position::from_offset(input, offset);
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line = input[line_start..].lines().next().unwrap_or("");
+
+        let block_kind = if in_front_matter(input, line_start) {
+            Some(BlockKind::FrontMatter)
+        } else {
+            BlockKind::detect(line)
+        };
+
+        Self {
+            position,
+            block_kind,
+            expected,
+            found: describe_found(&input[offset..]),
+        }
+    }
+}
+
+fn describe_error_kind(kind: nom::error::ErrorKind) -> String {
+    use nom::error::ErrorKind;
+
+    match kind {
+        ErrorKind::Eof => "a block".to_owned(),
+        ErrorKind::Tag | ErrorKind::Char => "a block marker".to_owned(),
+        ErrorKind::Many0 | ErrorKind::Many1 => "input that makes progress".to_owned(),
+        other => other.description().to_lowercase(),
+    }
+}
+
+fn describe_found(rest: &str) -> String {
+    let line = rest.lines().next().unwrap_or("").trim_end();
+    if line.is_empty() {
+        return if rest.is_empty() {
+            "end of input".to_owned()
+        } else {
+            "an empty line".to_owned()
+        };
+    }
+
+    let mut snippet: String = line.chars().take(FOUND_SNIPPET_LEN).collect();
+    if line.chars().count() > FOUND_SNIPPET_LEN {
+        snippet.push('…');
+    }
+    format!("{snippet:?}")
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.position.line, self.position.column, self.expected, self.found
+        )?;
+        if let Some(kind) = self.block_kind {
+            let article = if kind == BlockKind::HtmlBlock {
+                "an"
+            } else {
+                "a"
+            };
+            write!(f, " (while parsing {article} {kind})")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+    use crate::parser::{parse_markdown, MarkdownParserState};
+
+    fn parse_without_paragraphs(input: &str) -> ParseError {
+        let config = MarkdownParserConfig::default()
+            .with_block_heading_v2_behavior(ElementBehavior::Ignore)
+            .with_block_paragraph_behavior(ElementBehavior::Ignore);
+        parse_markdown(MarkdownParserState::with_config(config), input).unwrap_err()
+    }
+
+    #[test]
+    fn reports_position_and_block_kind() {
+        let err = parse_without_paragraphs("# Title\n\n- item\n\nплохо *text*\n");
+        assert_eq!(err.position().line, 5);
+        assert_eq!(err.position().column, 1);
+        assert_eq!(err.position().offset, 17);
+        assert_eq!(err.block_kind(), Some(BlockKind::Paragraph));
+        assert_eq!(err.expected(), "a block");
+        assert_eq!(err.found(), "\"плохо *text*\"");
+    }
+
+    #[test]
+    fn long_lines_are_shortened() {
+        let err = parse_without_paragraphs(&"x".repeat(100));
+        assert_eq!(
+            err.found(),
+            format!("\"{}…\"", "x".repeat(FOUND_SNIPPET_LEN))
+        );
+    }
+
+    #[test]
+    fn display() {
+        let err = parse_without_paragraphs("---\nhello");
+        assert_eq!(
+            err.to_string(),
+            "2:1: expected a block, found \"hello\" (while parsing a paragraph)"
+        );
+
+        let err = ParseError {
+            position: Position::default(),
+            block_kind: Some(BlockKind::HtmlBlock),
+            expected: "a block".to_owned(),
+            found: "end of input".to_owned(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "1:1: expected a block, found end of input (while parsing an HTML block)"
+        );
+    }
+
+    #[test]
+    fn errors_in_front_matter() {
+        // Without front matter support the fence is a thematic break
+        let err = parse_without_paragraphs("---\ntitle: Hello\n---\n\n# Doc");
+        assert_eq!(err.position().line, 2);
+        assert_eq!(err.block_kind(), Some(BlockKind::FrontMatter));
+        assert_eq!(
+            err.to_string(),
+            "2:1: expected a block, found \"title: Hello\" (while parsing a front matter block)"
+        );
+
+        let err = parse_without_paragraphs("---\n\ntitle: Hello");
+        assert_eq!(err.block_kind(), Some(BlockKind::Paragraph));
+    }
+
+    #[test]
+    fn detect_block_kind() {
+        let cases = [
+            ("plain", Some(BlockKind::Paragraph)),
+            ("## heading", Some(BlockKind::Heading)),
+            ("* * *", Some(BlockKind::ThematicBreak)),
+            ("> quote", Some(BlockKind::BlockQuote)),
+            ("> [!NOTE]", Some(BlockKind::GitHubAlert)),
+            ("- item", Some(BlockKind::List)),
+            ("12) item", Some(BlockKind::List)),
+            ("12 apples", Some(BlockKind::Paragraph)),
+            ("-not a list", Some(BlockKind::Paragraph)),
+            ("```rust", Some(BlockKind::CodeBlock)),
+            ("    indented", Some(BlockKind::CodeBlock)),
+            ("<div>", Some(BlockKind::HtmlBlock)),
+            ("[label]: /url", Some(BlockKind::Definition)),
+            ("[^1]: note", Some(BlockKind::FootnoteDefinition)),
+            ("| a | b |", Some(BlockKind::Table)),
+            ("$$ x^2 $$", Some(BlockKind::Math)),
+            ("$5", Some(BlockKind::Paragraph)),
+            (": definition", Some(BlockKind::DefinitionList)),
+            (":-)", Some(BlockKind::Paragraph)),
+            ("   ", None),
+        ];
+        for (line, expected) in cases {
+            assert_eq!(BlockKind::detect(line), expected, "{line:?}");
+        }
+    }
+}
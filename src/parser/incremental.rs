@@ -0,0 +1,415 @@
+//! Incremental reparsing for editors.
+//!
+//! [`reparse_markdown`] updates a document parsed with
+//! [`parse_markdown_with_spans`] after an edit. Top-level blocks are parsed one
+//! after another, and every block only depends on the text from its start on.
+//! So parsing can start at an old block in front of the edit, and can stop as
+//! soon as a block would start where an old block behind the edit started: from
+//! there on, the text and thus the blocks are the same as before, only moved.
+//!
+//! Parsing starts one block before the first block touched by the edit, since
+//! the edit may merge a block with its predecessor (a removed blank line, a new
+//! setext underline), or at an earlier block that looked for a closing line
+//! behind the edit (an unclosed code fence). Reference links are not resolved
+//! while parsing, so blocks do not depend on link definitions elsewhere in the
+//! document.
+
+use crate::ast::map_data_visitor::{ClosureMapDataVisitor, MapDataVisitor};
+use crate::ast::{generic, Document, Position, Span};
+use crate::parser::span::{attach_spans, SpanContext};
+use crate::parser::util::many_empty_lines0;
+use crate::parser::{parse_markdown_with_spans, MarkdownParserState, ParseError};
+use nom::{combinator::opt, Parser};
+use std::ops::Range;
+use std::rc::Rc;
+
+/// Result of [`reparse_markdown`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reparsed {
+    /// The document after the edit, with spans against the new text
+    pub document: generic::Document<Span>,
+
+    /// Indices in `document.blocks` of the blocks that were parsed again and
+    /// differ from the previous document. All other blocks are unchanged,
+    /// apart from their spans.
+    pub changed_blocks: Vec<usize>,
+}
+
+/// Update a document after an edit, parsing only the affected top-level
+/// blocks.
+///
+/// `previous` must have been parsed with [`parse_markdown_with_spans`] and the
+/// same configuration as `state`. `edit` is the byte range of the previous text
+/// that was replaced, and `text` is the complete text after the edit. The
+/// result is the same document that `parse_markdown_with_spans` returns for
+/// `text`.
+///
+/// The whole text is parsed again if `edit` does not fit the previous
+/// document, or if its blocks have no usable spans (e.g. blocks produced by
+/// custom parsers or `ElementBehavior::FlatMap`).
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::parser::{parse_markdown_with_spans, reparse_markdown, MarkdownParserState};
+///
+/// let text = "# Title\n\nFirst paragraph.\n\nSecond paragraph.\n";
+/// let doc = parse_markdown_with_spans(MarkdownParserState::new(), text).unwrap();
+///
+/// // Replace "First" with "1st"
+/// let new_text = "# Title\n\n1st paragraph.\n\nSecond paragraph.\n";
+/// let reparsed = reparse_markdown(MarkdownParserState::new(), &doc, 9..14, new_text).unwrap();
+///
+/// assert_eq!(reparsed.changed_blocks, vec![1]);
+/// assert_eq!(
+///     reparsed.document,
+///     parse_markdown_with_spans(MarkdownParserState::new(), new_text).unwrap(),
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_markdown`](crate::parser::parse_markdown).
+pub fn reparse_markdown(
+    state: MarkdownParserState,
+    previous: &generic::Document<Span>,
+    edit: Range<usize>,
+    text: &str,
+) -> Result<Reparsed, ParseError> {
+    let old_len = previous.user_data.end.offset;
+    let spans: Vec<Span> = previous.blocks.iter().map(|b| *b.user_data()).collect();
+    let fits = edit.start <= edit.end
+        && edit.end <= old_len
+        && text.len() + (edit.end - edit.start) >= old_len
+        && spans
+            .iter()
+            .all(|span| span.start.offset <= span.end.offset)
+        && spans
+            .windows(2)
+            .all(|pair| pair[0].end.offset <= pair[1].start.offset)
+        && spans.last().is_none_or(|span| span.end.offset <= old_len);
+    if !fits {
+        return parse_all(state, text);
+    }
+
+    let delta = text.len() as isize - old_len as isize;
+    let edit_end = (edit.end as isize + delta) as usize;
+
+    // The block before the first block touched by the edit, or an earlier
+    // block that may have looked ahead into the edit
+    let touched = spans
+        .iter()
+        .position(|span| span.end.offset >= edit.start)
+        .unwrap_or(spans.len());
+    let unclosed_front_matter = (text.starts_with("---") || text.starts_with("+++"))
+        && !matches!(
+            previous.blocks.first(),
+            Some(generic::Block::FrontMatter { .. })
+        );
+    let first = previous.blocks[..touched]
+        .iter()
+        .zip(&spans)
+        .position(|(block, span)| {
+            unclosed_front_matter
+                || may_look_ahead(block, &text[span.start.offset..span.end.offset])
+        })
+        .unwrap_or(touched.saturating_sub(1));
+    let start = match first {
+        0 => 0,
+        _ => line_start(text, spans[first].start.offset),
+    };
+    if first > 0 && start < spans[first - 1].end.offset {
+        return parse_all(state, text);
+    }
+
+    let Some((region, reused)) = parse_region(&state, &spans, text, start, edit_end, delta) else {
+        // Let the full parse report the error against the whole text
+        return parse_all(state, text);
+    };
+
+    let start_line = Position::from_offset(text, start).line as isize - 1;
+    let region: Vec<_> = region
+        .into_iter()
+        .map(|block| move_block(block, start as isize, start_line))
+        .collect();
+    let changed_blocks = region
+        .iter()
+        .enumerate()
+        .filter(|&(i, block)| previous.blocks.get(first + i) != Some(block))
+        .map(|(i, _)| first + i)
+        .collect();
+
+    let mut blocks = previous.blocks[..first].to_vec();
+    blocks.extend(region);
+    if let Some(reused) = reused {
+        let moved_start =
+            Position::from_offset(text, (spans[reused].start.offset as isize + delta) as usize);
+        let line_delta = moved_start.line as isize - spans[reused].start.line as isize;
+        blocks.extend(
+            previous.blocks[reused..]
+                .iter()
+                .map(|block| move_block(block.clone(), delta, line_delta)),
+        );
+    }
+
+    Ok(Reparsed {
+        document: generic::Document {
+            blocks,
+            user_data: document_span(text),
+        },
+        changed_blocks,
+    })
+}
+
+fn parse_all(state: MarkdownParserState, text: &str) -> Result<Reparsed, ParseError> {
+    let document = parse_markdown_with_spans(state, text)?;
+    Ok(Reparsed {
+        changed_blocks: (0..document.blocks.len()).collect(),
+        document,
+    })
+}
+
+/// Parse the blocks of `text` from `start`, until a block would start where a
+/// block of the previous document behind the edit started.
+///
+/// Returns the parsed blocks, with spans relative to `start`, and the index of
+/// the previous block to continue with. `None` if the text does not parse.
+fn parse_region(
+    state: &MarkdownParserState,
+    spans: &[Span],
+    text: &str,
+    start: usize,
+    edit_end: usize,
+    delta: isize,
+) -> Option<(Vec<generic::Block<Span>>, Option<usize>)> {
+    let input = &text[start..];
+    let span_context = SpanContext::new(input);
+    let state = Rc::new(MarkdownParserState {
+        config: state.config.clone(),
+        is_nested_block_context: false,
+        spans: Some(span_context.clone()),
+    });
+    // Start of a previous block in the new text. Blocks in front of the edit
+    // may end up in front of the text
+    let moved = |span: &Span| span.start.offset as isize + delta;
+
+    let mut rest = input;
+    let mut blocks = Vec::new();
+    if start == 0 {
+        let (tail, front_matter) = opt(crate::parser::blocks::front_matter(state.clone()))
+            .parse(rest)
+            .ok()?;
+        rest = tail;
+        blocks.extend(front_matter.into_iter().flatten());
+    }
+
+    // Previous blocks that may be reused, in order
+    let mut candidate = spans.partition_point(|span| moved(span) < edit_end as isize);
+    let reused = loop {
+        // Fails at the end of the text, as `eof` consumes nothing
+        let tail = many_empty_lines0(rest).map_or(rest, |(tail, _)| tail);
+        let offset = text.len() - tail.len();
+        while spans
+            .get(candidate)
+            .is_some_and(|span| moved(span) < offset as isize)
+        {
+            candidate += 1;
+        }
+        if spans
+            .get(candidate)
+            .is_some_and(|span| moved(span) == offset as isize)
+            && line_start(text, offset) >= edit_end
+        {
+            break Some(candidate);
+        }
+
+        match crate::parser::blocks::block(state.clone()).parse(rest) {
+            Ok((tail, parsed)) if tail.len() < rest.len() => {
+                rest = tail;
+                blocks.extend(parsed);
+            }
+            Ok(_) => return None,
+            Err(_) => {
+                if !rest.chars().all(|c| matches!(c, ' ' | '\t' | '\n' | '\r')) {
+                    return None;
+                }
+                break None;
+            }
+        }
+    };
+
+    let parsed = &input[..input.len() - rest.len()];
+    drop(state);
+    let document = attach_spans(parsed, Document { blocks }, span_context.into_nodes());
+    Some((document.blocks, reused))
+}
+
+fn move_block(
+    block: generic::Block<Span>,
+    delta: isize,
+    line_delta: isize,
+) -> generic::Block<Span> {
+    let move_position = |position: Position| Position {
+        offset: position.offset.saturating_add_signed(delta),
+        line: position.line.saturating_add_signed(line_delta),
+        column: position.column,
+    };
+    ClosureMapDataVisitor::new(|span: Span| Span {
+        start: move_position(span.start),
+        end: move_position(span.end),
+    })
+    .visit_block(block)
+}
+
+/// Whether text added behind a block may change it.
+///
+/// Code fences, math and HTML blocks look for their closing line up to the end
+/// of the text, and are parsed as something else if there is none. Likewise a
+/// list or block quote that fails to parse further down ends up as a
+/// paragraph. So a block depends on the text behind it if it contains a line
+/// opening one of the former, or if it is a paragraph starting like one of the
+/// latter.
+fn may_look_ahead(block: &generic::Block<Span>, source: &str) -> bool {
+    match block {
+        generic::Block::CodeBlock(_)
+        | generic::Block::Math { .. }
+        | generic::Block::HtmlBlock { .. } => return false,
+        generic::Block::Paragraph { .. } => {
+            let line = source.trim_start();
+            let digits = line.trim_start_matches(|c: char| c.is_ascii_digit());
+            let marker = if digits.len() < line.len() {
+                digits.strip_prefix(['.', ')'])
+            } else {
+                line.strip_prefix(['-', '*', '+'])
+            };
+            if line.starts_with('>')
+                || marker.is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t', '\n']))
+            {
+                return true;
+            }
+        }
+        _ => {}
+    }
+    source.lines().any(|line| {
+        // Strip block quote and list markers
+        let line = line.trim_start_matches(|c: char| {
+            c.is_whitespace()
+                || c.is_ascii_digit()
+                || matches!(c, '>' | '-' | '*' | '+' | '.' | ')')
+        });
+        let lowercase = line.get(..7).unwrap_or(line).to_ascii_lowercase();
+        ["```", "~~~", "$$", "<!", "<?", "<script", "<pre", "<style"]
+            .iter()
+            .any(|opening| lowercase.starts_with(opening))
+    })
+}
+
+fn line_start(text: &str, offset: usize) -> usize {
+    let offset = offset.min(text.len());
+    text.as_bytes()[..offset]
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1)
+}
+
+fn document_span(text: &str) -> Span {
+    Span {
+        start: Position::from_offset(text, 0),
+        end: Position::from_offset(text, text.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Replace `edit` in `text` with `replacement`, check that the reparsed
+    /// document equals a full parse and return the changed blocks.
+    fn reparse(text: &str, edit: Range<usize>, replacement: &str) -> Vec<usize> {
+        let previous = parse_markdown_with_spans(MarkdownParserState::new(), text).unwrap();
+        let new_text = format!("{}{replacement}{}", &text[..edit.start], &text[edit.end..]);
+        let reparsed =
+            reparse_markdown(MarkdownParserState::new(), &previous, edit, &new_text).unwrap();
+        assert_eq!(
+            reparsed.document,
+            parse_markdown_with_spans(MarkdownParserState::new(), &new_text).unwrap()
+        );
+        reparsed.changed_blocks
+    }
+
+    const TEXT: &str = "# Title\n\nFirst\nparagraph.\n\n- a\n- b\n\nLast paragraph.\n";
+
+    #[test]
+    fn edit_inside_block() {
+        assert_eq!(reparse(TEXT, 9..14, "1st"), vec![1]);
+        assert_eq!(reparse(TEXT, 29..29, "\n  more"), vec![2]);
+    }
+
+    #[test]
+    fn inserted_lines_move_following_blocks() {
+        assert_eq!(reparse(TEXT, 9..9, "New\n\n"), vec![1]);
+    }
+
+    #[test]
+    fn removed_blank_line_merges_blocks() {
+        assert_eq!(reparse(TEXT, 8..9, ""), Vec::<usize>::new());
+        assert_eq!(reparse(TEXT, 35..36, ""), vec![2]);
+    }
+
+    #[test]
+    fn opened_code_fence_takes_following_blocks() {
+        let text = "a\n\nb\n\nc\n\n```\nd\n```\n";
+        assert_eq!(reparse(text, 3..3, "```\n"), vec![1, 2]);
+    }
+
+    #[test]
+    fn closing_fence_changes_earlier_block() {
+        let text = "```\na\n\nb\n\nc\n";
+        assert_eq!(reparse(text, text.len()..text.len(), "```\n"), vec![0]);
+    }
+
+    #[test]
+    fn setext_underline_changes_previous_block() {
+        assert_eq!(reparse(TEXT, 25..25, "===\n"), vec![1]);
+    }
+
+    #[test]
+    fn edit_at_the_end() {
+        assert_eq!(reparse(TEXT, TEXT.len()..TEXT.len(), "\n> quote"), vec![4]);
+        assert_eq!(reparse(TEXT, 0..TEXT.len(), ""), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn front_matter_only_at_start() {
+        let config = crate::parser::config::MarkdownParserConfig::default()
+            .with_block_front_matter_behavior(crate::parser::config::ElementBehavior::Parse);
+        let text = "---\na: 1\n---\n\ntext\n\n---\n\nmore\n\n---\n";
+        let previous =
+            parse_markdown_with_spans(MarkdownParserState::with_config(config.clone()), text)
+                .unwrap();
+        let new_text = text.replace("more", "b: 2");
+        let reparsed = reparse_markdown(
+            MarkdownParserState::with_config(config.clone()),
+            &previous,
+            22..26,
+            &new_text,
+        )
+        .unwrap();
+        assert_eq!(
+            reparsed.document,
+            parse_markdown_with_spans(MarkdownParserState::with_config(config), &new_text).unwrap()
+        );
+    }
+
+    #[test]
+    fn edit_outside_the_document_parses_everything() {
+        let previous = parse_markdown_with_spans(MarkdownParserState::new(), TEXT).unwrap();
+        let reparsed =
+            reparse_markdown(MarkdownParserState::new(), &previous, 100..120, "Other").unwrap();
+        assert_eq!(reparsed.changed_blocks, vec![0]);
+        assert_eq!(
+            reparsed.document,
+            parse_markdown_with_spans(MarkdownParserState::new(), "Other").unwrap()
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use crate::ast::Inline;
+use crate::ast::{Emphasis, EmphasisDelimiter, Inline};
+use crate::parser::span::{rollback, spanned};
 use crate::parser::MarkdownParserState;
 use nom::{
     branch::alt,
@@ -17,47 +18,35 @@ pub(crate) fn emphasis(
     move |input: &str| {
         alt((
             map(
-                alt((
-                    delimited(
-                        open_tag("***"),
-                        emphasis_content(state.clone(), close_tag("***")),
-                        close_tag("***"),
-                    ),
-                    delimited(
-                        open_tag("___"),
-                        emphasis_content(state.clone(), close_tag("___")),
-                        close_tag("___"),
-                    ),
-                )),
-                |inner| Inline::Strong(vec![Inline::Emphasis(inner)]),
+                // Record a span for the inner emphasis as well
+                spanned(
+                    &state,
+                    false,
+                    |_| 1,
+                    alt((
+                        delimited_content(&state, "***", EmphasisDelimiter::Asterisk),
+                        delimited_content(&state, "___", EmphasisDelimiter::Underscore),
+                    )),
+                ),
+                |inner| {
+                    let delimiter = inner.delimiter;
+                    Inline::Strong(Emphasis {
+                        delimiter,
+                        content: vec![Inline::Emphasis(inner)],
+                    })
+                },
             ),
             map(
                 alt((
-                    delimited(
-                        open_tag("**"),
-                        emphasis_content(state.clone(), close_tag("**")),
-                        close_tag("**"),
-                    ),
-                    delimited(
-                        open_tag("__"),
-                        emphasis_content(state.clone(), close_tag("__")),
-                        close_tag("__"),
-                    ),
+                    delimited_content(&state, "**", EmphasisDelimiter::Asterisk),
+                    delimited_content(&state, "__", EmphasisDelimiter::Underscore),
                 )),
                 Inline::Strong,
             ),
             map(
                 alt((
-                    delimited(
-                        open_tag("*"),
-                        emphasis_content(state.clone(), close_tag("*")),
-                        close_tag("*"),
-                    ),
-                    delimited(
-                        open_tag("_"),
-                        emphasis_content(state.clone(), close_tag("_")),
-                        close_tag("_"),
-                    ),
+                    delimited_content(&state, "*", EmphasisDelimiter::Asterisk),
+                    delimited_content(&state, "_", EmphasisDelimiter::Underscore),
                 )),
                 Inline::Emphasis,
             ),
@@ -66,6 +55,25 @@ pub(crate) fn emphasis(
     }
 }
 
+/// Content between `marker`s
+fn delimited_content<'a>(
+    state: &Rc<MarkdownParserState>,
+    marker: &'static str,
+    delimiter: EmphasisDelimiter,
+) -> impl Parser<&'a str, Output = Emphasis, Error = nom::error::Error<&'a str>> {
+    map(
+        rollback(
+            state,
+            delimited(
+                open_tag(marker),
+                emphasis_content(state.clone(), close_tag(marker)),
+                close_tag(marker),
+            ),
+        ),
+        move |content| Emphasis { delimiter, content },
+    )
+}
+
 fn emphasis_content<'a, P>(
     state: Rc<MarkdownParserState>,
     mut close_tag: P,
@@ -0,0 +1,31 @@
+use crate::ast::Inline;
+use nom::{
+    branch::alt,
+    bytes::complete::take_while1,
+    character::complete::{anychar, char, none_of, satisfy},
+    combinator::{not, peek, recognize, verify},
+    multi::many1,
+    sequence::preceded,
+    IResult, Parser,
+};
+
+pub(crate) fn math(input: &str) -> IResult<&str, Inline> {
+    let (input, _) = char('$').parse(input)?;
+    let (input, content) = verify(
+        recognize(many1(alt((
+            recognize(preceded(char('\\'), anychar)),
+            recognize(none_of("$\\")),
+        )))),
+        |s: &str| !s.starts_with(char::is_whitespace) && !s.ends_with(char::is_whitespace),
+    )
+    .parse(input)?;
+    // `$20 and $30` is not math
+    let (input, _) = (char('$'), peek(not(satisfy(|c| c.is_ascii_digit())))).parse(input)?;
+
+    Ok((input, Inline::Math(content.to_owned())))
+}
+
+/// Run of two or more `$`, which never starts inline math
+pub(crate) fn dollar_run(input: &str) -> IResult<&str, &str> {
+    verify(take_while1(|c| c == '$'), |s: &str| s.len() > 1).parse(input)
+}
@@ -7,6 +7,7 @@ mod hard_newline;
 mod html_entity;
 mod image;
 mod inline_link;
+mod math;
 mod reference_link;
 mod strikethrough;
 mod text;
@@ -24,6 +25,7 @@ use nom::{
 };
 use std::rc::Rc;
 
+use super::span::{merge_text_spans, rollback, spanned};
 use super::util::conditional_inline;
 
 /// Merges consecutive Text elements into a single Text element
@@ -63,8 +65,9 @@ pub(crate) fn inline_many0<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
     move |input: &'a str| {
-        let (input, list_of_lists) = many0(inline(state.clone())).parse(input)?;
-        let r: Vec<_> = list_of_lists.into_iter().flatten().collect();
+        let flattened = many0(inline(state.clone()))
+            .map(|list_of_lists| list_of_lists.into_iter().flatten().collect::<Vec<_>>());
+        let (input, r) = merge_text_spans(&state, flattened).parse(input)?;
         let merged = merge_consecutive_text_elements(r);
         Ok((input, merged))
     }
@@ -74,8 +77,9 @@ pub(crate) fn inline_many1<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
     move |input: &'a str| {
-        let (input, list_of_lists) = many1(inline(state.clone())).parse(input)?;
-        let r: Vec<_> = list_of_lists.into_iter().flatten().collect();
+        let flattened = many1(inline(state.clone()))
+            .map(|list_of_lists| list_of_lists.into_iter().flatten().collect::<Vec<_>>());
+        let (input, r) = merge_text_spans(&state, flattened).parse(input)?;
         let merged = merge_consecutive_text_elements(r);
         Ok((input, merged))
     }
@@ -85,56 +89,101 @@ pub(crate) fn inline<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Inline>> {
     move |input: &'a str| {
-        alt((
-            conditional_inline(
-                state.config.inline_autolink_behavior.clone(),
-                map(crate::parser::inline::autolink::autolink, Inline::Autolink),
-            ),
-            conditional_inline(
-                state.config.inline_link_behavior.clone(),
-                map(
-                    crate::parser::inline::inline_link::inline_link(state.clone()),
-                    Inline::Link,
+        spanned(
+            &state,
+            false,
+            Vec::len,
+            alt((
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_autolink_behavior.clone(),
+                        map(crate::parser::inline::autolink::autolink, Inline::Autolink),
+                    ),
                 ),
-            ),
-            conditional_inline(
-                state.config.inline_footnote_reference_behavior.clone(),
-                crate::parser::inline::footnote_reference::footnote_reference,
-            ),
-            conditional_inline(
-                state.config.inline_reference_link_behavior.clone(),
-                crate::parser::inline::reference_link::reference_link(state.clone()),
-            ),
-            conditional_inline(
-                state.config.inline_hard_newline_behavior.clone(),
-                crate::parser::inline::hard_newline::hard_newline,
-            ),
-            conditional_inline(
-                state.config.inline_image_behavior.clone(),
-                crate::parser::inline::image::image(state.clone()),
-            ),
-            conditional_inline(
-                state.config.inline_code_span_behavior.clone(),
-                map(crate::parser::inline::code_span::code_span, Inline::Code),
-            ),
-            map(
-                crate::parser::inline::environment_variable::environment_variable,
-                |env_var| vec![env_var],
-            ),
-            conditional_inline(
-                state.config.inline_emphasis_behavior.clone(),
-                crate::parser::inline::emphasis::emphasis(state.clone()),
-            ),
-            conditional_inline(
-                state.config.inline_strikethrough_behavior.clone(),
-                crate::parser::inline::strikethrough::strikethrough(state.clone()),
-            ),
-            custom_parser(state.clone()),
-            conditional_inline(
-                state.config.inline_text_behavior.clone(),
-                crate::parser::inline::text::text(state.clone()),
-            ),
-        ))
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_link_behavior.clone(),
+                        map(
+                            crate::parser::inline::inline_link::inline_link(state.clone()),
+                            Inline::Link,
+                        ),
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_footnote_reference_behavior.clone(),
+                        crate::parser::inline::footnote_reference::footnote_reference,
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_reference_link_behavior.clone(),
+                        crate::parser::inline::reference_link::reference_link(state.clone()),
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_hard_newline_behavior.clone(),
+                        crate::parser::inline::hard_newline::hard_newline,
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_image_behavior.clone(),
+                        crate::parser::inline::image::image(state.clone()),
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_code_span_behavior.clone(),
+                        map(crate::parser::inline::code_span::code_span, Inline::Code),
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_math_behavior.clone(),
+                        crate::parser::inline::math::math,
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_environment_variable_behavior.clone(),
+                        crate::parser::inline::environment_variable::environment_variable,
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_emphasis_behavior.clone(),
+                        crate::parser::inline::emphasis::emphasis(state.clone()),
+                    ),
+                ),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_strikethrough_behavior.clone(),
+                        crate::parser::inline::strikethrough::strikethrough(state.clone()),
+                    ),
+                ),
+                rollback(&state, custom_parser(state.clone())),
+                rollback(
+                    &state,
+                    conditional_inline(
+                        state.config.inline_text_behavior.clone(),
+                        crate::parser::inline::text::text(state.clone()),
+                    ),
+                ),
+            )),
+        )
         .parse(input)
     }
 }
@@ -142,8 +191,7 @@ pub(crate) fn inline<'a>(
 fn custom_parser(state: Rc<MarkdownParserState>) -> impl FnMut(&str) -> IResult<&str, Vec<Inline>> {
     move |input: &str| {
         if let Some(custom_parser) = state.config.custom_inline_parser.as_ref() {
-            let mut p = (**custom_parser).borrow_mut();
-            (p.as_mut())(input)
+            custom_parser(input)
         } else {
             fail().parse(input)
         }
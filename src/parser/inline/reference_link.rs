@@ -1,5 +1,6 @@
 use crate::ast::{Inline, LinkReference};
 use crate::parser::link_util::link_label;
+use crate::parser::span::rollback;
 use crate::parser::MarkdownParserState;
 use nom::{branch::alt, bytes::complete::tag, sequence::terminated, IResult, Parser};
 use std::rc::Rc;
@@ -9,8 +10,8 @@ pub(crate) fn reference_link<'a>(
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
     move |input: &'a str| {
         alt((
-            reference_link_full(state.clone()),
-            reference_link_collapsed(state.clone()),
+            rollback(&state, reference_link_full(state.clone())),
+            rollback(&state, reference_link_collapsed(state.clone())),
             reference_link_shortcut(state.clone()),
         ))
         .parse(input)
@@ -17,8 +17,8 @@ fn assert_no_consecutive_text_elements(inlines: &[Inline]) {
     // Recursively check content of other elements
     for inline in inlines {
         match inline {
-            Inline::Emphasis(content)
-            | Inline::Strong(content)
+            Inline::Emphasis(Emphasis { content, .. })
+            | Inline::Strong(Emphasis { content, .. })
             | Inline::Strikethrough(content) => {
                 assert_no_consecutive_text_elements(content);
             }
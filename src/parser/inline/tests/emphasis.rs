@@ -7,9 +7,9 @@ fn emphasis1() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Emphasis(vec![
-                Inline::Text("foo bar".to_string())
-            ])])],
+            blocks: vec![Block::Paragraph(vec![Inline::Emphasis(
+                vec![Inline::Text("foo bar".to_string())].into()
+            )])],
         }
     );
 }
@@ -25,7 +25,8 @@ fn emphasis2() {
                 items: vec![ListItem {
                     task: None,
                     blocks: vec![Block::Paragraph(vec![Inline::Text("a *".to_owned())])]
-                }]
+                }],
+                tight: true,
             })]
         }
     );
@@ -39,7 +40,13 @@ fn emphasis3() {
         Document {
             blocks: vec![Block::Paragraph(vec![
                 Inline::Text("foo ".to_owned()),
-                Inline::Strong(vec![Inline::Emphasis(vec![Inline::Text("bar".to_owned())])])
+                Inline::Strong(Emphasis {
+                    delimiter: EmphasisDelimiter::Underscore,
+                    content: vec![Inline::Emphasis(Emphasis {
+                        delimiter: EmphasisDelimiter::Underscore,
+                        content: vec![Inline::Text("bar".to_owned())]
+                    })]
+                })
             ])]
         }
     );
@@ -51,11 +58,20 @@ fn emphasis4() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Strong(vec![
-                Inline::Text("foo ".to_owned()),
-                Inline::Strong(vec![Inline::Emphasis(vec![Inline::Text("bar".to_owned())])]),
-                Inline::Text(" baz".to_owned())
-            ])])]
+            blocks: vec![Block::Paragraph(vec![Inline::Strong(
+                vec![
+                    Inline::Text("foo ".to_owned()),
+                    Inline::Strong(Emphasis {
+                        delimiter: EmphasisDelimiter::Underscore,
+                        content: vec![Inline::Emphasis(Emphasis {
+                            delimiter: EmphasisDelimiter::Underscore,
+                            content: vec![Inline::Text("bar".to_owned())]
+                        })]
+                    }),
+                    Inline::Text(" baz".to_owned())
+                ]
+                .into()
+            )])]
         }
     );
 }
@@ -78,7 +94,10 @@ fn emphasis_with_underscores_in_words() {
         Document {
             blocks: vec![Block::Paragraph(vec![
                 Inline::Text("Note that we set PKG_CONFIG_PATH only if it's not ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("already".to_string())]),
+                Inline::Emphasis(Emphasis {
+                    delimiter: EmphasisDelimiter::Underscore,
+                    content: vec![Inline::Text("already".to_string())]
+                }),
                 Inline::Text(" set".to_string())
             ])],
         }
@@ -94,9 +113,10 @@ fn test_simple_underscore() {
     assert_eq!(
         doc,
         Document {
-            blocks: vec![Block::Paragraph(vec![Inline::Emphasis(vec![
-                Inline::Text("already".to_string())
-            ])])],
+            blocks: vec![Block::Paragraph(vec![Inline::Emphasis(Emphasis {
+                delimiter: EmphasisDelimiter::Underscore,
+                content: vec![Inline::Text("already".to_string())]
+            })])],
         }
     );
 }
@@ -130,7 +150,10 @@ fn test_multiple_env_vars_with_emphasis() {
         Document {
             blocks: vec![Block::Paragraph(vec![
                 Inline::Text("Set PATH_TO_FILE and CMAKE_BUILD_TYPE to ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("debug".to_string())]),
+                Inline::Emphasis(Emphasis {
+                    delimiter: EmphasisDelimiter::Underscore,
+                    content: vec![Inline::Text("debug".to_string())]
+                }),
                 Inline::Text(" for testing".to_string())
             ])],
         }
@@ -169,9 +192,15 @@ fn test_false_positive_prevention() {
         Document {
             blocks: vec![Block::Paragraph(vec![
                 Inline::Text("Text with ".to_string()),
-                Inline::Emphasis(vec![Inline::Text("emphasis".to_string())]),
+                Inline::Emphasis(Emphasis {
+                    delimiter: EmphasisDelimiter::Underscore,
+                    content: vec![Inline::Text("emphasis".to_string())]
+                }),
                 Inline::Text(" and ".to_string()),
-                Inline::Strong(vec![Inline::Text("strong".to_string())]),
+                Inline::Strong(Emphasis {
+                    delimiter: EmphasisDelimiter::Underscore,
+                    content: vec![Inline::Text("strong".to_string())]
+                }),
                 Inline::Text(" formatting".to_string())
             ])],
         }
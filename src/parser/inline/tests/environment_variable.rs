@@ -72,3 +72,58 @@ fn test_integration_with_full_parser() {
         }
     }
 }
+
+#[test]
+fn test_env_var_behavior_map() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+    use crate::parser::{parse_markdown, MarkdownParserState};
+    use std::sync::Arc;
+
+    let config = MarkdownParserConfig::default().with_inline_environment_variable_behavior(
+        ElementBehavior::Map(Arc::new(|inline| match inline {
+            Inline::Text(name) => Inline::Code(name),
+            other => other,
+        })),
+    );
+    let doc = parse_markdown(MarkdownParserState::with_config(config), "Set MY_VAR now").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![
+            Inline::Text("Set ".to_string()),
+            Inline::Code("MY_VAR".to_string()),
+            Inline::Text(" now".to_string()),
+        ])]
+    );
+}
+
+#[test]
+fn test_strict_commonmark_intraword_underscores() {
+    use crate::parser::config::MarkdownParserConfig;
+    use crate::parser::{parse_markdown, MarkdownParserState};
+
+    let state = || {
+        MarkdownParserState::with_config(MarkdownParserConfig::default().with_strict_commonmark())
+    };
+    for input in [
+        "snake_case_name",
+        "foo_bar baz_ qux",
+        "foo_bar_",
+        "foo__bar__",
+    ] {
+        let doc = parse_markdown(state(), input).unwrap();
+        assert_eq!(
+            doc.blocks,
+            vec![Block::Paragraph(vec![Inline::Text(input.to_string())])],
+            "{input}"
+        );
+    }
+
+    let doc = parse_markdown(state(), "_foo_bar_").unwrap();
+    assert_eq!(
+        doc.blocks,
+        vec![Block::Paragraph(vec![Inline::Emphasis(Emphasis {
+            delimiter: EmphasisDelimiter::Underscore,
+            content: vec![Inline::Text("foo_bar".to_string())],
+        })])]
+    );
+}
@@ -0,0 +1,69 @@
+use crate::ast::*;
+use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+use crate::parser::{parse_markdown, MarkdownParserState};
+use rstest::rstest;
+
+fn state() -> MarkdownParserState {
+    MarkdownParserState::with_config(
+        MarkdownParserConfig::default().with_inline_math_behavior(ElementBehavior::Parse),
+    )
+}
+
+#[test]
+fn math1() {
+    let doc = parse_markdown(state(), "Energy $E = mc^2$ is conserved.").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("Energy ".to_owned()),
+                Inline::Math("E = mc^2".to_owned()),
+                Inline::Text(" is conserved.".to_owned()),
+            ])],
+        }
+    );
+}
+
+#[test]
+fn math2() {
+    let doc = parse_markdown(state(), "$a_1 * b_2 \\$ x$ and *$y$*").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Math("a_1 * b_2 \\$ x".to_owned()),
+                Inline::Text(" and ".to_owned()),
+                Inline::Emphasis(vec![Inline::Math("y".to_owned())].into()),
+            ])],
+        }
+    );
+}
+
+#[rstest]
+#[case("It costs $5 and $10.")]
+#[case("$ x$")]
+#[case("$x $")]
+#[case("a $$x$$ b")]
+#[case("\\$x\\$")]
+#[case("$x")]
+fn not_math(#[case] input: &str) {
+    let doc = parse_markdown(state(), input).unwrap();
+    let Block::Paragraph(content) = &doc.blocks[0] else {
+        panic!("expected paragraph");
+    };
+    assert!(
+        content.iter().all(|i| matches!(i, Inline::Text(_))),
+        "{input:?} => {content:?}"
+    );
+}
+
+#[test]
+fn math_disabled_by_default() {
+    let doc = parse_markdown(MarkdownParserState::default(), "$x$").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![Inline::Text("$x$".to_owned())])],
+        }
+    );
+}
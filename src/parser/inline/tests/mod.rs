@@ -8,5 +8,7 @@ mod hard_newline;
 mod html_entity;
 mod image;
 mod inline_link;
+mod math;
 mod reference_link;
+mod soft_break;
 mod strikethrough;
@@ -0,0 +1,54 @@
+use crate::ast::*;
+use crate::parser::{parse_markdown, MarkdownParserState};
+
+#[test]
+fn soft_break1() {
+    let doc = parse_markdown(MarkdownParserState::default(), "line1\nline2").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("line1".to_string()),
+                Inline::SoftBreak,
+                Inline::Text("line2".to_string())
+            ])],
+        }
+    );
+}
+
+#[test]
+fn soft_break_strips_spaces() {
+    let doc = parse_markdown(MarkdownParserState::default(), "line1 \n  *line2*").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![
+                Inline::Text("line1".to_string()),
+                Inline::SoftBreak,
+                Inline::Emphasis(Emphasis {
+                    delimiter: EmphasisDelimiter::Asterisk,
+                    content: vec![Inline::Text("line2".to_string())],
+                }),
+            ])],
+        }
+    );
+}
+
+#[test]
+fn soft_break_in_link_text() {
+    let doc = parse_markdown(MarkdownParserState::default(), "[a\nb](/url)").unwrap();
+    assert_eq!(
+        doc,
+        Document {
+            blocks: vec![Block::Paragraph(vec![Inline::Link(Link {
+                destination: "/url".to_string(),
+                title: None,
+                children: vec![
+                    Inline::Text("a".to_string()),
+                    Inline::SoftBreak,
+                    Inline::Text("b".to_string())
+                ],
+            })])],
+        }
+    );
+}
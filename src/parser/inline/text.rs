@@ -1,8 +1,10 @@
+use crate::parser::span::discard;
 use crate::parser::MarkdownParserState;
 use crate::{ast::Inline, parser::util::conditional_inline_unit};
 use nom::{
     branch::alt,
-    character::complete::{anychar, char, one_of},
+    bytes::complete::take_while,
+    character::complete::{anychar, char, line_ending, one_of, space0},
     combinator::{map, not, peek, recognize, value},
     multi::many1,
     sequence::preceded,
@@ -14,26 +16,51 @@ pub(crate) fn text<'a>(
     state: Rc<MarkdownParserState>,
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Inline> {
     move |input: &'a str| {
-        map(
-            many1(alt((
-                map(escaped_char, |c| c.to_string()),
-                map(
-                    crate::parser::inline::html_entity::html_entity(state.clone()),
-                    |c| c.to_string(),
-                ),
-                map(
-                    recognize(many1(preceded(peek(is_text(state.clone())), anychar))),
-                    |c| c.to_string(),
-                ),
-            ))),
-            |vec| Inline::Text(vec.join("")),
-        )
+        alt((
+            value(Inline::SoftBreak, soft_break),
+            map(
+                many1(alt((
+                    map(escaped_char, |c| c.to_string()),
+                    map(crate::parser::inline::math::dollar_run, |s| s.to_string()),
+                    map(
+                        crate::parser::inline::html_entity::html_entity(state.clone()),
+                        |c| c.to_string(),
+                    ),
+                    map(text_run(state.clone()), |c| c.to_string()),
+                ))),
+                |vec| Inline::Text(vec.join("")),
+            ),
+        ))
         .parse(input)
     }
 }
 
+/// A line ending that is not a hard line break. Spaces around it are part of
+/// the break.
+fn soft_break(input: &str) -> IResult<&str, ()> {
+    value((), (space0, line_ending, space0)).parse(input)
+}
+
+/// Plain characters up to the next inline element.
+///
+/// Underscores right after a letter or digit are included: such an underscore
+/// is intraword and can neither open nor close emphasis.
+fn text_run<'a>(
+    state: Rc<MarkdownParserState>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    move |input: &'a str| {
+        let (rest, run) =
+            recognize(many1(preceded(peek(is_text(state.clone())), anychar))).parse(input)?;
+        if !run.ends_with(char::is_alphanumeric) {
+            return Ok((rest, run));
+        }
+        let (rest, _) = take_while(|c| c == '_').parse(rest)?;
+        Ok((rest, &input[..input.len() - rest.len()]))
+    }
+}
+
 fn is_text<'a>(state: Rc<MarkdownParserState>) -> impl FnMut(&'a str) -> IResult<&'a str, ()> {
-    move |input: &'a str| not(not_a_text(state.clone())).parse(input)
+    move |input: &'a str| not(discard(&state, not_a_text(state.clone()))).parse(input)
 }
 
 fn not_a_text<'a>(
@@ -99,17 +126,22 @@ fn not_a_text<'a>(
                         crate::parser::inline::strikethrough::strikethrough(state.clone()),
                     ),
                 ),
+                conditional_inline_unit(
+                    state.config.inline_math_behavior.clone(),
+                    alt((
+                        value((), crate::parser::inline::math::math),
+                        value((), crate::parser::inline::math::dollar_run),
+                    )),
+                ),
             )),
-            map(
+            conditional_inline_unit(
+                state.config.inline_environment_variable_behavior.clone(),
                 value(
                     (),
-                    map(
-                        crate::parser::inline::environment_variable::environment_variable,
-                        |_| (),
-                    ),
+                    crate::parser::inline::environment_variable::environment_variable,
                 ),
-                |_| vec![()],
             ),
+            value(Vec::new(), soft_break),
         ))
         .parse(input)
     }
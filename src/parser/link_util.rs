@@ -9,6 +9,7 @@ use nom::{
 };
 use std::rc::Rc;
 
+use super::span::SourceText;
 use super::MarkdownParserState;
 
 pub(crate) fn link_label<'a>(
@@ -24,17 +25,26 @@ fn link_label_inner<'a>(
 ) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<crate::ast::Inline>> {
     move |input: &'a str| {
         // Parse content with balanced brackets (handles nested [...] properly)
-        let (input, label) = verify(balanced_brackets_content, |s: &String| {
+        let (rest, label) = verify(balanced_brackets_content, |s: &String| {
             s.chars().any(|c| c != ' ' && c != '\n') && s.len() < 1000
         })
         .parse(input)?;
 
+        // The label differs from the input only when it contains escaped brackets
+        let raw = &input[..input.len() - rest.len()];
+        let mut content = SourceText::new(&state);
+        if label == raw {
+            content.push(raw);
+        } else {
+            content.push_replacement(&label, raw);
+        }
+
         // Recursively parse the label content as inline elements
-        let (_, label) = crate::parser::inline::inline_many1(state.clone())
-            .parse(label.as_str())
-            .map_err(|err| err.map_input(|_| input))?;
+        let (_, label) = crate::parser::inline::inline_many1(Rc::new(content.state(&state)))
+            .parse(content.as_str())
+            .map_err(|err| err.map_input(|_| rest))?;
 
-        Ok((input, label))
+        Ok((rest, label))
     }
 }
 
@@ -10,7 +10,7 @@
 //! - **GitHub extensions**: Tables, task lists, strikethrough, autolinks, footnotes, alerts
 //! - **Configurable parsing**: Control which elements to parse, skip, or transform
 //! - **Custom parsers**: Register custom block and inline element parsers
-//! - **Error handling**: Comprehensive error reporting with nom-based parsing
+//! - **Error handling**: [`ParseError`] with line, column and a readable message
 //!
 //! # Basic Usage
 //!
@@ -25,7 +25,7 @@
 //!         println!("Parsed {} blocks", document.blocks.len());
 //!     }
 //!     Err(err) => {
-//!         eprintln!("Parse error: {:?}", err);
+//!         eprintln!("Parse error: {err}");
 //!     }
 //! }
 //! ```
@@ -48,21 +48,27 @@ mod blocks;
 
 /// Configuration options for Markdown parsing behavior.
 pub mod config;
+mod error;
+mod incremental;
 mod inline;
 mod link_util;
+mod span;
 mod util;
 
-use crate::ast::Document;
+use crate::ast::{generic, Document, Span};
 use crate::parser::config::MarkdownParserConfig;
+pub use crate::parser::error::{BlockKind, ParseError};
+pub use crate::parser::incremental::{reparse_markdown, Reparsed};
 use nom::{
     branch::alt,
     character::complete::{line_ending, space1},
-    combinator::eof,
+    combinator::{eof, opt},
     multi::many0,
     sequence::terminated,
     Parser,
 };
 use std::rc::Rc;
+use std::sync::Arc;
 
 /// Parser state containing configuration and shared context
 ///
@@ -87,11 +93,14 @@ use std::rc::Rc;
 #[non_exhaustive]
 pub struct MarkdownParserState {
     /// The parser configuration (reference-counted for efficient cloning)
-    pub config: Rc<MarkdownParserConfig>,
+    pub config: Arc<MarkdownParserConfig>,
     /// Whether we are parsing content extracted from a container block (list item, blockquote, etc.)
     /// When true, fenced code blocks should not strip additional indentation from their content.
     /// This field is for internal use only.
     pub(crate) is_nested_block_context: bool,
+    /// Source position tracking, enabled by [`parse_markdown_with_spans`].
+    /// This field is for internal use only.
+    pub(crate) spans: Option<span::SpanContext>,
 }
 
 impl MarkdownParserState {
@@ -112,7 +121,8 @@ impl MarkdownParserState {
     ///
     /// # Arguments
     ///
-    /// * `config` - The parser configuration to use
+    /// * `config` - The parser configuration to use, either owned or shared
+    ///   through an `Arc`
     ///
     /// # Examples
     ///
@@ -122,10 +132,45 @@ impl MarkdownParserState {
     /// let config = MarkdownParserConfig::default();
     /// let state = MarkdownParserState::with_config(config);
     /// ```
-    pub fn with_config(config: MarkdownParserConfig) -> Self {
+    ///
+    /// Sharing one configuration between threads:
+    ///
+    /// ```rust
+    /// use markdown_ppp::ast::{Block, Heading, HeadingKind};
+    /// use markdown_ppp::parser::{parse_markdown, MarkdownParserState, config::*};
+    /// use std::sync::Arc;
+    ///
+    /// // Demote `#` headings by one level
+    /// let config = Arc::new(MarkdownParserConfig::default().with_block_heading_v1_behavior(
+    ///     ElementBehavior::Map(Arc::new(|block| match block {
+    ///         Block::Heading(Heading { kind: HeadingKind::Atx(level), content }) => {
+    ///             Block::Heading(Heading { kind: HeadingKind::Atx((level + 1).min(6)), content })
+    ///         }
+    ///         other => other,
+    ///     })),
+    /// ));
+    ///
+    /// let handles: Vec<_> = (0..4)
+    ///     .map(|_| {
+    ///         let config = Arc::clone(&config);
+    ///         std::thread::spawn(move || {
+    ///             parse_markdown(MarkdownParserState::with_config(config), "# Title").unwrap()
+    ///         })
+    ///     })
+    ///     .collect();
+    /// for handle in handles {
+    ///     let doc = handle.join().unwrap();
+    ///     assert!(matches!(
+    ///         &doc.blocks[0],
+    ///         Block::Heading(Heading { kind: HeadingKind::Atx(2), .. })
+    ///     ));
+    /// }
+    /// ```
+    pub fn with_config(config: impl Into<Arc<MarkdownParserConfig>>) -> Self {
         Self {
-            config: Rc::new(config),
+            config: config.into(),
             is_nested_block_context: false,
+            spans: None,
         }
     }
 
@@ -138,6 +183,7 @@ impl MarkdownParserState {
         Self {
             config: self.config.clone(),
             is_nested_block_context: true,
+            spans: self.spans.clone(),
         }
     }
 }
@@ -163,7 +209,7 @@ impl Default for MarkdownParserState {
 ///
 /// Returns a `Result` containing either:
 /// - `Ok(Document)` - Successfully parsed AST document
-/// - `Err(nom::Err)` - Parse error with position and context information
+/// - `Err(ParseError)` - Parse error with position and context information
 ///
 /// # Examples
 ///
@@ -176,7 +222,7 @@ impl Default for MarkdownParserState {
 ///
 /// match result {
 ///     Ok(doc) => println!("Parsed {} blocks", doc.blocks.len()),
-///     Err(e) => eprintln!("Parse error: {:?}", e),
+///     Err(e) => eprintln!("Parse error: {e}"),
 /// }
 /// ```
 ///
@@ -194,21 +240,69 @@ impl Default for MarkdownParserState {
 ///
 /// # Errors
 ///
-/// Returns a parse error if the input contains invalid Markdown syntax
+/// Returns a [`ParseError`] if the input contains invalid Markdown syntax
 /// that cannot be recovered from. Most malformed Markdown is handled
 /// gracefully according to CommonMark's error handling rules.
-pub fn parse_markdown(
-    state: MarkdownParserState,
-    input: &str,
-) -> Result<Document, nom::Err<nom::error::Error<&str>>> {
+pub fn parse_markdown(state: MarkdownParserState, input: &str) -> Result<Document, ParseError> {
+    let state = Rc::new(state);
     let empty_lines = many0(alt((space1, line_ending)));
     let mut parser = terminated(
-        many0(crate::parser::blocks::block(Rc::new(state))),
+        (
+            opt(crate::parser::blocks::front_matter(state.clone())),
+            many0(crate::parser::blocks::block(state)),
+        ),
         (empty_lines, eof),
     );
-    let (_, blocks) = parser.parse(input)?;
+    let (_, (front_matter, blocks)) = parser
+        .parse(input)
+        .map_err(|err| ParseError::from_nom(input, err))?;
 
-    let blocks = blocks.into_iter().flatten().collect();
+    let blocks = front_matter.into_iter().chain(blocks).flatten().collect();
 
     Ok(Document { blocks })
 }
+
+/// Parse a Markdown string into an AST annotated with source positions
+///
+/// Works like [`parse_markdown`], but every block, inline, list item and the
+/// document itself carry a [`Span`] in their `user_data` field. Spans are
+/// reported against the original input, including content of nested
+/// containers such as list items, blockquotes and GitHub alerts.
+///
+/// Nodes whose shape was changed by `ElementBehavior::Map` or
+/// `ElementBehavior::FlatMap`, or which were produced by custom parsers,
+/// receive the span of the enclosing node.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::generic::{Block, Inline};
+/// use markdown_ppp::parser::{parse_markdown_with_spans, MarkdownParserState};
+///
+/// let input = "# Title\n\n- first\n- *second*\n";
+/// let doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+///
+/// let Block::List(list) = &doc.blocks[1] else { panic!() };
+/// let Block::Paragraph { content, .. } = &list.items[1].blocks[0] else { panic!() };
+/// let Inline::Emphasis { user_data: span, .. } = &content[0] else { panic!() };
+///
+/// assert_eq!(&input[span.range()], "*second*");
+/// assert_eq!((span.start.line, span.start.column), (4, 3));
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_markdown`].
+pub fn parse_markdown_with_spans(
+    state: MarkdownParserState,
+    input: &str,
+) -> Result<generic::Document<Span>, ParseError> {
+    let spans = span::SpanContext::new(input);
+    let state = MarkdownParserState {
+        spans: Some(spans.clone()),
+        ..state
+    };
+    let document = parse_markdown(state, input)?;
+
+    Ok(span::attach_spans(input, document, spans.into_nodes()))
+}
@@ -0,0 +1,1129 @@
+//! Source position tracking for [`parse_markdown_with_spans`](super::parse_markdown_with_spans).
+//!
+//! Parsers work on `&str` slices, so the location of a parsed node follows from
+//! the slice it consumed. Container blocks (list items, blockquotes, alerts,
+//! footnotes) and paragraphs strip their markers and re-parse the remaining
+//! lines as a freshly allocated string. Such strings are built with
+//! [`SourceText`], which remembers where every piece came from so offsets can
+//! be translated back into the original input.
+//!
+//! While parsing, every block and inline parser records a [`SpanNode`]. The
+//! recorded tree mirrors the structure of the resulting AST and is attached to
+//! it once parsing has succeeded.
+
+use crate::ast::{self, generic, Position, Span};
+use crate::parser::MarkdownParserState;
+use nom::{IResult, Parser};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+// ——————————————————————————————————————————————————————————————————————————
+// Offset translation
+// ——————————————————————————————————————————————————————————————————————————
+
+/// Piece of parsed text that originates from the input.
+///
+/// When `len != source_len` the piece is a replacement (e.g. an unescaped
+/// character), and offsets inside it collapse to its boundaries.
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    local: usize,
+    len: usize,
+    source: usize,
+    source_len: usize,
+}
+
+impl Segment {
+    fn local_end(&self) -> usize {
+        self.local + self.len
+    }
+
+    fn is_exact(&self) -> bool {
+        self.len == self.source_len
+    }
+}
+
+/// Maps offsets in the text currently being parsed to offsets in the input.
+pub(crate) struct SourceMap {
+    base: usize,
+    len: usize,
+    segments: Vec<Segment>,
+}
+
+impl SourceMap {
+    fn identity(input: &str) -> Self {
+        Self {
+            base: input.as_ptr() as usize,
+            len: input.len(),
+            segments: vec![Segment {
+                local: 0,
+                len: input.len(),
+                source: 0,
+                source_len: input.len(),
+            }],
+        }
+    }
+
+    /// Offset of `slice` within the mapped text, if it belongs to it.
+    fn local_offset(&self, slice: &str) -> Option<usize> {
+        let ptr = slice.as_ptr() as usize;
+        if ptr >= self.base && ptr + slice.len() <= self.base + self.len {
+            Some(ptr - self.base)
+        } else {
+            None
+        }
+    }
+
+    fn map_start(&self, offset: usize) -> usize {
+        for segment in &self.segments {
+            if offset < segment.local {
+                // Text between segments was synthesized; start at the next piece.
+                return segment.source;
+            }
+            if offset < segment.local_end() {
+                return if segment.is_exact() {
+                    segment.source + (offset - segment.local)
+                } else {
+                    segment.source
+                };
+            }
+        }
+        self.segments
+            .last()
+            .map(|s| s.source + s.source_len)
+            .unwrap_or(0)
+    }
+
+    fn map_end(&self, offset: usize) -> usize {
+        let mut result = self.segments.first().map(|s| s.source).unwrap_or(0);
+        for segment in &self.segments {
+            if offset <= segment.local {
+                break;
+            }
+            if offset <= segment.local_end() {
+                return if segment.is_exact() {
+                    segment.source + (offset - segment.local)
+                } else {
+                    segment.source + segment.source_len
+                };
+            }
+            // Text between segments was synthesized; end at the previous piece.
+            result = segment.source + segment.source_len;
+        }
+        result
+    }
+
+    /// Translate `local..local + len` into segments of a new text, where the
+    /// range starts at offset `target`.
+    fn project(&self, local: usize, len: usize, target: usize, out: &mut Vec<Segment>) {
+        let end = local + len;
+        for segment in &self.segments {
+            let from = local.max(segment.local);
+            let to = end.min(segment.local_end());
+            if from >= to {
+                continue;
+            }
+            let (source, source_len) = if segment.is_exact() {
+                (segment.source + (from - segment.local), to - from)
+            } else {
+                (segment.source, segment.source_len)
+            };
+            out.push(Segment {
+                local: target + (from - local),
+                len: to - from,
+                source,
+                source_len,
+            });
+        }
+    }
+}
+
+/// Text assembled from pieces of the text being parsed.
+///
+/// Container parsers use it instead of `String` when re-parsing extracted
+/// lines, and parse the result with [`SourceText::state`].
+pub(crate) struct SourceText {
+    text: String,
+    source: Option<Arc<SourceMap>>,
+    segments: Vec<Segment>,
+}
+
+impl SourceText {
+    pub(crate) fn new(state: &MarkdownParserState) -> Self {
+        Self {
+            text: String::new(),
+            source: state.spans.as_ref().map(|spans| spans.source.clone()),
+            segments: Vec::new(),
+        }
+    }
+
+    /// Join lines, which are slices of `text`, the text being parsed, with
+    /// `\n` standing in for the line ending that follows each line in `text`.
+    pub(crate) fn join_lines(state: &MarkdownParserState, text: &str, lines: &[&str]) -> Self {
+        let mut joined = Self::new(state);
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                joined.push_line_ending(text, lines[i - 1]);
+            }
+            joined.push(line);
+        }
+        joined
+    }
+
+    /// Append a slice of the text being parsed.
+    pub(crate) fn push(&mut self, piece: &str) {
+        if let Some(source) = &self.source {
+            if let Some(local) = source.local_offset(piece) {
+                source.project(local, piece.len(), self.text.len(), &mut self.segments);
+            }
+        }
+        self.text.push_str(piece);
+    }
+
+    /// Append `\n` standing in for the line ending (`\n` or `\r\n`) that
+    /// follows `line` in `text`. Both are slices of the text being parsed.
+    pub(crate) fn push_line_ending(&mut self, text: &str, line: &str) {
+        let end = (line.as_ptr() as usize + line.len()).checked_sub(text.as_ptr() as usize);
+        let rest = end.and_then(|end| text.get(end..)).unwrap_or_default();
+        match ["\r\n", "\n"]
+            .into_iter()
+            .find(|ending| rest.starts_with(ending))
+        {
+            Some(ending) => self.push_replacement("\n", &rest[..ending.len()]),
+            None => self.push_str("\n"),
+        }
+    }
+
+    /// Append text that does not come from the input.
+    pub(crate) fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+    }
+
+    /// Append `text` standing in for `original`, a slice of the text being parsed.
+    pub(crate) fn push_replacement(&mut self, text: &str, original: &str) {
+        if let Some(source) = &self.source {
+            if let Some(local) = source.local_offset(original) {
+                let start = source.map_start(local);
+                let end = source.map_end(local + original.len()).max(start);
+                self.segments.push(Segment {
+                    local: self.text.len(),
+                    len: text.len(),
+                    source: start,
+                    source_len: end - start,
+                });
+            }
+        }
+        self.text.push_str(text);
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Parser state for parsing this text, derived from `state`. The text
+    /// must not be modified while the returned state is in use.
+    pub(crate) fn state(&self, state: &MarkdownParserState) -> MarkdownParserState {
+        MarkdownParserState {
+            config: state.config.clone(),
+            is_nested_block_context: state.is_nested_block_context,
+            spans: state.spans.as_ref().map(|spans| SpanContext {
+                recorder: spans.recorder.clone(),
+                source: Arc::new(SourceMap {
+                    base: self.text.as_ptr() as usize,
+                    len: self.text.len(),
+                    segments: self.segments.clone(),
+                }),
+            }),
+        }
+    }
+}
+
+// ——————————————————————————————————————————————————————————————————————————
+// Recording
+// ——————————————————————————————————————————————————————————————————————————
+
+/// Source range of a parsed node together with the ranges of its children.
+#[derive(Debug, Clone)]
+pub(crate) struct SpanNode {
+    start: usize,
+    end: usize,
+    children: Vec<SpanNode>,
+}
+
+/// Stack of nodes recorded by the parsers that are currently running.
+pub(crate) struct SpanRecorder {
+    frames: Vec<Vec<SpanNode>>,
+}
+
+impl SpanRecorder {
+    fn current(&mut self) -> &mut Vec<SpanNode> {
+        if self.frames.is_empty() {
+            self.frames.push(Vec::new());
+        }
+        self.frames.last_mut().unwrap()
+    }
+}
+
+/// Span tracking state shared by a parser state and its nested states.
+#[derive(Clone)]
+pub(crate) struct SpanContext {
+    recorder: Arc<Mutex<SpanRecorder>>,
+    source: Arc<SourceMap>,
+}
+
+impl SpanContext {
+    pub(crate) fn new(input: &str) -> Self {
+        Self {
+            recorder: Arc::new(Mutex::new(SpanRecorder {
+                frames: vec![Vec::new()],
+            })),
+            source: Arc::new(SourceMap::identity(input)),
+        }
+    }
+
+    /// The recorder. Every parse has its own and no parser runs while it is
+    /// locked, so locking never blocks.
+    fn recorder(&self) -> MutexGuard<'_, SpanRecorder> {
+        self.recorder.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn mark(&self) -> usize {
+        self.recorder().current().len()
+    }
+
+    fn truncate(&self, mark: usize) {
+        self.recorder().current().truncate(mark);
+    }
+
+    /// Nodes recorded at the top level.
+    pub(crate) fn into_nodes(self) -> Vec<SpanNode> {
+        std::mem::take(self.recorder().current())
+    }
+}
+
+/// Record the range consumed by `parser` as `count(&output)` nodes. Nodes
+/// recorded by nested parsers become children of the node when exactly one
+/// node is recorded.
+pub(crate) fn spanned<'a, O, P>(
+    state: &MarkdownParserState,
+    trim_line_endings: bool,
+    count: fn(&O) -> usize,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    P: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+{
+    let spans = state.spans.clone();
+    move |input: &'a str| {
+        let Some(spans) = &spans else {
+            return parser.parse(input);
+        };
+
+        spans.recorder().frames.push(Vec::new());
+        let result = parser.parse(input);
+        let mut recorder = spans.recorder();
+        let children = recorder.frames.pop().unwrap_or_default();
+
+        if let Ok((rest, output)) = &result {
+            let mut consumed = &input[..input.len() - rest.len()];
+            if trim_line_endings {
+                consumed = consumed.trim_end_matches(['\n', '\r']);
+            }
+            if let Some(local) = spans.source.local_offset(consumed) {
+                let start = spans.source.map_start(local);
+                let end = spans.source.map_end(local + consumed.len()).max(start);
+                let frame = recorder.current();
+                match count(output) {
+                    1 => frame.push(SpanNode {
+                        start,
+                        end,
+                        children,
+                    }),
+                    n => frame.extend((0..n).map(|_| SpanNode {
+                        start,
+                        end,
+                        children: Vec::new(),
+                    })),
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Drop nodes recorded by `parser` if it fails. Wrap alternatives of `alt`
+/// whose nested parsers may succeed before the alternative itself fails.
+pub(crate) fn rollback<'a, O, P>(
+    state: &MarkdownParserState,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    P: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+{
+    let spans = state.spans.clone();
+    move |input: &'a str| {
+        let Some(spans) = &spans else {
+            return parser.parse(input);
+        };
+        let mark = spans.mark();
+        let result = parser.parse(input);
+        if result.is_err() {
+            spans.truncate(mark);
+        }
+        result
+    }
+}
+
+/// Drop all nodes recorded by `parser`. Used for lookahead.
+pub(crate) fn discard<'a, O, P>(
+    state: &MarkdownParserState,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    P: Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+{
+    let spans = state.spans.clone();
+    move |input: &'a str| {
+        let Some(spans) = &spans else {
+            return parser.parse(input);
+        };
+        let mark = spans.mark();
+        let result = parser.parse(input);
+        spans.truncate(mark);
+        result
+    }
+}
+
+/// Merge the nodes of consecutive text elements produced by `parser`, the
+/// same way the inline parser merges the elements themselves.
+pub(crate) fn merge_text_spans<'a, P>(
+    state: &MarkdownParserState,
+    mut parser: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<ast::Inline>>
+where
+    P: Parser<&'a str, Output = Vec<ast::Inline>, Error = nom::error::Error<&'a str>>,
+{
+    let spans = state.spans.clone();
+    move |input: &'a str| {
+        let Some(spans) = &spans else {
+            return parser.parse(input);
+        };
+        let mark = spans.mark();
+        let (rest, inlines) = parser.parse(input)?;
+
+        let mut recorder = spans.recorder();
+        let frame = recorder.current();
+        if frame.len() >= mark && frame.len() - mark == inlines.len() {
+            let nodes = frame.split_off(mark);
+            let mut previous_is_text = false;
+            for (inline, node) in inlines.iter().zip(nodes) {
+                let is_text = matches!(inline, ast::Inline::Text(_));
+                match frame.last_mut() {
+                    Some(last) if is_text && previous_is_text => last.end = node.end,
+                    _ => frame.push(node),
+                }
+                previous_is_text = is_text;
+            }
+        }
+
+        Ok((rest, inlines))
+    }
+}
+
+// ——————————————————————————————————————————————————————————————————————————
+// Attaching spans to the AST
+// ——————————————————————————————————————————————————————————————————————————
+
+/// Build a document with spans from a parsed document and the recorded
+/// nodes. Nodes whose structure does not match the AST (e.g. because of
+/// `ElementBehavior::Map`) are replaced by the span of the enclosing node.
+pub(crate) fn attach_spans(
+    input: &str,
+    document: ast::Document,
+    nodes: Vec<SpanNode>,
+) -> generic::Document<Span> {
+    let attacher = Attacher::new(input);
+    let span = attacher.span(0, input.len());
+    generic::Document {
+        blocks: attacher.blocks(document.blocks, nodes, span),
+        user_data: span,
+    }
+}
+
+struct Attacher<'a> {
+    input: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> Attacher<'a> {
+    fn new(input: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(input.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { input, line_starts }
+    }
+
+    fn position(&self, offset: usize) -> Position {
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1];
+        let column = self
+            .input
+            .get(line_start..offset)
+            .map(|s| s.chars().count())
+            .unwrap_or(offset - line_start);
+        Position {
+            offset,
+            line,
+            column: column + 1,
+        }
+    }
+
+    fn span(&self, start: usize, end: usize) -> Span {
+        Span {
+            start: self.position(start),
+            end: self.position(end),
+        }
+    }
+
+    /// Pair every element with its node, or with the parent span if the
+    /// number of recorded nodes does not match.
+    fn pair<E>(
+        &self,
+        elements: Vec<E>,
+        nodes: Vec<SpanNode>,
+        parent: Span,
+    ) -> Vec<(E, Span, Vec<SpanNode>)> {
+        if elements.len() == nodes.len() {
+            elements
+                .into_iter()
+                .zip(nodes)
+                .map(|(element, node)| (element, self.span(node.start, node.end), node.children))
+                .collect()
+        } else {
+            elements
+                .into_iter()
+                .map(|element| (element, parent, Vec::new()))
+                .collect()
+        }
+    }
+
+    fn blocks(
+        &self,
+        blocks: Vec<ast::Block>,
+        nodes: Vec<SpanNode>,
+        parent: Span,
+    ) -> Vec<generic::Block<Span>> {
+        self.pair(blocks, nodes, parent)
+            .into_iter()
+            .map(|(block, span, children)| self.block(block, span, children))
+            .collect()
+    }
+
+    fn block(
+        &self,
+        block: ast::Block,
+        span: Span,
+        children: Vec<SpanNode>,
+    ) -> generic::Block<Span> {
+        match block {
+            ast::Block::Paragraph(content) => generic::Block::Paragraph {
+                content: self.inlines(content, children, span),
+                user_data: span,
+            },
+            ast::Block::Heading(heading) => generic::Block::Heading(generic::Heading {
+                kind: heading.kind,
+                content: self.inlines(heading.content, children, span),
+                user_data: span,
+            }),
+            ast::Block::ThematicBreak => generic::Block::ThematicBreak { user_data: span },
+            ast::Block::BlockQuote(blocks) => generic::Block::BlockQuote {
+                blocks: self.blocks(blocks, children, span),
+                user_data: span,
+            },
+            ast::Block::List(list) => generic::Block::List(generic::List {
+                kind: generic::ListKind::from(list.kind),
+                items: self
+                    .pair(list.items, children, span)
+                    .into_iter()
+                    .map(|(item, span, children)| generic::ListItem {
+                        task: item.task,
+                        blocks: self.blocks(item.blocks, children, span),
+                        user_data: span,
+                    })
+                    .collect(),
+                tight: list.tight,
+                user_data: span,
+            }),
+            ast::Block::DefinitionList(list) => {
+                generic::Block::DefinitionList(generic::DefinitionList {
+                    items: self
+                        .pair(list.items, children, span)
+                        .into_iter()
+                        .map(|(item, span, children)| {
+                            self.definition_list_item(item, span, children)
+                        })
+                        .collect(),
+                    user_data: span,
+                })
+            }
+            ast::Block::CodeBlock(code_block) => generic::Block::CodeBlock(generic::CodeBlock {
+                kind: code_block.kind,
+                literal: code_block.literal,
+                user_data: span,
+            }),
+            ast::Block::Math(content) => generic::Block::Math {
+                content,
+                user_data: span,
+            },
+            ast::Block::HtmlBlock(content) => generic::Block::HtmlBlock {
+                content,
+                user_data: span,
+            },
+            ast::Block::FrontMatter { format, raw } => generic::Block::FrontMatter {
+                format,
+                raw,
+                user_data: span,
+            },
+            ast::Block::Definition(definition) => {
+                generic::Block::Definition(generic::LinkDefinition {
+                    label: self.inlines(definition.label, children, span),
+                    destination: definition.destination,
+                    title: definition.title,
+                    user_data: span,
+                })
+            }
+            ast::Block::Table(table) => generic::Block::Table(generic::Table {
+                rows: self
+                    .pair(table.rows, children, span)
+                    .into_iter()
+                    .map(|(row, span, children)| self.table_row(row, span, children))
+                    .collect(),
+                alignments: table.alignments,
+                user_data: span,
+            }),
+            ast::Block::FootnoteDefinition(footnote) => {
+                generic::Block::FootnoteDefinition(generic::FootnoteDefinition {
+                    label: footnote.label,
+                    blocks: self.blocks(footnote.blocks, children, span),
+                    user_data: span,
+                })
+            }
+            ast::Block::GitHubAlert(alert) => {
+                generic::Block::GitHubAlert(generic::GitHubAlertNode {
+                    alert_type: alert.alert_type,
+                    blocks: self.blocks(alert.blocks, children, span),
+                    user_data: span,
+                })
+            }
+            ast::Block::Empty => generic::Block::Empty { user_data: span },
+        }
+    }
+
+    /// Items record the term first and then every definition.
+    fn definition_list_item(
+        &self,
+        item: ast::DefinitionListItem,
+        span: Span,
+        mut children: Vec<SpanNode>,
+    ) -> generic::DefinitionListItem<Span> {
+        let (term, definitions) = if children.len() == 1 + item.definitions.len() {
+            let definitions = children.split_off(1);
+            (children, definitions)
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        let term_span = term
+            .first()
+            .map_or(span, |node| self.span(node.start, node.end));
+        let term_children = term
+            .into_iter()
+            .next()
+            .map_or(Vec::new(), |node| node.children);
+        generic::DefinitionListItem {
+            term: self.inlines(item.term, term_children, term_span),
+            definitions: self
+                .pair(item.definitions, definitions, span)
+                .into_iter()
+                .map(|(blocks, span, children)| self.blocks(blocks, children, span))
+                .collect(),
+            user_data: span,
+        }
+    }
+
+    /// Rows may be padded with empty cells or truncated to the column count,
+    /// so cells are paired with the recorded cell nodes one by one.
+    fn table_row(
+        &self,
+        row: ast::TableRow,
+        span: Span,
+        children: Vec<SpanNode>,
+    ) -> generic::TableRow<Span> {
+        let mut nodes = children.into_iter();
+        row.into_iter()
+            .map(|cell| match nodes.next() {
+                Some(node) => {
+                    let span = self.span(node.start, node.end);
+                    self.inlines(cell, node.children, span)
+                }
+                None => self.inlines(cell, Vec::new(), span),
+            })
+            .collect()
+    }
+
+    fn inlines(
+        &self,
+        inlines: Vec<ast::Inline>,
+        nodes: Vec<SpanNode>,
+        parent: Span,
+    ) -> Vec<generic::Inline<Span>> {
+        self.pair(inlines, nodes, parent)
+            .into_iter()
+            .map(|(inline, span, children)| self.inline(inline, span, children))
+            .collect()
+    }
+
+    fn inline(
+        &self,
+        inline: ast::Inline,
+        span: Span,
+        mut children: Vec<SpanNode>,
+    ) -> generic::Inline<Span> {
+        match inline {
+            ast::Inline::Text(content) => generic::Inline::Text {
+                content,
+                user_data: span,
+            },
+            ast::Inline::LineBreak => generic::Inline::LineBreak { user_data: span },
+            ast::Inline::SoftBreak => generic::Inline::SoftBreak { user_data: span },
+            ast::Inline::Code(content) => generic::Inline::Code {
+                content,
+                user_data: span,
+            },
+            ast::Inline::Math(content) => generic::Inline::Math {
+                content,
+                user_data: span,
+            },
+            ast::Inline::Html(content) => generic::Inline::Html {
+                content,
+                user_data: span,
+            },
+            ast::Inline::Link(link) => generic::Inline::Link(generic::Link {
+                destination: link.destination,
+                title: link.title,
+                children: self.inlines(link.children, children, span),
+                user_data: span,
+            }),
+            ast::Inline::LinkReference(reference) => {
+                // Full references record the text and then the label; collapsed
+                // and shortcut references record the label once.
+                let (text_nodes, label_nodes) =
+                    if children.len() == reference.text.len() + reference.label.len() {
+                        let label_nodes = children.split_off(reference.text.len());
+                        (children, label_nodes)
+                    } else {
+                        (children.clone(), children)
+                    };
+                generic::Inline::LinkReference(generic::LinkReference {
+                    label: self.inlines(reference.label, label_nodes, span),
+                    text: self.inlines(reference.text, text_nodes, span),
+                    user_data: span,
+                })
+            }
+            ast::Inline::Image(image) => generic::Inline::Image(generic::Image {
+                destination: image.destination,
+                title: image.title,
+                alt: image.alt,
+                user_data: span,
+            }),
+            ast::Inline::Emphasis(ast::Emphasis { delimiter, content }) => generic::Inline::Emphasis {
+                delimiter,
+                content: self.inlines(content, children, span),
+                user_data: span,
+            },
+            ast::Inline::Strong(ast::Emphasis { delimiter, content }) => generic::Inline::Strong {
+                delimiter,
+                content: self.inlines(content, children, span),
+                user_data: span,
+            },
+            ast::Inline::Strikethrough(content) => generic::Inline::Strikethrough {
+                content: self.inlines(content, children, span),
+                user_data: span,
+            },
+            ast::Inline::Autolink(url) => generic::Inline::Autolink {
+                url,
+                user_data: span,
+            },
+            ast::Inline::FootnoteReference(label) => generic::Inline::FootnoteReference {
+                label,
+                user_data: span,
+            },
+            ast::Inline::Empty => generic::Inline::Empty { user_data: span },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::convert::StripData;
+    use crate::ast::generic::{Block, Inline};
+    use crate::ast::Span;
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+    use crate::parser::{parse_markdown, parse_markdown_with_spans, MarkdownParserState};
+    use std::sync::Arc;
+
+    fn parse(input: &str) -> crate::ast::generic::Document<Span> {
+        parse_markdown_with_spans(MarkdownParserState::default(), input).unwrap()
+    }
+
+    fn text<'a>(input: &'a str, span: &Span) -> &'a str {
+        &input[span.range()]
+    }
+
+    fn block_span(block: &Block<Span>) -> Span {
+        match block {
+            Block::Paragraph { user_data, .. }
+            | Block::ThematicBreak { user_data }
+            | Block::BlockQuote { user_data, .. }
+            | Block::HtmlBlock { user_data, .. }
+            | Block::Math { user_data, .. }
+            | Block::FrontMatter { user_data, .. }
+            | Block::Empty { user_data } => *user_data,
+            Block::Heading(v) => v.user_data,
+            Block::List(v) => v.user_data,
+            Block::DefinitionList(v) => v.user_data,
+            Block::CodeBlock(v) => v.user_data,
+            Block::Definition(v) => v.user_data,
+            Block::Table(v) => v.user_data,
+            Block::FootnoteDefinition(v) => v.user_data,
+            Block::GitHubAlert(v) => v.user_data,
+        }
+    }
+
+    fn inline_span(inline: &Inline<Span>) -> Span {
+        match inline {
+            Inline::Text { user_data, .. }
+            | Inline::LineBreak { user_data }
+            | Inline::SoftBreak { user_data }
+            | Inline::Code { user_data, .. }
+            | Inline::Math { user_data, .. }
+            | Inline::Html { user_data, .. }
+            | Inline::Emphasis { user_data, .. }
+            | Inline::Strong { user_data, .. }
+            | Inline::Strikethrough { user_data, .. }
+            | Inline::Autolink { user_data, .. }
+            | Inline::FootnoteReference { user_data, .. }
+            | Inline::Empty { user_data } => *user_data,
+            Inline::Link(v) => v.user_data,
+            Inline::LinkReference(v) => v.user_data,
+            Inline::Image(v) => v.user_data,
+        }
+    }
+
+    fn paragraph_content(block: &Block<Span>) -> &Vec<Inline<Span>> {
+        match block {
+            Block::Paragraph { content, .. } => content,
+            other => panic!("expected paragraph, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn same_ast_as_parse_markdown() {
+        let input = "# Title\n\n- a\n- b\n\n  > quote\n\n| a | b |\n|---|---|\n| `c` | [d][e] |\n\n[e]: /url\n";
+        let plain = parse_markdown(MarkdownParserState::default(), input).unwrap();
+        assert_eq!(parse(input).strip_data(), plain);
+    }
+
+    #[test]
+    fn front_matter() {
+        let config = MarkdownParserConfig::default()
+            .with_block_front_matter_behavior(ElementBehavior::Parse);
+        let input = "---\ntitle: x\n---\n\nText\n";
+        let doc =
+            parse_markdown_with_spans(MarkdownParserState::with_config(config), input).unwrap();
+
+        assert_eq!(doc.blocks.len(), 2);
+        assert_eq!(
+            text(input, &block_span(&doc.blocks[0])),
+            "---\ntitle: x\n---"
+        );
+        assert_eq!(text(input, &block_span(&doc.blocks[1])), "Text");
+    }
+
+    #[test]
+    fn definition_list() {
+        let config = MarkdownParserConfig::default()
+            .with_block_definition_list_behavior(ElementBehavior::Parse);
+        let input = "Apple\n: A *fruit*\n\n  More\n:   A company\n";
+        let doc =
+            parse_markdown_with_spans(MarkdownParserState::with_config(config), input).unwrap();
+
+        let Block::DefinitionList(list) = &doc.blocks[0] else {
+            panic!("expected a definition list");
+        };
+        assert_eq!(
+            text(input, &list.user_data),
+            "Apple\n: A *fruit*\n\n  More\n:   A company"
+        );
+        let item = &list.items[0];
+        assert_eq!(text(input, &inline_span(&item.term[0])), "Apple");
+        assert_eq!(item.definitions.len(), 2);
+        assert_eq!(text(input, &block_span(&item.definitions[0][0])), "A *fruit*");
+        assert_eq!(text(input, &block_span(&item.definitions[0][1])), "More");
+        let emphasis = &paragraph_content(&item.definitions[0][0])[1];
+        assert_eq!(text(input, &inline_span(emphasis)), "*fruit*");
+        assert_eq!(text(input, &block_span(&item.definitions[1][0])), "A company");
+    }
+
+    #[test]
+    fn top_level_blocks() {
+        let input = "# Title\n\nSome *text*\nmore\n\n---\n";
+        let doc = parse(input);
+
+        assert_eq!(doc.user_data.range(), 0..input.len());
+        assert_eq!(doc.blocks.len(), 3);
+        assert_eq!(text(input, &block_span(&doc.blocks[0])), "# Title");
+        assert_eq!(
+            text(input, &block_span(&doc.blocks[1])),
+            "Some *text*\nmore"
+        );
+        assert_eq!(text(input, &block_span(&doc.blocks[2])), "---");
+
+        let span = block_span(&doc.blocks[1]);
+        assert_eq!((span.start.line, span.start.column), (3, 1));
+        assert_eq!((span.end.line, span.end.column), (4, 5));
+
+        let Block::Heading(heading) = &doc.blocks[0] else {
+            panic!("expected heading");
+        };
+        assert_eq!(text(input, &inline_span(&heading.content[0])), "Title");
+
+        let content = paragraph_content(&doc.blocks[1]);
+        assert_eq!(content.len(), 4);
+        assert_eq!(text(input, &inline_span(&content[0])), "Some ");
+        assert_eq!(text(input, &inline_span(&content[1])), "*text*");
+        let soft_break = inline_span(&content[2]);
+        assert_eq!(text(input, &soft_break), "\n");
+        assert_eq!((soft_break.start.line, soft_break.start.column), (3, 12));
+        assert_eq!(text(input, &inline_span(&content[3])), "more");
+        let Inline::Emphasis { content: inner, .. } = &content[1] else {
+            panic!("expected emphasis");
+        };
+        assert_eq!(text(input, &inline_span(&inner[0])), "text");
+    }
+
+    #[test]
+    fn soft_breaks_cover_line_endings() {
+        let soft_break = |input: &str, content: &[Inline<Span>]| {
+            let soft_break = content
+                .iter()
+                .find(|inline| matches!(inline, Inline::SoftBreak { .. }))
+                .expect("expected soft break");
+            text(input, &inline_span(soft_break)).to_owned()
+        };
+
+        let input = "one\r\ntwo";
+        let doc = parse(input);
+        assert_eq!(soft_break(input, paragraph_content(&doc.blocks[0])), "\r\n");
+
+        let input = "one \ntwo";
+        let doc = parse(input);
+        assert_eq!(soft_break(input, paragraph_content(&doc.blocks[0])), " \n");
+
+        let input = "> one\r\n> two\n";
+        let doc = parse(input);
+        let Block::BlockQuote { blocks, .. } = &doc.blocks[0] else {
+            panic!("expected blockquote");
+        };
+        assert_eq!(soft_break(input, paragraph_content(&blocks[0])), "\r\n");
+
+        let input = "- one\n  two\n";
+        let doc = parse(input);
+        let Block::List(list) = &doc.blocks[0] else {
+            panic!("expected list");
+        };
+        let content = paragraph_content(&list.items[0].blocks[0]);
+        assert_eq!(soft_break(input, content), "\n");
+
+        let input = "[^1]: one\n   two\n";
+        let doc = parse(input);
+        let Block::FootnoteDefinition(footnote) = &doc.blocks[0] else {
+            panic!("expected footnote definition");
+        };
+        assert_eq!(
+            soft_break(input, paragraph_content(&footnote.blocks[0])),
+            "\n"
+        );
+    }
+
+    #[test]
+    fn list_items() {
+        let input = "- first\n- second **bold**\n  continued\n\n  - nested\n";
+        let doc = parse(input);
+
+        let Block::List(list) = &doc.blocks[0] else {
+            panic!("expected list");
+        };
+        assert_eq!(text(input, &list.user_data), input.trim_end());
+        assert_eq!(text(input, &list.items[0].user_data), "- first");
+        assert_eq!(
+            text(input, &list.items[1].user_data),
+            "- second **bold**\n  continued\n\n  - nested"
+        );
+
+        let item = &list.items[1];
+        let content = paragraph_content(&item.blocks[0]);
+        assert_eq!(
+            text(input, &block_span(&item.blocks[0])),
+            "second **bold**\n  continued"
+        );
+        let strong = inline_span(&content[1]);
+        assert_eq!(text(input, &strong), "**bold**");
+        assert_eq!((strong.start.line, strong.start.column), (2, 10));
+
+        let Block::List(nested) = &item.blocks[1] else {
+            panic!("expected nested list");
+        };
+        let nested_paragraph = &nested.items[0].blocks[0];
+        assert_eq!(text(input, &block_span(nested_paragraph)), "nested");
+        let span = block_span(nested_paragraph);
+        assert_eq!((span.start.line, span.start.column), (5, 5));
+    }
+
+    #[test]
+    fn blockquote_strips_markers() {
+        let input = "> first\n> *second*\n>\n> > deep\n";
+        let doc = parse(input);
+
+        let Block::BlockQuote { blocks, user_data } = &doc.blocks[0] else {
+            panic!("expected blockquote");
+        };
+        assert_eq!(text(input, user_data), input.trim_end());
+
+        let content = paragraph_content(&blocks[0]);
+        assert_eq!(text(input, &block_span(&blocks[0])), "first\n> *second*");
+        assert_eq!(text(input, &inline_span(&content[2])), "*second*");
+
+        let Block::BlockQuote { blocks: deep, .. } = &blocks[1] else {
+            panic!("expected nested blockquote");
+        };
+        let span = block_span(&deep[0]);
+        assert_eq!(text(input, &span), "deep");
+        assert_eq!((span.start.line, span.start.column), (4, 5));
+    }
+
+    #[test]
+    fn github_alert_content() {
+        let input = "> [!NOTE]\n> Read `this`\n";
+        let doc = parse(input);
+
+        let Block::GitHubAlert(alert) = &doc.blocks[0] else {
+            panic!("expected alert");
+        };
+        assert_eq!(text(input, &alert.user_data), input.trim_end());
+        let content = paragraph_content(&alert.blocks[0]);
+        assert_eq!(text(input, &inline_span(&content[1])), "`this`");
+    }
+
+    #[test]
+    fn footnote_definition_content() {
+        let input = "[^1]: First\n   second _line_\n";
+        let doc = parse(input);
+
+        let Block::FootnoteDefinition(footnote) = &doc.blocks[0] else {
+            panic!("expected footnote definition");
+        };
+        let content = paragraph_content(&footnote.blocks[0]);
+        assert_eq!(text(input, &inline_span(&content[3])), "_line_");
+    }
+
+    #[test]
+    fn table_cells() {
+        let input = "| a | b \\| c |\n|---|---|\n| **x** |\n";
+        let doc = parse(input);
+
+        let Block::Table(table) = &doc.blocks[0] else {
+            panic!("expected table");
+        };
+        assert_eq!(text(input, &inline_span(&table.rows[0][0][0])), "a");
+        assert_eq!(text(input, &inline_span(&table.rows[0][1][0])), "b \\| c");
+        assert_eq!(text(input, &inline_span(&table.rows[1][0][0])), "**x**");
+        // Missing cells are padded and get the span of their row
+        assert_eq!(text(input, &inline_span(&table.rows[1][1][0])), "| **x** |");
+    }
+
+    #[test]
+    fn links() {
+        let input = "See [the *docs*](/docs) and [full][ref].\n";
+        let doc = parse(input);
+        let content = paragraph_content(&doc.blocks[0]);
+
+        let Inline::Link(link) = &content[1] else {
+            panic!("expected link");
+        };
+        assert_eq!(text(input, &link.user_data), "[the *docs*](/docs)");
+        assert_eq!(text(input, &inline_span(&link.children[1])), "*docs*");
+
+        let Inline::LinkReference(reference) = &content[3] else {
+            panic!("expected link reference");
+        };
+        assert_eq!(text(input, &reference.user_data), "[full][ref]");
+        assert_eq!(text(input, &inline_span(&reference.text[0])), "full");
+        assert_eq!(text(input, &inline_span(&reference.label[0])), "ref");
+    }
+
+    #[test]
+    fn triple_emphasis() {
+        let input = "***both***";
+        let doc = parse(input);
+        let content = paragraph_content(&doc.blocks[0]);
+
+        let Inline::Strong { content: inner, .. } = &content[0] else {
+            panic!("expected strong");
+        };
+        assert_eq!(text(input, &inline_span(&inner[0])), "***both***");
+        let Inline::Emphasis {
+            content: text_nodes,
+            ..
+        } = &inner[0]
+        else {
+            panic!("expected emphasis");
+        };
+        assert_eq!(text(input, &inline_span(&text_nodes[0])), "both");
+    }
+
+    #[test]
+    fn columns_count_characters() {
+        let input = "- привет *мир*\n";
+        let doc = parse(input);
+
+        let Block::List(list) = &doc.blocks[0] else {
+            panic!("expected list");
+        };
+        let content = paragraph_content(&list.items[0].blocks[0]);
+        let span = inline_span(&content[1]);
+        assert_eq!(text(input, &span), "*мир*");
+        assert_eq!(span.start.column, 10);
+        assert_eq!(span.end.column, 15);
+    }
+
+    #[test]
+    fn mapped_blocks_fall_back_to_enclosing_span() {
+        let config = MarkdownParserConfig::default().with_block_blockquote_behavior(
+            ElementBehavior::FlatMap(Arc::new(|block| match block {
+                crate::ast::Block::BlockQuote(blocks) => blocks,
+                other => vec![other],
+            })),
+        );
+        let input = "> a\n>\n> b\n";
+        let doc =
+            parse_markdown_with_spans(MarkdownParserState::with_config(config), input).unwrap();
+
+        assert_eq!(doc.blocks.len(), 2);
+        assert_eq!(text(input, &block_span(&doc.blocks[0])), input.trim_end());
+        assert_eq!(text(input, &block_span(&doc.blocks[1])), input.trim_end());
+    }
+}
@@ -68,14 +68,12 @@ where
             }
             crate::parser::config::ElementBehavior::Map(f) => {
                 let (i, o) = inner.parse(input)?;
-                let mut f1 = (**f).borrow_mut();
-                let mapped = vec![(f1.as_mut())(o)];
+                let mapped = vec![f(o)];
                 Ok((i, mapped))
             }
             crate::parser::config::ElementBehavior::FlatMap(f) => {
                 let (i, o) = inner.parse(input)?;
-                let mut f1 = (**f).borrow_mut();
-                let mapped = (f1.as_mut())(o);
+                let mapped = f(o);
                 Ok((i, mapped))
             }
         }
@@ -2,8 +2,11 @@ use crate::ast::*;
 use crate::plaintext_printer::ToDoc;
 use pretty::{Arena, DocAllocator, DocBuilder};
 
-fn is_visible_block(block: &Block) -> bool {
-    !matches!(block, Block::HtmlBlock(_) | Block::Definition(_) | Block::Empty)
+pub(crate) fn is_visible_block(block: &Block) -> bool {
+    !matches!(
+        block,
+        Block::HtmlBlock(_) | Block::Definition(_) | Block::FrontMatter { .. } | Block::Empty
+    )
 }
 
 impl<'a> ToDoc<'a> for Vec<Block> {
@@ -35,9 +38,12 @@ impl<'a> ToDoc<'a> for Block {
             Block::ThematicBreak => state.arena.text("---"),
             Block::BlockQuote(blocks) => blocks.to_doc(state),
             Block::List(v) => v.to_doc(state),
+            Block::DefinitionList(v) => v.to_doc(state),
             Block::CodeBlock(v) => state.arena.text(v.literal.trim_end_matches('\n').to_string()),
+            Block::Math(math) => state.arena.text(math.clone()),
             Block::HtmlBlock(_) => state.arena.nil(),
             Block::Definition(_) => state.arena.nil(),
+            Block::FrontMatter { .. } => state.arena.nil(),
             Block::Table(v) => v.to_doc(state),
             Block::FootnoteDefinition(def) => def.to_doc(state),
             Block::GitHubAlert(alert) => alert.to_doc(state),
@@ -80,6 +86,31 @@ impl<'a> ToDoc<'a> for List {
     }
 }
 
+impl<'a> ToDoc<'a> for DefinitionList {
+    fn to_doc(
+        &self,
+        state: &'a crate::plaintext_printer::State<'a>,
+    ) -> DocBuilder<'a, Arena<'a>, ()> {
+        let mut result = state.arena.nil();
+        for (i, item) in self.items.iter().enumerate() {
+            if i > 0 {
+                result = result.append(state.arena.hardline());
+            }
+            result = result.append(item.term.to_doc(state));
+            for definition in &item.definitions {
+                result = result.append(
+                    state
+                        .arena
+                        .hardline()
+                        .append(definition.to_doc(state))
+                        .nest(2),
+                );
+            }
+        }
+        result
+    }
+}
+
 impl<'a> ToDoc<'a> for Table {
     fn to_doc(
         &self,
@@ -20,11 +20,16 @@ impl<'a> ToDoc<'a> for Inline {
     ) -> DocBuilder<'a, Arena<'a>, ()> {
         match self {
             Inline::Text(t) => state.arena.text(t.clone()),
-            Inline::LineBreak => state.arena.hardline(),
+            Inline::LineBreak | Inline::SoftBreak => state.arena.hardline(),
             Inline::Code(code) => state.arena.text(code.clone()),
+            Inline::Math(math) => state.arena.text(math.clone()),
             Inline::Html(_) => state.arena.nil(),
-            Inline::Emphasis(children) => children.to_doc(state),
-            Inline::Strong(children) => children.to_doc(state),
+            Inline::Emphasis(Emphasis {
+                content: children, ..
+            }) => children.to_doc(state),
+            Inline::Strong(Emphasis {
+                content: children, ..
+            }) => children.to_doc(state),
             Inline::Strikethrough(children) => children.to_doc(state),
             Inline::Link(Link { children, .. }) => children.to_doc(state),
             Inline::Image(Image { alt, .. }) => state.arena.text(alt.clone()),
@@ -16,7 +16,7 @@
 //!         }),
 //!         Block::Paragraph(vec![
 //!             Inline::Text("This is ".to_string()),
-//!             Inline::Strong(vec![Inline::Text("bold".to_string())]),
+//!             Inline::Strong(vec![Inline::Text("bold".to_string())].into()),
 //!             Inline::Text(" text.".to_string()),
 //!         ]),
 //!     ],
@@ -39,13 +39,13 @@ mod tests;
 use crate::ast::*;
 use config::Config;
 use pretty::{Arena, DocBuilder};
-use std::collections::HashMap;
+use std::{collections::HashMap, rc::Rc};
 
 /// Internal rendering state for plaintext generation
 pub(crate) struct State<'a> {
     arena: Arena<'a>,
-    config: Config,
-    footnote_index: HashMap<String, usize>,
+    config: Rc<Config>,
+    footnote_index: Rc<HashMap<String, usize>>,
 }
 
 impl State<'_> {
@@ -54,8 +54,17 @@ impl State<'_> {
         let arena = Arena::new();
         Self {
             arena,
-            config,
-            footnote_index,
+            config: Rc::new(config),
+            footnote_index: Rc::new(footnote_index),
+        }
+    }
+
+    /// Same state with a fresh arena, for rendering the next top-level block
+    fn with_new_arena<'b>(&self) -> State<'b> {
+        State {
+            arena: Arena::new(),
+            config: self.config.clone(),
+            footnote_index: self.footnote_index.clone(),
         }
     }
 
@@ -84,7 +93,7 @@ impl State<'_> {
 /// let doc = Document {
 ///     blocks: vec![Block::Paragraph(vec![
 ///         Inline::Text("Hello ".to_string()),
-///         Inline::Strong(vec![Inline::Text("world".to_string())]),
+///         Inline::Strong(vec![Inline::Text("world".to_string())].into()),
 ///     ])],
 /// };
 ///
@@ -92,15 +101,55 @@ impl State<'_> {
 /// assert_eq!(text, "Hello world");
 /// ```
 pub fn render_plaintext(ast: &Document, config: Config) -> String {
-    let state = State::new(config, ast);
-    let doc = ast.to_doc(&state);
-
     let mut buf = Vec::new();
-    doc.render(state.config.width, &mut buf)
-        .expect("Vec<u8> write is infallible");
+    render_plaintext_to(ast, config, &mut buf).expect("Vec<u8> write is infallible");
     String::from_utf8(buf).expect("pretty crate always produces valid UTF-8")
 }
 
+/// Render a Markdown AST to plain text, writing to `writer` block by block
+///
+/// Same output as [`render_plaintext`], without building the whole document
+/// in memory first. Wrap unbuffered writers in a [`std::io::BufWriter`].
+///
+/// # Errors
+///
+/// Returns the first error of `writer`.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::plaintext_printer::{render_plaintext_to, config::Config};
+///
+/// let doc = Document {
+///     blocks: vec![
+///         Block::Paragraph(vec![Inline::Text("Hello".to_string())]),
+///         Block::Paragraph(vec![Inline::Text("world".to_string())]),
+///     ],
+/// };
+///
+/// let mut out = Vec::new();
+/// render_plaintext_to(&doc, Config::default(), &mut out).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "Hello\n\nworld");
+/// ```
+pub fn render_plaintext_to<W: std::io::Write>(
+    ast: &Document,
+    config: Config,
+    mut writer: W,
+) -> std::io::Result<()> {
+    let state = State::new(config, ast);
+    let blocks = ast.blocks.iter().filter(|b| block::is_visible_block(b));
+    for (i, block) in blocks.enumerate() {
+        if i > 0 {
+            writer.write_all(b"\n\n")?;
+        }
+        let block_state = state.with_new_arena();
+        let doc = block.to_doc(&block_state);
+        doc.render(block_state.config.width, &mut writer)?;
+    }
+    Ok(())
+}
+
 trait ToDoc<'a> {
     fn to_doc(&self, state: &'a State<'a>) -> DocBuilder<'a, Arena<'a>, ()>;
 }
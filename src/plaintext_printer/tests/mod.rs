@@ -90,6 +90,19 @@ fn html_block_is_skipped() {
     assert_eq!("Before\n\nAfter", result);
 }
 
+#[test]
+fn front_matter_is_skipped() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+
+    let config = crate::plaintext_printer::config::Config::default();
+    let state = crate::parser::MarkdownParserState::with_config(
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Parse),
+    );
+    let ast = crate::parser::parse_markdown(state, "+++\ntitle = \"Hello\"\n+++\n\nText").unwrap();
+    let result = crate::plaintext_printer::render_plaintext(&ast, config);
+    assert_eq!("Text", result);
+}
+
 #[test]
 fn unicode_text() {
     let config = crate::plaintext_printer::config::Config::default();
@@ -125,3 +138,19 @@ fn github_alert() {
     let result = crate::plaintext_printer::render_plaintext(&ast, config);
     assert_eq!("[Note]\nImportant info here", result);
 }
+
+#[test]
+fn render_to_writer() {
+    let config = crate::plaintext_printer::config::Config::default;
+    let ast = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::default(),
+        "<div>hidden</div>\n\nFirst[^n]\n\n[a]: /url\n\n- one[^m]\n- two[^n]\n\n[^n]: Note.\n\n[^m]: Other.",
+    )
+    .unwrap();
+    let mut out = Vec::new();
+    crate::plaintext_printer::render_plaintext_to(&ast, config(), &mut out).unwrap();
+    assert_eq!(
+        "First[1]\n\n- one[2]\n- two[1]\n\n[1] Note.\n\n[2] Other.",
+        String::from_utf8(out).unwrap()
+    );
+}
@@ -1,4 +1,5 @@
 use crate::ast::*;
+use crate::printer::config::{BulletStyle, Config, FenceChar, ThematicBreakStyle};
 use crate::printer::{inline::ToDocInline, ToDoc};
 use pretty::{Arena, DocAllocator, DocBuilder};
 use std::rc::Rc;
@@ -21,25 +22,111 @@ impl<'a> ToDoc<'a> for Vec<&Block> {
         arena: &'a Arena<'a>,
     ) -> DocBuilder<'a, Arena<'a>, ()> {
         let mut acc = arena.nil();
-        for (i, block) in self.iter().enumerate() {
+        let printed = printed_blocks(self.iter().copied(), &config);
+        for (i, (block, bullet)) in printed.into_iter().enumerate() {
             if i > 0 {
                 // first block should not have an empty line before it
                 acc = acc.append(arena.hardline());
-                if matches!(block, Block::List(_)) {
-                    if config.empty_line_before_list {
-                        // empty line before list block
-                        acc = acc.append(arena.hardline());
-                    }
-                } else {
+                if empty_line_before(block, &config) {
                     acc = acc.append(arena.hardline());
                 }
             }
-            acc = acc.append(block.to_doc(config.clone(), arena))
+            acc = acc.append(printed_block_to_doc(block, bullet, config.clone(), arena))
         }
         acc
     }
 }
 
+/// Whether a block is separated from the previous block by an empty line
+pub(crate) fn empty_line_before(block: &Block, config: &Config) -> bool {
+    !matches!(block, Block::List(_)) || config.empty_line_before_list
+}
+
+/// The blocks of a container that are printed, with the bullet of bullet
+/// lists.
+///
+/// Link definitions are left out when they are printed at the end of the
+/// document. A bullet list directly following another one gets a different
+/// bullet, otherwise both would be parsed as one list.
+pub(crate) fn printed_blocks<'b>(
+    blocks: impl IntoIterator<Item = &'b Block>,
+    config: &Config,
+) -> Vec<(&'b Block, Option<ListBulletKind>)> {
+    let mut printed = Vec::new();
+    let mut previous_bullet = None;
+    for block in blocks {
+        if config.link_definitions_at_end && matches!(block, Block::Definition(_)) {
+            continue;
+        }
+        let bullet = match block {
+            Block::List(List {
+                kind: ListKind::Bullet(kind),
+                ..
+            }) => {
+                let bullet = styled_bullet(*kind, config);
+                match (previous_bullet, bullet) {
+                    (Some(ListBulletKind::Dash), ListBulletKind::Dash) => {
+                        Some(ListBulletKind::Star)
+                    }
+                    (Some(previous), bullet) if previous == bullet => Some(ListBulletKind::Dash),
+                    _ => Some(bullet),
+                }
+            }
+            _ => None,
+        };
+        previous_bullet = bullet;
+        printed.push((block, bullet));
+    }
+    printed
+}
+
+/// The bullet of a bullet list with `kind`, after [`Config::with_bullet_style`]
+pub(crate) fn styled_bullet(kind: ListBulletKind, config: &Config) -> ListBulletKind {
+    match config.bullet_style {
+        BulletStyle::Preserve => kind,
+        BulletStyle::Dash => ListBulletKind::Dash,
+        BulletStyle::Star => ListBulletKind::Star,
+        BulletStyle::Plus => ListBulletKind::Plus,
+    }
+}
+
+/// A block from [`printed_blocks`]
+pub(crate) fn printed_block_to_doc<'a>(
+    block: &Block,
+    bullet: Option<ListBulletKind>,
+    config: Rc<Config>,
+    arena: &'a Arena<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    match block {
+        Block::List(list) => crate::printer::list::list_to_doc(list, bullet, config, arena),
+        block => block.to_doc(config, arena),
+    }
+}
+
+/// Link definitions in `blocks` and the containers among them, in document
+/// order
+pub(crate) fn link_definitions<'b>(blocks: &'b [Block], definitions: &mut Vec<&'b Block>) {
+    for block in blocks {
+        match block {
+            Block::Definition(_) => definitions.push(block),
+            Block::BlockQuote(blocks) => link_definitions(blocks, definitions),
+            Block::List(list) => {
+                for item in &list.items {
+                    link_definitions(&item.blocks, definitions);
+                }
+            }
+            Block::DefinitionList(list) => {
+                for definition in list.items.iter().flat_map(|item| &item.definitions) {
+                    link_definitions(definition, definitions);
+                }
+            }
+            Block::FootnoteDefinition(footnote) => link_definitions(&footnote.blocks, definitions),
+            Block::GitHubAlert(alert) => link_definitions(&alert.blocks, definitions),
+            _ => {}
+        }
+    }
+}
+
 /// Block-level nodes
 impl<'a> ToDoc<'a> for Block {
     fn to_doc(
@@ -48,21 +135,27 @@ impl<'a> ToDoc<'a> for Block {
         arena: &'a Arena<'a>,
     ) -> DocBuilder<'a, Arena<'a>, ()> {
         match self {
-            Block::Paragraph(inlines) => inlines.to_doc_inline(true, arena),
+            Block::Paragraph(inlines) => inlines.to_doc_inline(true, &config, arena),
             Block::Heading(v) => v.to_doc(config, arena),
-            Block::ThematicBreak => arena.text("---"),
+            Block::ThematicBreak => arena.text(match config.thematic_break_style {
+                ThematicBreakStyle::Dash => "---",
+                ThematicBreakStyle::Star => "***",
+                ThematicBreakStyle::Underscore => "___",
+            }),
             Block::BlockQuote(inner) => {
                 crate::printer::blockquote::blockquote_to_doc(config, arena, inner)
             }
             Block::List(v) => v.to_doc(config, arena),
+            Block::DefinitionList(v) => v.to_doc(config, arena),
             Block::CodeBlock(CodeBlock { kind, literal }) => {
                 match kind {
                     CodeBlockKind::Fenced { info } => {
                         let info = info.as_deref().unwrap_or("");
+                        let fence = code_fence(info, literal, &config);
                         // Use hardline() between lines so nest() indentation applies correctly
                         // when the code block is inside a list or other nested structure.
                         // We use split('\n') instead of lines() to preserve trailing newlines.
-                        let mut doc = arena.text(format!("```{info}"));
+                        let mut doc = arena.text(format!("{fence}{info}"));
 
                         // Handle code block content.
                         // For non-empty content, we use split('\n') instead of lines() to preserve
@@ -85,7 +178,7 @@ impl<'a> ToDoc<'a> for Block {
                         }
 
                         // Closing fence must be on its own line
-                        doc.append(arena.hardline()).append(arena.text("```"))
+                        doc.append(arena.hardline()).append(arena.text(fence))
                     }
                     CodeBlockKind::Indented => {
                         // Each line indented with 4 spaces
@@ -98,10 +191,34 @@ impl<'a> ToDoc<'a> for Block {
                     }
                 }
             }
+            Block::Math(math) => {
+                // Same line handling as in fenced code blocks, so that nest()
+                // indentation applies inside lists
+                let mut doc = arena.text("$$");
+                for line in math.split('\n') {
+                    doc = doc.append(arena.hardline());
+                    if !line.trim_start().is_empty() {
+                        doc = doc.append(arena.text(line.to_string()));
+                    }
+                }
+                doc.append(arena.hardline()).append(arena.text("$$"))
+            }
             Block::HtmlBlock(html) => arena.text(html.clone()),
+            Block::FrontMatter { format, raw } => {
+                let fence = format.fence();
+                let mut doc = arena.text(fence);
+                if !raw.is_empty() {
+                    for line in raw.split('\n') {
+                        doc = doc
+                            .append(arena.hardline())
+                            .append(arena.text(line.to_string()));
+                    }
+                }
+                doc.append(arena.hardline()).append(arena.text(fence))
+            }
             Block::Definition(def) => arena
                 .text("[")
-                .append(def.label.to_doc_inline(true, arena))
+                .append(def.label.to_doc_inline(true, &config, arena))
                 .append(arena.text("]: "))
                 .append(arena.text(format!(
                     "{}{}",
@@ -123,3 +240,26 @@ impl<'a> ToDoc<'a> for Block {
         }
     }
 }
+
+/// Opening and closing fence of a fenced code block
+fn code_fence(info: &str, literal: &str, config: &Config) -> String {
+    // Info strings of backtick fences cannot contain backticks
+    let fence_char = match config.code_fence_char {
+        FenceChar::Backtick if !info.contains('`') => '`',
+        _ => '~',
+    };
+    // A line of the code starting with a fence at least as long would close
+    // the block
+    let longest_in_code = literal
+        .lines()
+        .map(|line| {
+            line.trim_start()
+                .chars()
+                .take_while(|&c| c == fence_char)
+                .count()
+        })
+        .max()
+        .unwrap_or(0);
+    let length = config.code_fence_length.max(3).max(longest_in_code + 1);
+    fence_char.to_string().repeat(length)
+}
@@ -1,8 +1,121 @@
+/// Delimiter used to print emphasis or strong emphasis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DelimiterStyle {
+    /// Use the delimiter recorded in the AST (`*` for hand-built nodes).
+    #[default]
+    Preserve,
+
+    /// Always use `*` / `**`.
+    Asterisk,
+
+    /// Use `_` / `__`, except inside words, where underscores do not work.
+    Underscore,
+}
+
+/// Marker of bullet list items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BulletStyle {
+    /// Use the marker recorded in the AST.
+    #[default]
+    Preserve,
+
+    /// Always use `-`.
+    Dash,
+
+    /// Always use `*`.
+    Star,
+
+    /// Always use `+`.
+    Plus,
+}
+
+/// Numbering of ordered list items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrderedListNumbering {
+    /// Count up from the start number: `1.`, `2.`, `3.`.
+    #[default]
+    Sequential,
+
+    /// Repeat the start number for every item, e.g. `1.`, `1.`, `1.`, so that
+    /// inserting items does not renumber the others.
+    Repeated,
+}
+
+/// Syntax of level 1 and 2 headings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HeadingStyle {
+    /// Use the syntax recorded in the AST.
+    #[default]
+    Preserve,
+
+    /// Always use `#` / `##`.
+    Atx,
+
+    /// Underline level 1 and 2 headings with `===` / `---`. Other levels
+    /// always use ATX syntax.
+    Setext,
+}
+
+/// Character of code fences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FenceChar {
+    /// `` ``` ``
+    #[default]
+    Backtick,
+
+    /// `~~~`
+    Tilde,
+}
+
+/// Syntax of thematic breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThematicBreakStyle {
+    /// `---`
+    #[default]
+    Dash,
+
+    /// `***`
+    Star,
+
+    /// `___`
+    Underscore,
+}
+
+/// Line breaking of paragraph text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProseWrap {
+    /// Reflow paragraphs to fit the configured width.
+    #[default]
+    Always,
+
+    /// Print every paragraph on one line.
+    Never,
+
+    /// Keep the line breaks of the source and never add new ones.
+    Preserve,
+
+    /// Print every sentence on its own line. A sentence ends with `.`, `!` or
+    /// `?`, optionally followed by closing quotes or brackets, before a word
+    /// that does not start with a lowercase letter.
+    Sentence,
+}
+
 /// Configuration for Markdown pretty-printing output.
+#[derive(Clone)]
 pub struct Config {
     pub(crate) width: usize,
     pub(crate) spaces_before_list_item: usize,
     pub(crate) empty_line_before_list: bool,
+    pub(crate) emphasis_style: DelimiterStyle,
+    pub(crate) strong_style: DelimiterStyle,
+    pub(crate) bullet_style: BulletStyle,
+    pub(crate) ordered_list_numbering: OrderedListNumbering,
+    pub(crate) heading_style: HeadingStyle,
+    pub(crate) code_fence_char: FenceChar,
+    pub(crate) code_fence_length: usize,
+    pub(crate) thematic_break_style: ThematicBreakStyle,
+    pub(crate) link_definitions_at_end: bool,
+    pub(crate) prose_wrap: ProseWrap,
 }
 
 impl Default for Config {
@@ -11,6 +124,16 @@ impl Default for Config {
             width: 80,
             spaces_before_list_item: 1,
             empty_line_before_list: true,
+            emphasis_style: DelimiterStyle::Preserve,
+            strong_style: DelimiterStyle::Preserve,
+            bullet_style: BulletStyle::Preserve,
+            ordered_list_numbering: OrderedListNumbering::Sequential,
+            heading_style: HeadingStyle::Preserve,
+            code_fence_char: FenceChar::Backtick,
+            code_fence_length: 3,
+            thematic_break_style: ThematicBreakStyle::Dash,
+            link_definitions_at_end: false,
+            prose_wrap: ProseWrap::Always,
         }
     }
 }
@@ -50,4 +173,111 @@ impl Config {
             ..self
         }
     }
+
+    /// Sets the delimiter of emphasis (`*text*` or `_text_`).
+    ///
+    /// The default is [`DelimiterStyle::Preserve`], which keeps the delimiter
+    /// used in the source.
+    pub fn with_emphasis_style(self, emphasis_style: DelimiterStyle) -> Self {
+        Self {
+            emphasis_style,
+            ..self
+        }
+    }
+
+    /// Sets the delimiter of strong emphasis (`**text**` or `__text__`).
+    ///
+    /// The default is [`DelimiterStyle::Preserve`], which keeps the delimiter
+    /// used in the source.
+    pub fn with_strong_style(self, strong_style: DelimiterStyle) -> Self {
+        Self {
+            strong_style,
+            ..self
+        }
+    }
+
+    /// Sets the marker of bullet list items.
+    ///
+    /// The default is [`BulletStyle::Preserve`]. A bullet list directly
+    /// following another one gets a different marker, otherwise both would be
+    /// parsed as one list.
+    pub fn with_bullet_style(self, bullet_style: BulletStyle) -> Self {
+        Self {
+            bullet_style,
+            ..self
+        }
+    }
+
+    /// Sets the numbering of ordered list items.
+    ///
+    /// The default is [`OrderedListNumbering::Sequential`].
+    pub fn with_ordered_list_numbering(self, ordered_list_numbering: OrderedListNumbering) -> Self {
+        Self {
+            ordered_list_numbering,
+            ..self
+        }
+    }
+
+    /// Sets the syntax of headings.
+    ///
+    /// The default is [`HeadingStyle::Preserve`].
+    pub fn with_heading_style(self, heading_style: HeadingStyle) -> Self {
+        Self {
+            heading_style,
+            ..self
+        }
+    }
+
+    /// Sets the character of code fences.
+    ///
+    /// The default is [`FenceChar::Backtick`]. Fences with an info string
+    /// containing a backtick always use tildes.
+    pub fn with_code_fence_char(self, code_fence_char: FenceChar) -> Self {
+        Self {
+            code_fence_char,
+            ..self
+        }
+    }
+
+    /// Sets the length of code fences.
+    ///
+    /// The default is 3, lengths below 3 are treated as 3. Fences are made
+    /// longer where the code contains a fence of the same length.
+    pub fn with_code_fence_length(self, code_fence_length: usize) -> Self {
+        Self {
+            code_fence_length,
+            ..self
+        }
+    }
+
+    /// Sets the syntax of thematic breaks.
+    ///
+    /// The default is [`ThematicBreakStyle::Dash`].
+    pub fn with_thematic_break_style(self, thematic_break_style: ThematicBreakStyle) -> Self {
+        Self {
+            thematic_break_style,
+            ..self
+        }
+    }
+
+    /// Sets whether link reference definitions are moved to the end of the
+    /// document.
+    ///
+    /// The default is `false`, which keeps them where they are. When `true`,
+    /// all definitions, including those in block quotes and lists, are
+    /// printed in document order after the last block.
+    pub fn with_link_definitions_at_end(self, link_definitions_at_end: bool) -> Self {
+        Self {
+            link_definitions_at_end,
+            ..self
+        }
+    }
+
+    /// Sets how paragraph text is broken into lines.
+    ///
+    /// The default is [`ProseWrap::Always`]. Headings and table cells are
+    /// never broken, hard line breaks are always kept.
+    pub fn with_prose_wrap(self, prose_wrap: ProseWrap) -> Self {
+        Self { prose_wrap, ..self }
+    }
 }
@@ -0,0 +1,29 @@
+use crate::ast::*;
+use crate::printer::{inline::ToDocInline, ToDoc};
+use pretty::{Arena, DocAllocator, DocBuilder};
+use std::rc::Rc;
+
+impl<'a> ToDoc<'a> for DefinitionList {
+    fn to_doc(
+        &self,
+        config: Rc<crate::printer::config::Config>,
+        arena: &'a Arena<'a>,
+    ) -> DocBuilder<'a, Arena<'a>, ()> {
+        let items = self.items.iter().map(|item| {
+            // The term must stay on a single line
+            let mut doc = item.term.to_doc_inline(false, &config, arena);
+            for definition in &item.definitions {
+                doc = doc.append(arena.hardline()).append(
+                    arena
+                        .text(": ")
+                        .append(definition.to_doc(config.clone(), arena))
+                        .nest(2),
+                );
+            }
+            doc
+        });
+
+        // Empty line between items
+        arena.intersperse(items, arena.hardline().append(arena.hardline()))
+    }
+}
@@ -1,4 +1,5 @@
 use crate::ast::*;
+use crate::printer::config::HeadingStyle;
 use crate::printer::{inline::ToDocInline, ToDoc};
 use pretty::{Arena, DocAllocator, DocBuilder};
 use std::rc::Rc;
@@ -6,27 +7,37 @@ use std::rc::Rc;
 impl<'a> ToDoc<'a> for Heading {
     fn to_doc(
         &self,
-        _config: Rc<crate::printer::config::Config>,
+        config: Rc<crate::printer::config::Config>,
         arena: &'a Arena<'a>,
     ) -> DocBuilder<'a, Arena<'a>, ()> {
-        match self.kind {
-            HeadingKind::Atx(level) => {
-                let hashes = "#".repeat(level as usize);
-                arena
-                    .text(hashes)
-                    .append(arena.space())
-                    .append(self.content.to_doc_inline(false, arena))
-            }
-            HeadingKind::Setext(SetextHeading::Level1) => self
-                .content
-                .to_doc_inline(true, arena)
-                .append(arena.hardline())
-                .append(arena.text("==========")),
-            HeadingKind::Setext(SetextHeading::Level2) => self
-                .content
-                .to_doc_inline(true, arena)
+        let level = match self.kind {
+            HeadingKind::Atx(level) => level,
+            HeadingKind::Setext(SetextHeading::Level1) => 1,
+            HeadingKind::Setext(SetextHeading::Level2) => 2,
+        };
+        // Setext headings only exist for levels 1 and 2 and need content
+        let setext = match config.heading_style {
+            HeadingStyle::Preserve => matches!(self.kind, HeadingKind::Setext(_)),
+            HeadingStyle::Atx => false,
+            HeadingStyle::Setext => level <= 2 && !self.content.is_empty(),
+        };
+
+        if setext {
+            let underline = if level == 1 {
+                "=========="
+            } else {
+                "----------"
+            };
+            self.content
+                .to_doc_inline(true, &config, arena)
                 .append(arena.hardline())
-                .append(arena.text("----------")),
+                .append(arena.text(underline))
+        } else {
+            let hashes = "#".repeat(level as usize);
+            arena
+                .text(hashes)
+                .append(arena.space())
+                .append(self.content.to_doc_inline(false, &config, arena))
         }
     }
 }
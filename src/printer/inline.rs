@@ -1,11 +1,14 @@
 use crate::ast::*;
-use crate::printer::markdown_syntax_detector::is_safe_line_break_before;
+use crate::printer::config::{Config, DelimiterStyle, ProseWrap};
+use crate::printer::markdown_syntax_detector::{escape_line_start, is_safe_line_break_before};
 use pretty::{Arena, DocAllocator, DocBuilder};
+use std::borrow::Cow;
 
 pub(crate) trait ToDocInline<'a> {
     fn to_doc_inline(
         &self,
         allow_newlines: bool,
+        config: &Config,
         arena: &'a Arena<'a>,
     ) -> DocBuilder<'a, Arena<'a>, ()>;
 }
@@ -14,11 +17,27 @@ impl<'a> ToDocInline<'a> for Vec<Inline> {
     fn to_doc_inline(
         &self,
         allow_newlines: bool,
+        config: &Config,
         arena: &'a Arena<'a>,
     ) -> DocBuilder<'a, Arena<'a>, ()> {
+        let inlines = join_soft_breaks(self);
         arena.concat(
-            self.iter()
-                .map(|inline| inline.to_doc_inline(allow_newlines, arena))
+            inlines
+                .iter()
+                .enumerate()
+                .map(|(i, inline)| match inline {
+                    Inline::Emphasis(_) | Inline::Strong(_) => {
+                        let intraword = (i > 0 && ends_with_word_char(&inlines[i - 1]))
+                            || inlines.get(i + 1).is_some_and(starts_with_word_char);
+                        emphasis_doc(inline, intraword, allow_newlines, config, arena)
+                    }
+                    // Text after a hard line break starts a line of its own
+                    Inline::Text(t) if i > 0 && inlines[i - 1] == Inline::LineBreak => {
+                        let text = Inline::Text(escape_line_start(t).into_owned());
+                        text.to_doc_inline(allow_newlines, config, arena)
+                    }
+                    inline => inline.to_doc_inline(allow_newlines, config, arena),
+                })
                 .collect::<Vec<_>>(),
         )
     }
@@ -28,20 +47,35 @@ impl<'a> ToDocInline<'a> for Inline {
     fn to_doc_inline(
         &self,
         allow_newlines: bool,
+        config: &Config,
         arena: &'a Arena<'a>,
     ) -> DocBuilder<'a, Arena<'a>, ()> {
         match self {
             Inline::Text(t) => {
+                if allow_newlines && config.prose_wrap == ProseWrap::Preserve {
+                    // Source line breaks are kept. The escapes that kept a line from
+                    // starting a new block are gone, so they are added back
+                    let last = t.split('\n').count() - 1;
+                    let lines = t.split('\n').enumerate().map(|(i, line)| {
+                        let line = if i > 0 {
+                            escape_line_start(line.trim_start())
+                        } else {
+                            Cow::Borrowed(line)
+                        };
+                        let line = if i < last { line.trim_end() } else { &line };
+                        spaced_text(&split_with_spaces(line), arena)
+                    });
+                    return arena.intersperse(lines, arena.hardline());
+                }
+
                 let t = t.replace('\n', " ");
                 let words_or_spaces: Vec<_> = split_with_spaces(&t);
 
-                if !allow_newlines {
+                if !allow_newlines || config.prose_wrap == ProseWrap::Never {
                     // If newlines are not allowed, use simple space separators
-                    let words_or_spaces = words_or_spaces.into_iter().map(|v| match v {
-                        Some(v) => arena.text(v.to_string()),
-                        None => arena.space(),
-                    });
-                    arena.concat(words_or_spaces)
+                    spaced_text(&words_or_spaces, arena)
+                } else if config.prose_wrap == ProseWrap::Sentence {
+                    sentence_text_layout(&words_or_spaces, arena)
                 } else {
                     // Use smart line breaking when newlines are allowed
                     safe_text_layout(&words_or_spaces, arena)
@@ -49,19 +83,18 @@ impl<'a> ToDocInline<'a> for Inline {
             }
             // TODO parametrize format
             Inline::LineBreak => arena.text("  \n"),
+            Inline::SoftBreak => {
+                Inline::Text("\n".to_owned()).to_doc_inline(allow_newlines, config, arena)
+            }
             Inline::Code(code) => arena.text("`").append(code.clone()).append(arena.text("`")),
+            Inline::Math(math) => arena.text("$").append(math.clone()).append(arena.text("$")),
             Inline::Html(html) => arena.text(html.clone()),
-            Inline::Emphasis(children) => arena
-                .text("*")
-                .append(children.to_doc_inline(allow_newlines, arena))
-                .append(arena.text("*")),
-            Inline::Strong(children) => arena
-                .text("**")
-                .append(children.to_doc_inline(allow_newlines, arena))
-                .append(arena.text("**")),
+            Inline::Emphasis(_) | Inline::Strong(_) => {
+                emphasis_doc(self, false, allow_newlines, config, arena)
+            }
             Inline::Strikethrough(children) => arena
                 .text("~~")
-                .append(children.to_doc_inline(allow_newlines, arena))
+                .append(children.to_doc_inline(allow_newlines, config, arena))
                 .append(arena.text("~~")),
             Inline::Link(Link {
                 destination,
@@ -77,7 +110,7 @@ impl<'a> ToDocInline<'a> for Inline {
                 };
                 arena
                     .text("[")
-                    .append(children.to_doc_inline(allow_newlines, arena))
+                    .append(children.to_doc_inline(allow_newlines, config, arena))
                     .append(arena.text("]("))
                     .append(arena.text(destination.clone()))
                     .append(title)
@@ -107,20 +140,45 @@ impl<'a> ToDocInline<'a> for Inline {
                 if v.label == v.text {
                     return arena
                         .text("[")
-                        .append(v.label.to_doc_inline(allow_newlines, arena))
+                        .append(v.label.to_doc_inline(allow_newlines, config, arena))
                         .append("]");
                 }
                 arena
                     .text("[")
-                    .append(v.text.to_doc_inline(allow_newlines, arena))
+                    .append(v.text.to_doc_inline(allow_newlines, config, arena))
                     .append("][")
-                    .append(v.label.to_doc_inline(allow_newlines, arena))
+                    .append(v.label.to_doc_inline(allow_newlines, config, arena))
                     .append(arena.text("]"))
             }
         }
     }
 }
 
+/// Soft breaks merged into the surrounding text as newlines, so that they are
+/// laid out like the spaces between words
+fn join_soft_breaks(inlines: &[Inline]) -> Cow<'_, [Inline]> {
+    if !inlines.contains(&Inline::SoftBreak) {
+        return Cow::Borrowed(inlines);
+    }
+
+    let mut result: Vec<Inline> = Vec::with_capacity(inlines.len());
+    for inline in inlines {
+        let text = match inline {
+            Inline::Text(t) => t.as_str(),
+            Inline::SoftBreak => "\n",
+            inline => {
+                result.push(inline.clone());
+                continue;
+            }
+        };
+        match result.last_mut() {
+            Some(Inline::Text(previous)) => previous.push_str(text),
+            _ => result.push(Inline::Text(text.to_owned())),
+        }
+    }
+    Cow::Owned(result)
+}
+
 /// Split string by spaces, but keep the spaces in the result.
 fn split_with_spaces(s: &str) -> Vec<Option<&str>> {
     let mut result = Vec::new();
@@ -205,6 +263,59 @@ fn safe_text_layout<'a>(
     result
 }
 
+/// Text with plain spaces, which are never broken
+fn spaced_text<'a>(
+    words_or_spaces: &[Option<&str>],
+    arena: &'a Arena<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    arena.concat(words_or_spaces.iter().map(|v| match v {
+        Some(v) => arena.text(v.to_string()),
+        None => arena.space(),
+    }))
+}
+
+/// Text with a line break after every sentence, see [`ProseWrap::Sentence`]
+///
+/// Like [`safe_text_layout`], a line break is only inserted where the next
+/// word would not be read as markdown syntax at the beginning of a line.
+fn sentence_text_layout<'a>(
+    words_or_spaces: &[Option<&str>],
+    arena: &'a Arena<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let mut result = arena.nil();
+    let mut previous_word = None;
+
+    for (i, v) in words_or_spaces.iter().enumerate() {
+        match v {
+            Some(word) => {
+                result = result.append(arena.text(word.to_string()));
+                previous_word = Some(*word);
+            }
+            None => {
+                let next_word = find_next_word(&words_or_spaces[i + 1..]);
+                let sentence_end = previous_word.is_some_and(ends_sentence)
+                    && next_word.is_some_and(|next| {
+                        !next.starts_with(char::is_lowercase)
+                            && is_safe_line_break_before(next, &[])
+                    });
+                result = result.append(if sentence_end {
+                    arena.hardline()
+                } else {
+                    arena.space()
+                });
+            }
+        }
+    }
+
+    result
+}
+
+/// Whether `word` is the last word of a sentence
+fn ends_sentence(word: &str) -> bool {
+    word.trim_end_matches(['"', '\'', ')', ']', '”', '’'])
+        .ends_with(['.', '!', '?'])
+}
+
 /// Find the next word in a sequence of words and spaces
 ///
 /// # Arguments
@@ -217,3 +328,67 @@ fn safe_text_layout<'a>(
 fn find_next_word<'a>(words_or_spaces: &'a [Option<&'a str>]) -> Option<&'a str> {
     words_or_spaces.iter().flatten().next().copied()
 }
+
+/// Emphasis or strong emphasis with the delimiter chosen by `config`
+///
+/// `intraword` is set when the node touches a letter or digit of the
+/// surrounding text, where `_` does not work as a delimiter.
+fn emphasis_doc<'a>(
+    inline: &Inline,
+    intraword: bool,
+    allow_newlines: bool,
+    config: &Config,
+    arena: &'a Arena<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let (emphasis, count) = match inline {
+        Inline::Emphasis(v) => (v, 1),
+        Inline::Strong(v) => (v, 2),
+        _ => unreachable!("not an emphasis"),
+    };
+    let marker = if uses_underscore(inline, intraword, config) {
+        "_"
+    } else {
+        "*"
+    }
+    .repeat(count);
+    arena
+        .text(marker.clone())
+        .append(
+            emphasis
+                .content
+                .to_doc_inline(allow_newlines, config, arena),
+        )
+        .append(arena.text(marker))
+}
+
+/// Whether the emphasis or strong emphasis `inline` is printed with `_`
+///
+/// `_` only opens emphasis before a letter or digit, or as `___` around
+/// emphasis printed with `_` as well. Everywhere else `*` is used.
+fn uses_underscore(inline: &Inline, intraword: bool, config: &Config) -> bool {
+    let (emphasis, style) = match inline {
+        Inline::Emphasis(v) => (v, config.emphasis_style),
+        Inline::Strong(v) => (v, config.strong_style),
+        _ => return false,
+    };
+    let delimiter = match style {
+        DelimiterStyle::Preserve => emphasis.delimiter,
+        DelimiterStyle::Asterisk => EmphasisDelimiter::Asterisk,
+        DelimiterStyle::Underscore => EmphasisDelimiter::Underscore,
+    };
+    if delimiter != EmphasisDelimiter::Underscore || intraword {
+        return false;
+    }
+    match (inline, emphasis.content.as_slice()) {
+        (Inline::Strong(_), [inner @ Inline::Emphasis(_)]) => uses_underscore(inner, false, config),
+        (_, content) => content.first().is_some_and(starts_with_word_char),
+    }
+}
+
+fn starts_with_word_char(inline: &Inline) -> bool {
+    matches!(inline, Inline::Text(t) if t.chars().next().is_some_and(char::is_alphanumeric))
+}
+
+fn ends_with_word_char(inline: &Inline) -> bool {
+    matches!(inline, Inline::Text(t) if t.chars().last().is_some_and(char::is_alphanumeric))
+}
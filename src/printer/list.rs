@@ -1,62 +1,135 @@
 use crate::ast::*;
+use crate::printer::block::{printed_block_to_doc, printed_blocks, styled_bullet};
+use crate::printer::config::{Config, OrderedListNumbering};
 use crate::printer::ToDoc;
 use pretty::{Arena, DocAllocator, DocBuilder};
 use std::rc::Rc;
 
 impl<'a> ToDoc<'a> for List {
-    fn to_doc(
-        &self,
-        config: Rc<crate::printer::config::Config>,
-        arena: &'a Arena<'a>,
-    ) -> DocBuilder<'a, Arena<'a>, ()> {
-        let mut counter = if let ListKind::Ordered(v) = &self.kind {
-            v.start
-        } else {
-            0
-        };
-        let prefix_length = match &self.kind {
-            ListKind::Bullet(ListBulletKind::Dash) => 2 + config.spaces_before_list_item, // <space>-<space>
-            ListKind::Bullet(ListBulletKind::Star) => 2 + config.spaces_before_list_item, // <space>*<space>
-            ListKind::Bullet(ListBulletKind::Plus) => 2 + config.spaces_before_list_item, // <space>+<space>
-            ListKind::Ordered(v) => {
-                let last = v.start + self.items.len() as u64 - 1;
-                let digits = last.to_string().len();
-                digits + 2 + config.spaces_before_list_item // <space>1.<space>
+    fn to_doc(&self, config: Rc<Config>, arena: &'a Arena<'a>) -> DocBuilder<'a, Arena<'a>, ()> {
+        list_to_doc(self, None, config, arena)
+    }
+}
+
+/// A list, bullet lists with `bullet` if given (see
+/// [`printed_blocks`](crate::printer::block::printed_blocks))
+pub(crate) fn list_to_doc<'a>(
+    list: &List,
+    bullet: Option<ListBulletKind>,
+    config: Rc<Config>,
+    arena: &'a Arena<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    let mut counter = if let ListKind::Ordered(v) = &list.kind {
+        v.start
+    } else {
+        0
+    };
+    let prefix_length = match &list.kind {
+        ListKind::Bullet(_) => 2 + config.spaces_before_list_item, // <space>-<space>
+        ListKind::Ordered(v) => {
+            let last = match config.ordered_list_numbering {
+                OrderedListNumbering::Sequential => v.start + list.items.len() as u64 - 1,
+                OrderedListNumbering::Repeated => v.start,
+            };
+            let digits = last.to_string().len();
+            digits + 2 + config.spaces_before_list_item // <space>1.<space>
+        }
+    };
+    let items = list.items.iter().map(|item| {
+        let marker = match list.kind {
+            ListKind::Bullet(kind) => {
+                match bullet.unwrap_or_else(|| styled_bullet(kind, &config)) {
+                    ListBulletKind::Dash => "-".to_owned(),
+                    ListBulletKind::Star => "*".to_owned(),
+                    ListBulletKind::Plus => "+".to_owned(),
+                }
             }
-        };
-        let items = self.items.iter().map(|item| {
-            let marker = match self.kind {
-                ListKind::Bullet(ListBulletKind::Dash) => "-".to_owned(),
-                ListKind::Bullet(ListBulletKind::Star) => "*".to_owned(),
-                ListKind::Bullet(ListBulletKind::Plus) => "+".to_owned(),
-                ListKind::Ordered(_) => {
-                    let r = format!("{counter}.");
+            ListKind::Ordered(_) => {
+                let r = format!("{counter}.");
+                if config.ordered_list_numbering == OrderedListNumbering::Sequential {
                     counter += 1;
-                    r
                 }
-            };
+                r
+            }
+        };
 
-            let task_list_marker = match item.task {
-                Some(TaskState::Complete) => arena.text("[X]").append(arena.space()),
-                Some(TaskState::Incomplete) => arena.text("[ ]").append(arena.space()),
-                None => arena.nil(),
-            };
+        let task_list_marker = match item.task {
+            Some(TaskState::Complete) => arena.text("[X]").append(arena.space()),
+            Some(TaskState::Incomplete) => arena.text("[ ]").append(arena.space()),
+            None => arena.nil(),
+        };
+
+        let indent = " ".repeat(config.spaces_before_list_item);
 
-            let indent = " ".repeat(config.spaces_before_list_item);
-
-            arena
-                .text(indent)
-                .append(arena.text(marker.clone()))
-                .append(arena.space())
-                .append(task_list_marker)
-                .append(
-                    item.blocks
-                        .to_doc(config.clone(), arena)
-                        .nest(prefix_length as isize)
-                        .group(),
-                )
-        });
+        arena
+            .text(indent)
+            .append(arena.text(marker.clone()))
+            .append(arena.space())
+            .append(task_list_marker)
+            .append(
+                item_blocks_to_doc(&item.blocks, list.tight, config.clone(), arena)
+                    .nest(prefix_length as isize)
+                    .group(),
+            )
+    });
 
+    if list.tight {
         arena.intersperse(items, arena.hardline())
+    } else {
+        // Loose lists keep an empty line between items
+        arena.intersperse(items, arena.hardline().append(arena.hardline()))
+    }
+}
+
+/// Blocks of a list item. In tight lists a block directly follows the
+/// preceding one where it can't be read as part of it (see
+/// [`follows_directly`]), an empty line there would make the list loose.
+fn item_blocks_to_doc<'a>(
+    blocks: &[Block],
+    tight: bool,
+    config: Rc<Config>,
+    arena: &'a Arena<'a>,
+) -> DocBuilder<'a, Arena<'a>, ()> {
+    if !tight {
+        return blocks.iter().collect::<Vec<_>>().to_doc(config, arena);
+    }
+
+    let mut acc = arena.nil();
+    let mut previous = None;
+    for (block, bullet) in printed_blocks(blocks, &config) {
+        if let Some(previous) = previous {
+            acc = acc.append(arena.hardline());
+            if !follows_directly(previous, block) {
+                acc = acc.append(arena.hardline());
+            }
+        }
+        acc = acc.append(printed_block_to_doc(block, bullet, config.clone(), arena));
+        previous = Some(block);
+    }
+    acc
+}
+
+/// Whether `block` can be printed on the line after `previous` without an
+/// empty line in between, so that it still starts a block of its own
+fn follows_directly(previous: &Block, block: &Block) -> bool {
+    match block {
+        Block::List(_) => true,
+        Block::BlockQuote(_)
+        | Block::GitHubAlert(_)
+        | Block::CodeBlock(CodeBlock {
+            kind: CodeBlockKind::Fenced { .. },
+            ..
+        }) => matches!(
+            previous,
+            Block::Paragraph(_)
+                | Block::Heading(_)
+                | Block::ThematicBreak
+                | Block::List(_)
+                | Block::CodeBlock(CodeBlock {
+                    kind: CodeBlockKind::Fenced { .. },
+                    ..
+                })
+        ),
+        _ => false,
     }
 }
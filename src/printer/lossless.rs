@@ -0,0 +1,402 @@
+//! Lossless printing of edited documents
+//!
+//! [`render_markdown`](crate::printer::render_markdown) prints the whole
+//! document in one canonical style, so formatting hand-written files produces
+//! large diffs. [`render_markdown_lossless`] prints a document parsed with
+//! [`parse_markdown_with_spans`](crate::parser::parse_markdown_with_spans)
+//! against its original source instead:
+//!
+//! - blocks and list items that were not changed are copied from the source
+//!   byte for byte, with their delimiters, escapes and indentation;
+//! - blank lines and other text between unchanged neighbours are kept;
+//! - a changed block quote, alert, footnote definition, list or list item
+//!   keeps its markers and its unchanged children, and only the changed
+//!   children are reformatted, down to the innermost changed block;
+//! - other changed blocks are reformatted on their own.
+//!
+//! Nodes are matched with the source by their spans. Nodes sharing a span,
+//! e.g. the blocks a `FlatMap` callback returns for one source block, can't
+//! be told apart and are always reformatted.
+//!
+//! An unmodified document is printed back identical to its source. New nodes
+//! should carry `Span::default()`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use markdown_ppp::ast::generic::{Block, Inline};
+//! use markdown_ppp::parser::{parse_markdown_with_spans, MarkdownParserState};
+//! use markdown_ppp::printer::config::Config;
+//! use markdown_ppp::printer::lossless::{render_markdown_lossless, OriginalSource};
+//!
+//! let input = "Some  _hand-written_\ntext.\n\n\n* one\n* two\n";
+//! let mut doc = parse_markdown_with_spans(MarkdownParserState::new(), input).unwrap();
+//! let source = OriginalSource::new(input, &doc);
+//!
+//! // Nothing changed: the output is the input
+//! assert_eq!(render_markdown_lossless(&doc, &source, Config::default()), input);
+//!
+//! // Only the edited paragraph of the list item is reformatted
+//! let Block::List(list) = &mut doc.blocks[1] else { panic!() };
+//! let Block::Paragraph { content, .. } = &mut list.items[1].blocks[0] else { panic!() };
+//! content.push(Inline::Text { content: " and three".into(), user_data: Default::default() });
+//! assert_eq!(
+//!     render_markdown_lossless(&doc, &source, Config::default()),
+//!     "Some  _hand-written_\ntext.\n\n\n* one\n* two and three\n",
+//! );
+//! ```
+
+use crate::ast::convert::StripData;
+use crate::ast::{generic, Block, Document, List, ListKind, ListOrderedKindOptions, Span};
+use crate::printer::config::Config;
+use std::collections::HashMap;
+
+/// Original source of a document, used by [`render_markdown_lossless`] to find
+/// unchanged nodes.
+pub struct OriginalSource<'a> {
+    text: &'a str,
+    /// Top-level blocks as parsed
+    blocks: Vec<generic::Block<Span>>,
+}
+
+impl<'a> OriginalSource<'a> {
+    /// Remember the unmodified `document` parsed from `text` with
+    /// [`parse_markdown_with_spans`](crate::parser::parse_markdown_with_spans).
+    pub fn new(text: &'a str, document: &generic::Document<Span>) -> Self {
+        Self {
+            text,
+            blocks: document.blocks.clone(),
+        }
+    }
+
+    /// Source text of `span`, if it is a valid span of the source
+    fn slice(&self, span: Span) -> Option<&'a str> {
+        if span.is_empty() {
+            return None;
+        }
+        self.text.get(span.range())
+    }
+
+    /// Source text between two spans, if `next` follows `previous`
+    fn between(&self, previous: Span, next: Span) -> Option<&'a str> {
+        self.text.get(previous.end.offset..next.start.offset)
+    }
+
+    /// For every edited node, the index of the original node with the same
+    /// span. Spans shared by several original nodes match nothing.
+    fn match_spans(
+        &self,
+        original: impl IntoIterator<Item = Span>,
+        edited: impl IntoIterator<Item = Span>,
+    ) -> Vec<Option<usize>> {
+        let mut index: HashMap<Span, Option<usize>> = HashMap::new();
+        for (i, span) in original.into_iter().enumerate() {
+            if self.slice(span).is_some() {
+                index
+                    .entry(span)
+                    .and_modify(|shared| *shared = None)
+                    .or_insert(Some(i));
+            }
+        }
+        edited
+            .into_iter()
+            .map(|span| index.get(&span).copied().flatten())
+            .collect()
+    }
+}
+
+/// Render a document parsed with spans, copying unchanged parts from the
+/// original source
+///
+/// See the [module documentation](self) for details. `config` is used for the
+/// changed blocks.
+pub fn render_markdown_lossless(
+    ast: &generic::Document<Span>,
+    source: &OriginalSource,
+    config: Config,
+) -> String {
+    let text = source.text;
+    let original = &source.blocks;
+    let matches = source.match_spans(
+        original.iter().map(block_span),
+        ast.blocks.iter().map(block_span),
+    );
+
+    let mut out = String::new();
+    match matches.first() {
+        // Leading blank lines of the source
+        Some(Some(0)) => out.push_str(&text[..block_span(&original[0]).start.offset]),
+        Some(_) => {}
+        // An empty document stays as it was if it was empty in the source
+        None if original.is_empty() => return text.to_owned(),
+        None => return String::new(),
+    }
+
+    out.push_str(&render_blocks(
+        original,
+        &ast.blocks,
+        &matches,
+        "",
+        source,
+        &config,
+    ));
+
+    match matches.last() {
+        Some(Some(last)) if *last == original.len() - 1 => {
+            out.push_str(&text[block_span(&original[*last]).end.offset..]);
+        }
+        _ if text.ends_with('\n') => out.push('\n'),
+        _ => {}
+    }
+    out
+}
+
+/// Render `edited` blocks, whose original blocks are given by `matches`
+///
+/// Reformatted blocks are separated by an empty line, and their lines after
+/// the first start with `prefix`.
+fn render_blocks(
+    original: &[generic::Block<Span>],
+    edited: &[generic::Block<Span>],
+    matches: &[Option<usize>],
+    prefix: &str,
+    source: &OriginalSource,
+    config: &Config,
+) -> String {
+    let mut out = String::new();
+    for (i, block) in edited.iter().enumerate() {
+        if i > 0 {
+            let between = match (matches[i - 1], matches[i]) {
+                (Some(previous), Some(next)) if next == previous + 1 => {
+                    source.between(block_span(&original[previous]), block_span(&original[next]))
+                }
+                _ => None,
+            };
+            match between {
+                Some(between) => out.push_str(between),
+                None => {
+                    out.push('\n');
+                    out.push_str(prefix.trim_end());
+                    out.push('\n');
+                    out.push_str(prefix);
+                }
+            }
+        }
+        let original = matches[i].map(|j| &original[j]);
+        out.push_str(&render_block(original, block, prefix, source, config));
+    }
+    out
+}
+
+fn render_block(
+    original: Option<&generic::Block<Span>>,
+    block: &generic::Block<Span>,
+    prefix: &str,
+    source: &OriginalSource,
+    config: &Config,
+) -> String {
+    let stripped = block.clone().strip_data();
+    let Some((original, text)) =
+        original.and_then(|original| Some((original, source.slice(block_span(original))?)))
+    else {
+        return format_block(stripped, prefix, config);
+    };
+    if original.clone().strip_data() == stripped {
+        return text.to_owned();
+    }
+
+    let span = block_span(original);
+    let reused = match (original, block) {
+        (
+            generic::Block::BlockQuote {
+                blocks: original, ..
+            },
+            generic::Block::BlockQuote { blocks, .. },
+        ) => render_container(span, original, blocks, source, config),
+        (generic::Block::GitHubAlert(original), generic::Block::GitHubAlert(alert))
+            if original.alert_type == alert.alert_type =>
+        {
+            render_container(span, &original.blocks, &alert.blocks, source, config)
+        }
+        (
+            generic::Block::FootnoteDefinition(original),
+            generic::Block::FootnoteDefinition(footnote),
+        ) if original.label == footnote.label => {
+            render_container(span, &original.blocks, &footnote.blocks, source, config)
+        }
+        (generic::Block::List(original), generic::Block::List(list))
+            if original.kind == list.kind =>
+        {
+            Some(render_list(original, list, prefix, source, config))
+        }
+        _ => None,
+    };
+    reused.unwrap_or_else(|| format_block(stripped, prefix, config))
+}
+
+/// Render a changed container block or list item from its source, keeping
+/// its markers and its unchanged children
+///
+/// Returns `None` if the children can't be located in the source.
+fn render_container(
+    span: Span,
+    original: &[generic::Block<Span>],
+    blocks: &[generic::Block<Span>],
+    source: &OriginalSource,
+    config: &Config,
+) -> Option<String> {
+    let text = source.text;
+    let first = block_span(original.first()?);
+    let last = block_span(original.last()?);
+    if blocks.is_empty() || first.start.offset < span.start.offset || span.end < last.end {
+        return None;
+    }
+
+    // Lines of reformatted children start like the line of the first child,
+    // with list markers replaced by spaces
+    let line_start = text[..first.start.offset].rfind('\n').map_or(0, |i| i + 1);
+    let prefix: String = text[line_start..first.start.offset]
+        .chars()
+        .map(|c| match c {
+            '>' => c,
+            c if c.is_whitespace() => c,
+            _ => ' ',
+        })
+        .collect();
+
+    let matches = source.match_spans(
+        original.iter().map(block_span),
+        blocks.iter().map(block_span),
+    );
+    let mut out = text[span.start.offset..first.start.offset].to_owned();
+    out.push_str(&render_blocks(
+        original, blocks, &matches, &prefix, source, config,
+    ));
+    out.push_str(text.get(last.end.offset..span.end.offset)?);
+    Some(out)
+}
+
+/// Render a changed list, keeping the unchanged items
+fn render_list(
+    original: &generic::List<Span>,
+    list: &generic::List<Span>,
+    prefix: &str,
+    source: &OriginalSource,
+    config: &Config,
+) -> String {
+    let text = source.text;
+    let spans: Vec<Span> = original.items.iter().map(|item| item.user_data).collect();
+    let matches = source.match_spans(
+        spans.iter().copied(),
+        list.items.iter().map(|item| item.user_data),
+    );
+
+    // Reformatted items are indented and separated like the original ones
+    let first = matches.iter().flatten().next().copied();
+    let indent = first.map_or(0, |first| {
+        let span = spans[first];
+        text[span.range()]
+            .chars()
+            .take_while(|c| *c == ' ')
+            .count()
+            .min(3)
+    });
+    let separator = first
+        .and_then(|first| source.between(spans[first], *spans.get(first + 1)?))
+        .map_or_else(|| format!("\n{prefix}"), str::to_owned);
+    let config = Config {
+        spaces_before_list_item: indent,
+        ..config.clone()
+    };
+
+    let mut out = String::new();
+    let kind: ListKind = list.kind.clone().into();
+    let mut number = match &kind {
+        ListKind::Ordered(ListOrderedKindOptions { start }) => *start,
+        ListKind::Bullet(_) => 1,
+    };
+    for (i, item) in list.items.iter().enumerate() {
+        if i > 0 {
+            let between = match (matches[i - 1], matches[i]) {
+                (Some(previous), Some(next)) if next == previous + 1 => {
+                    source.between(spans[previous], spans[next])
+                }
+                _ => None,
+            };
+            out.push_str(between.unwrap_or(&separator));
+        }
+
+        let original = matches[i].map(|j| &original.items[j]);
+        let original_text = original.and_then(|original| source.slice(original.user_data));
+        if let Some(number_text) = original_text.and_then(item_number) {
+            number = number_text;
+        }
+        let stripped = item.clone().strip_data();
+        let reused = match (original, original_text) {
+            (Some(original), Some(original_text)) if original.clone().strip_data() == stripped => {
+                Some(original_text.to_owned())
+            }
+            (Some(original), Some(_)) if original.task == item.task => render_container(
+                original.user_data,
+                &original.blocks,
+                &item.blocks,
+                source,
+                &config,
+            ),
+            _ => None,
+        };
+        out.push_str(&reused.unwrap_or_else(|| {
+            let kind = match &kind {
+                ListKind::Ordered(_) => ListKind::Ordered(ListOrderedKindOptions { start: number }),
+                bullet => bullet.clone(),
+            };
+            let list = Block::List(List {
+                kind,
+                items: vec![stripped],
+                tight: list.tight,
+            });
+            format_block(list, prefix, &config)
+        }));
+        number += 1;
+    }
+    out
+}
+
+/// Number of an ordered list item, from its source text
+fn item_number(item: &str) -> Option<u64> {
+    let item = item.trim_start_matches(' ');
+    let digits = item.find(|c: char| !c.is_ascii_digit())?;
+    item[..digits].parse().ok()
+}
+
+/// Format `block` on its own, starting its lines after the first with
+/// `prefix`
+fn format_block(block: Block, prefix: &str, config: &Config) -> String {
+    let config = Config {
+        width: config.width.saturating_sub(prefix.chars().count()),
+        ..config.clone()
+    };
+    let formatted = crate::printer::render_markdown(
+        &Document {
+            blocks: vec![block],
+        },
+        config,
+    );
+    if prefix.is_empty() {
+        return formatted;
+    }
+    formatted
+        .split('\n')
+        .enumerate()
+        .map(|(i, line)| match (i, line.is_empty()) {
+            (0, _) => line.to_owned(),
+            (_, true) => prefix.trim_end().to_owned(),
+            (_, false) => format!("{prefix}{line}"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn block_span(block: &generic::Block<Span>) -> Span {
+    *block.user_data()
+}
@@ -5,6 +5,7 @@
 //! document structure when text is wrapped.
 
 use regex::Regex;
+use std::borrow::Cow;
 use std::sync::OnceLock;
 
 /// Characters that can start markdown block syntax when at the beginning of a line
@@ -98,7 +99,60 @@ pub fn is_markdown_syntax_at_line_start(word: &str) -> bool {
 ///
 /// `true` if the line break is safe, `false` if it would create syntax conflicts
 pub fn is_safe_line_break_before(next_word: &str, _context_words: &[&str]) -> bool {
-    !is_markdown_syntax_at_line_start(next_word)
+    !is_markdown_syntax_at_line_start(next_word) && !can_interrupt_paragraph(next_word)
+}
+
+/// Get compiled regex for the starts of blocks that can interrupt a paragraph
+fn paragraph_interrupt_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(concat!(
+            r"^(",
+            r"#{1,6}(\s|$)",        // ATX heading
+            r"|>",                  // blockquote
+            r"|[-+*_=](\s|$)",      // list item or spaced thematic break
+            r"|(=+|-+|\*+|_+)\s*$", // setext underline or thematic break
+            r"|\d{1,9}[.)](\s|$)",  // ordered list item
+            r"|`{3,}|~{3,}",        // code fence
+            r"|<[A-Za-z/!?]",       // HTML block
+            r"|\$\$",               // math block
+            r"|\|",                 // table
+            r"|\[[^\]]*\]:",        // link or footnote definition
+            r")"
+        ))
+        .expect("Invalid regex")
+    })
+}
+
+/// Check if a line would start a new block when it follows a paragraph line
+///
+/// `line` may also be just the first word of the line. The check errs on the
+/// side of caution, so some lines that would stay paragraph text are reported
+/// as well.
+///
+/// # Arguments
+///
+/// * `line` - The line, without leading whitespace, that follows paragraph text
+///
+/// # Returns
+///
+/// `true` if the line could interrupt the paragraph, `false` otherwise
+pub fn can_interrupt_paragraph(line: &str) -> bool {
+    paragraph_interrupt_regex().is_match(line)
+}
+
+/// Escape the first line of `text` so that it cannot interrupt a paragraph
+///
+/// A backslash is inserted before the block marker, which for ordered list
+/// items is the `.` or `)` after the number. Only ASCII punctuation is escaped,
+/// so the text reads back unchanged.
+pub fn escape_line_start(text: &str) -> Cow<'_, str> {
+    let first_line = text.split('\n').next().unwrap_or_default();
+    if !can_interrupt_paragraph(first_line) {
+        return Cow::Borrowed(text);
+    }
+    let marker = text.find(|c: char| !c.is_ascii_digit()).unwrap_or_default();
+    Cow::Owned(format!("{}\\{}", &text[..marker], &text[marker..]))
 }
 
 #[cfg(test)]
@@ -199,5 +253,63 @@ mod tests {
         assert!(!is_safe_line_break_before("*", &[]));
         assert!(!is_safe_line_break_before("#", &[]));
         assert!(!is_safe_line_break_before("1.", &[]));
+        assert!(!is_safe_line_break_before("1)", &[]));
+        assert!(!is_safe_line_break_before("===", &[]));
+        assert!(!is_safe_line_break_before("<div>", &[]));
+    }
+
+    #[test]
+    fn test_paragraph_interrupts() {
+        for line in [
+            "# b",
+            "#",
+            "> q",
+            "- b",
+            "+",
+            "* b",
+            "===",
+            "---",
+            "- - -",
+            "_ _ _",
+            "1. x",
+            "1) x",
+            "```rust",
+            "~~~",
+            "<div>x",
+            "<!-- c -->",
+            "</p>",
+            "$$",
+            "| a |",
+            "[a]: /url",
+            "[^1]: note",
+        ] {
+            assert!(can_interrupt_paragraph(line), "{line}");
+        }
+        for line in [
+            "#hashtag",
+            "word",
+            "-1",
+            "1.5",
+            "1)x",
+            "=x",
+            "a = b",
+            "``code``",
+            "< 5",
+            "$5",
+            "[link](/url)",
+            "1234567890. x",
+        ] {
+            assert!(!can_interrupt_paragraph(line), "{line}");
+        }
+    }
+
+    #[test]
+    fn test_escape_line_start() {
+        assert_eq!("\\# b", escape_line_start("# b"));
+        assert_eq!("\\===", escape_line_start("==="));
+        assert_eq!("1\\) x", escape_line_start("1) x"));
+        assert_eq!("42\\. x\n- y", escape_line_start("42. x\n- y"));
+        assert_eq!("\\<div>", escape_line_start("<div>"));
+        assert_eq!("word\n# b", escape_line_start("word\n# b"));
     }
 }
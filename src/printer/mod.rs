@@ -26,7 +26,7 @@
 //!         }),
 //!         Block::Paragraph(vec![
 //!             Inline::Text("This is ".to_string()),
-//!             Inline::Strong(vec![Inline::Text("formatted".to_string())]),
+//!             Inline::Strong(vec![Inline::Text("formatted".to_string())].into()),
 //!             Inline::Text(" text.".to_string()),
 //!         ]),
 //!     ],
@@ -55,10 +55,14 @@ mod blockquote;
 
 /// Configuration options for Markdown pretty-printing.
 pub mod config;
+mod definition_list;
 mod github_alert;
 mod heading;
 mod inline;
 mod list;
+
+/// Lossless printing of edited documents against their original source.
+pub mod lossless;
 mod markdown_syntax_detector;
 mod table;
 mod tests;
@@ -93,7 +97,7 @@ use std::rc::Rc;
 ///     blocks: vec![
 ///         Block::Paragraph(vec![
 ///             Inline::Text("Hello ".to_string()),
-///             Inline::Strong(vec![Inline::Text("world".to_string())]),
+///             Inline::Strong(vec![Inline::Text("world".to_string())].into()),
 ///         ]),
 ///     ],
 /// };
@@ -121,15 +125,79 @@ use std::rc::Rc;
 /// ```
 /// Where ≈ means semantically equivalent AST structures.
 pub fn render_markdown(ast: &Document, config: crate::printer::config::Config) -> String {
-    let config = Rc::new(config);
-    let arena = Arena::new();
-    let doc = ast.to_doc(config.clone(), &arena);
-
     let mut buf = Vec::new();
-    doc.render(config.width, &mut buf).unwrap();
+    render_markdown_to(ast, config, &mut buf).unwrap();
     String::from_utf8(buf).unwrap()
 }
 
+/// Render a Markdown AST back to formatted Markdown text, writing to `writer`
+///
+/// The output is the same as that of [`render_markdown`]. Top-level blocks are
+/// rendered one after another, each with its own arena, and written as soon as
+/// they are done, so the whole document is never held in memory. Pass a
+/// [`std::io::BufWriter`] when writing to a file or socket.
+///
+/// # Errors
+///
+/// Returns the first error of `writer`.
+///
+/// # Examples
+///
+/// ```rust
+/// use markdown_ppp::ast::*;
+/// use markdown_ppp::printer::{render_markdown_to, config::Config};
+///
+/// let doc = Document {
+///     blocks: vec![
+///         Block::Heading(Heading {
+///             kind: HeadingKind::Atx(1),
+///             content: vec![Inline::Text("Report".to_string())],
+///         }),
+///         Block::Paragraph(vec![Inline::Text("Body".to_string())]),
+///     ],
+/// };
+///
+/// let mut out = Vec::new();
+/// render_markdown_to(&doc, Config::default(), &mut out).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "# Report\n\nBody");
+/// ```
+pub fn render_markdown_to<W: std::io::Write>(
+    ast: &Document,
+    config: crate::printer::config::Config,
+    mut writer: W,
+) -> std::io::Result<()> {
+    let config = Rc::new(config);
+    let printed = crate::printer::block::printed_blocks(&ast.blocks, &config);
+    for (i, (block, bullet)) in printed.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b"\n")?;
+            if crate::printer::block::empty_line_before(block, &config) {
+                writer.write_all(b"\n")?;
+            }
+        }
+        let arena = Arena::new();
+        let doc =
+            crate::printer::block::printed_block_to_doc(block, *bullet, config.clone(), &arena);
+        doc.render(config.width, &mut writer)?;
+    }
+
+    if config.link_definitions_at_end {
+        let mut definitions = Vec::new();
+        crate::printer::block::link_definitions(&ast.blocks, &mut definitions);
+        for (i, definition) in definitions.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b"\n")?;
+            } else if !printed.is_empty() {
+                writer.write_all(b"\n\n")?;
+            }
+            let arena = Arena::new();
+            let doc = definition.to_doc(config.clone(), &arena);
+            doc.render(config.width, &mut writer)?;
+        }
+    }
+    Ok(())
+}
+
 trait ToDoc<'a> {
     fn to_doc(
         &self,
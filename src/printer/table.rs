@@ -1,5 +1,5 @@
 use crate::ast::*;
-use crate::printer::{inline::ToDocInline, ToDoc};
+use crate::printer::{config::Config, inline::ToDocInline, ToDoc};
 use core::iter::Iterator;
 use pretty::{Arena, DocAllocator, DocBuilder};
 use std::rc::Rc;
@@ -7,14 +7,14 @@ use std::rc::Rc;
 impl<'a> ToDoc<'a> for Table {
     fn to_doc(
         &self,
-        _config: Rc<crate::printer::config::Config>,
+        config: Rc<crate::printer::config::Config>,
         arena: &'a Arena<'a>,
     ) -> DocBuilder<'a, Arena<'a>, ()> {
         if self.rows.is_empty() {
             return arena.nil();
         }
 
-        let content = table_content(self);
+        let content = table_content(self, &config);
         let columns_width = columns_width(&content, &self.alignments);
         let header = row_to_doc(&content[0], &columns_width, &self.alignments, arena);
         let separator = alignments_row_to_doc(&self.alignments, &columns_width, arena);
@@ -177,12 +177,12 @@ fn column_content_width(table: &[Vec<String>], column_index: usize) -> usize {
     max_width
 }
 
-fn table_content(table: &Table) -> Vec<Vec<String>> {
+fn table_content(table: &Table, config: &Config) -> Vec<Vec<String>> {
     let mut content = Vec::new();
     for row in &table.rows {
         let mut row_content = Vec::new();
         for cell in row {
-            let cell_content = render_cell(cell);
+            let cell_content = render_cell(cell, config);
             row_content.push(cell_content);
         }
         content.push(row_content);
@@ -190,9 +190,9 @@ fn table_content(table: &Table) -> Vec<Vec<String>> {
     content
 }
 
-fn render_cell(doc: &Vec<Inline>) -> String {
+fn render_cell(doc: &Vec<Inline>, config: &Config) -> String {
     let tmp_arena = Arena::new();
-    let doc = doc.to_doc_inline(false, &tmp_arena);
+    let doc = doc.to_doc_inline(false, config, &tmp_arena);
 
     let mut buf = Vec::new();
     doc.render(usize::MAX, &mut buf).unwrap();
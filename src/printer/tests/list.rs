@@ -17,6 +17,7 @@ use rstest::rstest;
     ),
     case(
         r#"- item1
+
 - item2
   
   - item2 1
@@ -235,3 +236,30 @@ fn fenced_code_block_in_blockquote_idempotent(input: &str) {
         "Formatting should be idempotent.\nInput:\n{input}\n\nFirst pass:\n{pass1}\n\nSecond pass:\n{pass2}"
     );
 }
+
+#[rstest(
+    input,
+    expected,
+    case("- a\n  > q\n- b", " - a\n   > q\n - b"),
+    case(
+        "- a\n  ```\n  code\n  ```\n- b",
+        " - a\n   ```\n   code\n   ```\n - b"
+    ),
+    case("- a\n  > [!NOTE]\n  > q\n- b", " - a\n   > [!NOTE]\n   > q\n - b"),
+    case("- # a\n  > q\n- b", " - # a\n   > q\n - b")
+)]
+fn tight_list_item_blocks_stay_tight(input: &str, expected: &str) {
+    let doc = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(expected, result);
+
+    let reparsed =
+        crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), &result)
+            .unwrap();
+    assert_eq!(doc, reparsed);
+    let crate::ast::Block::List(list) = &reparsed.blocks[0] else {
+        panic!("expected a list: {reparsed:?}");
+    };
+    assert!(list.tight);
+}
@@ -0,0 +1,213 @@
+#![cfg(test)]
+use crate::ast::generic::{Block, Document, Inline, ListItem};
+use crate::ast::Span;
+use crate::parser::{parse_markdown_with_spans, MarkdownParserState};
+use crate::printer::config::Config;
+use crate::printer::lossless::{render_markdown_lossless, OriginalSource};
+use rstest::rstest;
+
+fn parse(input: &str) -> Document<Span> {
+    parse_markdown_with_spans(MarkdownParserState::default(), input).unwrap()
+}
+
+fn text(content: &str) -> Inline<Span> {
+    Inline::Text {
+        content: content.to_owned(),
+        user_data: Span::default(),
+    }
+}
+
+fn paragraph(content: &str) -> Block<Span> {
+    Block::Paragraph {
+        content: vec![text(content)],
+        user_data: Span::default(),
+    }
+}
+
+#[rstest]
+#[case("")]
+#[case("\n\n")]
+#[case("Text")]
+#[case("Some  _emphasis_ and __strong__ text\\!\nwrapped   by hand.\n")]
+#[case("\n\nAfter blank lines\n\n\n\nFour blank lines\n\n")]
+#[case("Setext\n======\n\n#  ATX with closing  ##\n")]
+#[case("* star\n+ plus\n\n1) paren\n2) paren\n")]
+#[case("  - indented\n\n    loose item\n  - second\n")]
+#[case("> quote\nlazy continuation\n>\n> > nested\n")]
+#[case("~~~ rust\nfn main() {}\n~~~\n\n    indented code\n")]
+#[case("| a |  b |\n|---|:--:|\n| 1 |  2 |\n")]
+#[case("[link]: <https://example.com>  'title'\n\nUse [link][] and <https://example.com>\n")]
+#[case("***\n\n___\n\n<div>\n*html*\n</div>\n")]
+#[case("Text[^1]\n\n[^1]:   Footnote\n")]
+fn unchanged_document_is_identical(#[case] input: &str) {
+    let doc = parse(input);
+    let source = OriginalSource::new(input, &doc);
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        input
+    );
+}
+
+#[test]
+fn changed_block_is_reformatted() {
+    let input = "# Title\n\nOld  *text*.\n\n\n__Keep__   this.\n";
+    let mut doc = parse(input);
+    let source = OriginalSource::new(input, &doc);
+
+    let Block::Paragraph { content, .. } = &mut doc.blocks[1] else {
+        panic!("expected paragraph");
+    };
+    content[0] = text("New ");
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        "# Title\n\nNew *text*.\n\n\n__Keep__   this.\n"
+    );
+}
+
+#[test]
+fn inserted_and_removed_blocks() {
+    let input = "First\n\n\nSecond\n\n\nThird\n";
+    let mut doc = parse(input);
+    let source = OriginalSource::new(input, &doc);
+
+    doc.blocks.remove(1);
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        "First\n\nThird\n"
+    );
+
+    doc.blocks.insert(0, paragraph("Zeroth"));
+    doc.blocks.push(paragraph("Fourth"));
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        "Zeroth\n\nFirst\n\nThird\n\nFourth\n"
+    );
+
+    doc.blocks.clear();
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        ""
+    );
+}
+
+#[test]
+fn changed_list_items_are_reformatted() {
+    let input = "Intro\n\n  3. three\n  3.   four\n  3. five\n";
+    let mut doc = parse(input);
+    let source = OriginalSource::new(input, &doc);
+
+    let Block::List(list) = &mut doc.blocks[1] else {
+        panic!("expected list");
+    };
+    let Block::Paragraph { content, .. } = &mut list.items[1].blocks[0] else {
+        panic!("expected paragraph");
+    };
+    content[0] = text("FOUR");
+    list.items.push(ListItem {
+        task: None,
+        blocks: vec![paragraph("six")],
+        user_data: Span::default(),
+    });
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        "Intro\n\n  3. three\n  3.   FOUR\n  3. five\n  4. six\n"
+    );
+}
+
+#[test]
+fn changed_list_kind_reformats_list() {
+    let input = "*   one\n*   two\n";
+    let mut doc = parse(input);
+    let source = OriginalSource::new(input, &doc);
+
+    let Block::List(list) = &mut doc.blocks[0] else {
+        panic!("expected list");
+    };
+    list.kind = crate::ast::generic::ListKind::Bullet(crate::ast::ListBulletKind::Dash);
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        " - one\n - two\n"
+    );
+}
+
+/// Content of the paragraph at `path`, through the children of containers
+/// and the items of lists
+fn paragraph_at<'a>(blocks: &'a mut [Block<Span>], path: &[usize]) -> &'a mut Vec<Inline<Span>> {
+    match &mut blocks[path[0]] {
+        Block::Paragraph { content, .. } => content,
+        Block::BlockQuote { blocks, .. } => paragraph_at(blocks, &path[1..]),
+        Block::GitHubAlert(alert) => paragraph_at(&mut alert.blocks, &path[1..]),
+        Block::FootnoteDefinition(footnote) => paragraph_at(&mut footnote.blocks, &path[1..]),
+        Block::List(list) => paragraph_at(&mut list.items[path[1]].blocks, &path[2..]),
+        block => panic!("unexpected block {block:?}"),
+    }
+}
+
+#[rstest]
+#[case(
+    "> quote\n> more\n>\n> - a\n>   b\n>\n> *end*  x\n",
+    &[0, 1, 0, 0],
+    "> quote\n> more\n>\n> - new\n>\n> *end*  x\n"
+)]
+#[case("- a\n\n  > q\n  >  *x*  y\n- b\n", &[0, 0, 1, 0], "- a\n\n  > new\n- b\n")]
+#[case(
+    "> [!NOTE]\n> *text*  a\n>\n> two\n",
+    &[0, 1],
+    "> [!NOTE]\n> *text*  a\n>\n> new\n"
+)]
+#[case("   - x\n     - y\n       z\n", &[0, 0, 1, 0, 0], "   - x\n     - new\n")]
+#[case("[^1]:  *note*\n\nText[^1]  *a*\n", &[0, 0], "[^1]:  new\n\nText[^1]  *a*\n")]
+fn changed_nested_block_is_reformatted(
+    #[case] input: &str,
+    #[case] path: &[usize],
+    #[case] expected: &str,
+) {
+    let mut doc = parse(input);
+    let source = OriginalSource::new(input, &doc);
+
+    *paragraph_at(&mut doc.blocks, path) = vec![text("new")];
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        expected
+    );
+}
+
+#[test]
+fn nested_blocks_are_reformatted_with_container_prefix() {
+    let input = "> a\n>\n> b  *c*\n";
+    let mut doc = parse(input);
+    let source = OriginalSource::new(input, &doc);
+
+    *paragraph_at(&mut doc.blocks, &[0, 0]) = vec![text("one two three four five six")];
+    let Block::BlockQuote { blocks, .. } = &mut doc.blocks[0] else {
+        panic!("expected block quote");
+    };
+    blocks.push(paragraph("new"));
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default().with_width(20)),
+        "> one two three four\n> five six\n>\n> b  *c*\n>\n> new\n"
+    );
+}
+
+#[test]
+fn blocks_sharing_a_span_are_reformatted() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+    use std::sync::Arc;
+
+    // The note gets the span of the thematic break
+    let config = MarkdownParserConfig::default().with_block_thematic_break_behavior(
+        ElementBehavior::FlatMap(Arc::new(|block| {
+            vec![
+                block,
+                crate::ast::Block::Paragraph(vec![crate::ast::Inline::Text("note".to_owned())]),
+            ]
+        })),
+    );
+    let input = "Intro  *a*\n\n***\n";
+    let doc = parse_markdown_with_spans(MarkdownParserState::with_config(config), input).unwrap();
+    let source = OriginalSource::new(input, &doc);
+    assert_eq!(
+        render_markdown_lossless(&doc, &source, Config::default()),
+        "Intro  *a*\n\n---\n\nnote\n"
+    );
+}
@@ -3,6 +3,8 @@ use rstest::rstest;
 
 mod line_wrapping_issues;
 mod list;
+mod lossless;
+mod style;
 mod table;
 mod text_formatting;
 
@@ -25,11 +27,13 @@ heading 3
          case(r#" 100. item1 paragraph1
       
       item1 paragraph2
+
  101. item2 paragraph1
       
       item2 paragraph2
       
       item2 paragraph3
+
  102. item3 paragraph1
       
       item3 paragraph2"#),
@@ -38,6 +42,7 @@ heading 3
     
      * nested list item 1
      * nested list item 2
+
  2. item 2"#),
         case(
         r#"> line1 line1 line1 line1 line1 line1 line1 line1 line1 line1 line1 line1 line1
@@ -75,6 +80,7 @@ heading 3
         r#"> Список внутри цитаты:
 
 >  - Пункт *первый*
+> 
 >  - Пункт **второй**
 >    
 >     - Подпункт `третий`
@@ -320,3 +326,81 @@ fn symmetric_round_trip(input: &str) {
     let result = crate::printer::render_markdown(&doc, config);
     assert_eq!(input, result);
 }
+
+#[rstest(input,
+    case("---\ntitle: Hello\ntags:\n  - a\n  - b\n---\n\n# Hello"),
+    case("+++\ntitle = \"Hello\"\n\n[extra]\nkey = 1\n+++\n\nText"),
+    case("---\n---\n\n---"),
+)]
+fn front_matter_round_trip(input: &str) {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+
+    let state = crate::parser::MarkdownParserState::with_config(
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Parse),
+    );
+    let doc = crate::parser::parse_markdown(state, input).unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[test]
+fn yaml_front_matter_is_closed_with_dashes() {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+
+    let state = crate::parser::MarkdownParserState::with_config(
+        MarkdownParserConfig::default().with_block_front_matter_behavior(ElementBehavior::Parse),
+    );
+    let doc = crate::parser::parse_markdown(state, "---\ntitle: Hello\n...\n\nText").unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!("---\ntitle: Hello\n---\n\nText", result);
+}
+
+#[rstest(input,
+    case("Energy $E = mc^2$ is conserved."),
+    case("$$\n\\int_0^1 x\\,dx\n\n= \\frac{1}{2}\n$$"),
+    case(" - item\n   \n   $$\n   x^2\n   $$"),
+)]
+fn math_round_trip(input: &str) {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+
+    let state = crate::parser::MarkdownParserState::with_config(
+        MarkdownParserConfig::default()
+            .with_block_math_behavior(ElementBehavior::Parse)
+            .with_inline_math_behavior(ElementBehavior::Parse),
+    );
+    let doc = crate::parser::parse_markdown(state, input).unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest(input,
+    case("Apple\n: A fruit"),
+    case("*Apple*\n: A fruit\n: A company\n\nOrange\n: Another fruit"),
+    case("Term\n: First paragraph\n  \n  Second paragraph\n  \n   - item"),
+)]
+fn definition_list_round_trip(input: &str) {
+    use crate::parser::config::{ElementBehavior, MarkdownParserConfig};
+
+    let state = crate::parser::MarkdownParserState::with_config(
+        MarkdownParserConfig::default().with_block_definition_list_behavior(ElementBehavior::Parse),
+    );
+    let doc = crate::parser::parse_markdown(state, input).unwrap();
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!(input, result);
+}
+
+#[rstest(input, empty_line_before_list,
+    case("# Title\n\ntext\n\n - a\n - b\n\n> quote", true),
+    case("text\n - a\n - b\n\n```\ncode\n```", false),
+    case("Text[^n]\n\n[^n]: Note.", true),
+)]
+fn render_to_writer(input: &str, empty_line_before_list: bool) {
+    let config = || {
+        crate::printer::config::Config::default().with_empty_line_before_list(empty_line_before_list)
+    };
+    let doc = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    let mut out = Vec::new();
+    crate::printer::render_markdown_to(&doc, config(), &mut out).unwrap();
+    assert_eq!(input, String::from_utf8(out).unwrap());
+}
@@ -0,0 +1,187 @@
+#![cfg(test)]
+use crate::printer::config::{
+    BulletStyle, Config, FenceChar, HeadingStyle, OrderedListNumbering, ProseWrap,
+    ThematicBreakStyle,
+};
+use rstest::rstest;
+
+fn render(input: &str, config: Config) -> String {
+    let doc = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    crate::printer::render_markdown(&doc, config)
+}
+
+#[rstest(input, bullet_style, expected,
+    case("* a\n* b", BulletStyle::Preserve, " * a\n * b"),
+    case("* a\n* b", BulletStyle::Dash, " - a\n - b"),
+    case("- a\n  + b", BulletStyle::Plus, " + a\n    + b"),
+    // Adjacent lists keep different bullets
+    case("- a\n\n* b", BulletStyle::Dash, " - a\n\n * b"),
+    case("- a\n\n* b", BulletStyle::Star, " * a\n\n - b"),
+    case("- a\n\n+ b\n\n* c", BulletStyle::Dash, " - a\n\n * b\n\n - c"),
+)]
+fn bullet_style_is_applied(input: &str, bullet_style: BulletStyle, expected: &str) {
+    let config = Config::default().with_bullet_style(bullet_style);
+    let result = render(input, config.clone());
+    assert_eq!(expected, result);
+    assert_eq!(expected, render(&result, config));
+}
+
+#[rstest(input, numbering, expected,
+    case("1. a\n1. b\n1. c", OrderedListNumbering::Sequential, " 1. a\n 2. b\n 3. c"),
+    case("1. a\n2. b\n3. c", OrderedListNumbering::Repeated, " 1. a\n 1. b\n 1. c"),
+    case("9. a\n10. b", OrderedListNumbering::Repeated, " 9. a\n 9. b"),
+)]
+fn ordered_list_numbering_is_applied(input: &str, numbering: OrderedListNumbering, expected: &str) {
+    let config = Config::default().with_ordered_list_numbering(numbering);
+    assert_eq!(expected, render(input, config));
+}
+
+#[rstest(input, heading_style, expected,
+    case("Title\n===\n\n## Section", HeadingStyle::Preserve, "Title\n==========\n\n## Section"),
+    case("Title\n===\n\nSection\n---", HeadingStyle::Atx, "# Title\n\n## Section"),
+    case(
+        "# Title\n\n## Section\n\n### Sub",
+        HeadingStyle::Setext,
+        "Title\n==========\n\nSection\n----------\n\n### Sub"
+    ),
+    case("#", HeadingStyle::Setext, "#"),
+)]
+fn heading_style_is_applied(input: &str, heading_style: HeadingStyle, expected: &str) {
+    let config = Config::default().with_heading_style(heading_style);
+    assert_eq!(expected, render(input, config));
+}
+
+#[rstest(input, fence_char, fence_length, expected,
+    case("```rust\nfn main() {}\n```", FenceChar::Tilde, 3, "~~~rust\nfn main() {}\n~~~"),
+    case("~~~\ncode\n~~~", FenceChar::Backtick, 5, "`````\ncode\n`````"),
+    // Fences stay longer than the code they contain
+    case("````\n```\ncode\n```\n````", FenceChar::Backtick, 3, "````\n```\ncode\n```\n````"),
+    case("```\n~~~~\n```", FenceChar::Tilde, 3, "~~~~~\n~~~~\n~~~~~"),
+    // Info strings with backticks need tildes
+    case("~~~a`b\ncode\n~~~", FenceChar::Backtick, 3, "~~~a`b\ncode\n~~~"),
+)]
+fn code_fence_style_is_applied(
+    input: &str,
+    fence_char: FenceChar,
+    fence_length: usize,
+    expected: &str,
+) {
+    let config = Config::default()
+        .with_code_fence_char(fence_char)
+        .with_code_fence_length(fence_length);
+    assert_eq!(expected, render(input, config));
+}
+
+#[rstest(style, expected,
+    case(ThematicBreakStyle::Dash, "a\n\n---\n\nb"),
+    case(ThematicBreakStyle::Star, "a\n\n***\n\nb"),
+    case(ThematicBreakStyle::Underscore, "a\n\n___\n\nb"),
+)]
+fn thematic_break_style_is_applied(style: ThematicBreakStyle, expected: &str) {
+    let config = Config::default().with_thematic_break_style(style);
+    assert_eq!(expected, render("a\n\n***\n\nb", config));
+}
+
+#[rstest(input, expected,
+    case("[a]: /a\n\nSee [a] and [b].\n\n[b]: /b", "See [a] and [b].\n\n[a]: /a\n[b]: /b"),
+    case("> quote [a]\n>\n> [a]: /a\n\ntext", "> quote [a]\n\ntext\n\n[a]: /a"),
+    case("[a]: /a", "[a]: /a"),
+)]
+fn link_definitions_are_moved_to_end(input: &str, expected: &str) {
+    let config = || Config::default().with_link_definitions_at_end(true);
+    assert_eq!(expected, render(input, config()));
+
+    let doc = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+    let mut out = Vec::new();
+    crate::printer::render_markdown_to(&doc, config(), &mut out).unwrap();
+    assert_eq!(expected, String::from_utf8(out).unwrap());
+}
+
+const PROSE: &str =
+    "One sentence here. Another *one*\ngoes on, e.g. this. Done!\n\n - It wraps. Yes\n   really.";
+
+#[rstest(prose_wrap, expected,
+    case(
+        ProseWrap::Always,
+        "One sentence here.\nAnother *one* goes\non, e.g. this. Done!\n\n - It wraps. Yes\n   really."
+    ),
+    case(
+        ProseWrap::Never,
+        "One sentence here. Another *one* goes on, e.g. this. Done!\n\n - It wraps. Yes really."
+    ),
+    case(
+        ProseWrap::Preserve,
+        "One sentence here. Another *one*\ngoes on, e.g. this. Done!\n\n - It wraps. Yes\n   really."
+    ),
+    case(
+        ProseWrap::Sentence,
+        "One sentence here.\nAnother *one* goes on, e.g. this.\nDone!\n\n - It wraps.\n   Yes really."
+    ),
+)]
+fn prose_wrap_is_applied(prose_wrap: ProseWrap, expected: &str) {
+    let config = || Config::default().with_width(20).with_prose_wrap(prose_wrap);
+    let result = render(PROSE, config());
+    assert_eq!(expected, result);
+    assert_eq!(expected, render(&result, config()));
+}
+
+#[rstest(input, expected,
+    // A line break before a list marker would start a list
+    case("It costs 5. - Cheap. Yes.", "It costs 5. - Cheap.\nYes."),
+    case("End. 1. not a list.", "End. 1. not a list."),
+    case("Close \"quote.\" Next (one!) Last", "Close \"quote.\"\nNext (one!)\nLast"),
+)]
+fn sentence_breaks_are_safe(input: &str, expected: &str) {
+    let config = Config::default().with_prose_wrap(ProseWrap::Sentence);
+    assert_eq!(expected, render(input, config));
+}
+
+fn parse(input: &str) -> crate::ast::Document {
+    crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input).unwrap()
+}
+
+#[rstest(input, expected,
+    case("a\n\\# b", "a\n\\# b"),
+    case("a\n\\- b", "a\n\\- b"),
+    case("a\n\\+ b", "a\n\\+ b"),
+    case("a\n\\* b", "a\n\\* b"),
+    case("a\n\\> b", "a\n\\> b"),
+    case("a\n\\=\\=\\=", "a\n\\==="),
+    case("a\n1\\. b", "a\n1\\. b"),
+    case("a\n2\\) b", "a\n2\\) b"),
+    case("a\n\\<div>", "a\n\\<div>"),
+    case("a\n\\```", "a\n\\```"),
+    case("a\n\\# b\nc\n\\- d", "a\n\\# b\nc\n\\- d"),
+    case("a  \n\\# b", "a  \n\\# b"),
+)]
+fn preserved_lines_keep_escapes(input: &str, expected: &str) {
+    let config = Config::default().with_prose_wrap(ProseWrap::Preserve);
+    let result = render(input, config);
+    assert_eq!(expected, result);
+    assert_eq!(parse(input), parse(&result));
+}
+
+#[rstest(input,
+    case("Done. ==="),
+    case("Done. ---"),
+    case("Done. 1) x"),
+    case("Done. 1. x"),
+    case("Done. - x"),
+    case("Done. # x"),
+    case("Done. > x"),
+    case("Done. <div>x"),
+    case("Done. <!-- c -->"),
+)]
+fn line_breaks_keep_the_paragraph(input: &str) {
+    let config = Config::default().with_prose_wrap(ProseWrap::Sentence);
+    let result = render(input, config);
+    assert_eq!(input, result);
+    assert_eq!(parse(input), parse(&result));
+
+    // Wrapping at every possible position keeps a single paragraph
+    let wrapped = render(input, Config::default().with_width(1));
+    let unwrapped = render(&wrapped, Config::default().with_prose_wrap(ProseWrap::Never));
+    assert_eq!(input, unwrapped);
+}
@@ -1,4 +1,5 @@
 #![cfg(test)]
+use crate::printer::config::DelimiterStyle;
 use rstest::rstest;
 
 #[test]
@@ -31,3 +32,107 @@ fn text_newlines_normalize_to_spaces(input: &str, expected: &str) {
     let result = crate::printer::render_markdown(&doc, config);
     assert_eq!(expected, result);
 }
+
+#[rstest(
+    input,
+    case("Some _emphasis_ and *emphasis*"),
+    case("Some __strong__ and **strong**"),
+    case("Nested ___both___ and ***both***"),
+    case("Mixed **_both_** and __*both*__")
+)]
+fn emphasis_delimiters_are_preserved(input: &str) {
+    let config = crate::printer::config::Config::default();
+    let doc = crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input)
+        .unwrap();
+
+    let result = crate::printer::render_markdown(&doc, config);
+    assert_eq!(input, result);
+}
+
+#[rstest(
+    emphasis_style,
+    strong_style,
+    expected,
+    case(
+        DelimiterStyle::Asterisk,
+        DelimiterStyle::Asterisk,
+        "*a* *b* **c** **d**"
+    ),
+    case(
+        DelimiterStyle::Underscore,
+        DelimiterStyle::Underscore,
+        "_a_ _b_ __c__ __d__"
+    ),
+    case(
+        DelimiterStyle::Underscore,
+        DelimiterStyle::Asterisk,
+        "_a_ _b_ **c** **d**"
+    ),
+    case(
+        DelimiterStyle::Preserve,
+        DelimiterStyle::Underscore,
+        "*a* _b_ __c__ __d__"
+    )
+)]
+fn emphasis_delimiters_are_normalized(
+    emphasis_style: DelimiterStyle,
+    strong_style: DelimiterStyle,
+    expected: &str,
+) {
+    let config = crate::printer::config::Config::default()
+        .with_emphasis_style(emphasis_style)
+        .with_strong_style(strong_style);
+    let doc = crate::parser::parse_markdown(
+        crate::parser::MarkdownParserState::default(),
+        "*a* _b_ **c** __d__",
+    )
+    .unwrap();
+
+    let result = crate::printer::render_markdown(&doc, config);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn intraword_emphasis_falls_back_to_asterisks() {
+    use crate::ast::*;
+
+    let doc = Document {
+        blocks: vec![Block::Paragraph(vec![
+            Inline::Text("un".to_owned()),
+            Inline::Emphasis(Emphasis {
+                delimiter: EmphasisDelimiter::Underscore,
+                content: vec![Inline::Text("frigging".to_owned())],
+            }),
+            Inline::Text("believable".to_owned()),
+        ])],
+    };
+
+    let result = crate::printer::render_markdown(&doc, crate::printer::config::Config::default());
+    assert_eq!("un*frigging*believable", result);
+}
+
+#[rstest(
+    input,
+    expected,
+    case("*(version number)*", "*(version number)*"),
+    case("**[link](/url)** and *`code`*", "**[link](/url)** and *`code`*"),
+    case("***both*** and ***a** b*", "___both___ and ***a** b*"),
+    case("**_a_ b**", "**_a_ b**"),
+    case("*a (b)* c", "_a (b)_ c")
+)]
+fn underscore_emphasis_falls_back_to_asterisks_before_punctuation(input: &str, expected: &str) {
+    let config = || {
+        crate::printer::config::Config::default()
+            .with_emphasis_style(DelimiterStyle::Underscore)
+            .with_strong_style(DelimiterStyle::Underscore)
+    };
+    let parse = |input: &str| {
+        crate::parser::parse_markdown(crate::parser::MarkdownParserState::default(), input).unwrap()
+    };
+    let result = crate::printer::render_markdown(&parse(input), config());
+    assert_eq!(expected, result);
+    assert_eq!(
+        expected,
+        crate::printer::render_markdown(&parse(&result), config())
+    );
+}